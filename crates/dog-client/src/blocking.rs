@@ -0,0 +1,137 @@
+//! Blocking client, for callers without an async runtime.
+
+use {super::*, std::collections::VecDeque};
+
+#[derive(Clone, Debug)]
+pub struct Client {
+  client: reqwest::blocking::Client,
+  credentials: Option<Credentials>,
+  url: Url,
+}
+
+macro_rules! define {
+  ($(
+    $(#[$doc:meta])*
+    fn $name:ident($($arg:ident: $ty:ty),*) -> $output:ty = $path:literal $(, $path_arg:expr)*;
+  )*) => {
+    impl Client {
+      $(
+        $(#[$doc])*
+        pub fn $name(&self, $($arg: $ty),*) -> Result<$output> {
+          self.get(&format!($path $(, $path_arg)*))
+        }
+      )*
+    }
+  };
+}
+
+endpoints!(define);
+
+impl Client {
+  pub fn new(url: &str) -> Result<Self> {
+    Ok(Self {
+      client: reqwest::blocking::ClientBuilder::new()
+        .default_headers(default_headers())
+        .build()?,
+      credentials: None,
+      url: parse_url(url)?,
+    })
+  }
+
+  /// Authenticate with the `--server-username` and `--server-password` the
+  /// server was started with.
+  pub fn with_credentials(mut self, username: &str, password: &str) -> Self {
+    self.credentials = Some(Credentials {
+      username: username.into(),
+      password: password.into(),
+    });
+    self
+  }
+
+  /// Requests any JSON endpoint not covered by a typed method.
+  pub fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+    self.send(self.client.get(join(&self.url, path)))
+  }
+
+  /// Outputs for `outpoints`, in the same order, in a single request.
+  pub fn outputs(&self, outpoints: &[OutPoint]) -> Result<Vec<api::Output>> {
+    self.send(
+      self
+        .client
+        .post(join(&self.url, "/outputs"))
+        .json(outpoints),
+    )
+  }
+
+  fn send<T: DeserializeOwned>(&self, request: reqwest::blocking::RequestBuilder) -> Result<T> {
+    let request = match &self.credentials {
+      Some(credentials) => request.basic_auth(&credentials.username, Some(&credentials.password)),
+      None => request,
+    };
+
+    let response = request.send()?;
+    let url = response.url().to_string();
+    let status = response.status();
+    let body = response.text()?;
+
+    deserialize(url, status, &body)
+  }
+}
+
+/// Iterates over the items of every page returned by `get_page`, starting at
+/// page zero and stopping after the last page:
+///
+/// ```no_run
+/// # let client = dog_client::blocking::Client::new("http://localhost:80")?;
+/// for id in dog_client::blocking::pages(|page| client.inscriptions(page)) {
+///   println!("{}", id?);
+/// }
+/// # Ok::<(), dog_client::Error>(())
+/// ```
+pub fn pages<P, F>(get_page: F) -> Pages<P, F>
+where
+  P: Paginated,
+  F: FnMut(u32) -> Result<P>,
+{
+  Pages {
+    get_page,
+    items: VecDeque::new(),
+    next: Some(0),
+  }
+}
+
+pub struct Pages<P: Paginated, F> {
+  get_page: F,
+  items: VecDeque<P::Item>,
+  next: Option<u32>,
+}
+
+impl<P, F> Iterator for Pages<P, F>
+where
+  P: Paginated,
+  F: FnMut(u32) -> Result<P>,
+{
+  type Item = Result<P::Item>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(item) = self.items.pop_front() {
+        return Some(Ok(item));
+      }
+
+      let page = self.next?;
+
+      match (self.get_page)(page) {
+        Ok(response) => {
+          let (items, more) = response.into_items();
+          self.items.extend(items);
+          self.next = more.then_some(page + 1);
+        }
+        Err(err) => {
+          self.next = None;
+          return Some(Err(err));
+        }
+      }
+    }
+  }
+}
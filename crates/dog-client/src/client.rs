@@ -0,0 +1,110 @@
+use {super::*, std::future::Future};
+
+#[derive(Clone, Debug)]
+pub struct Client {
+  client: reqwest::Client,
+  credentials: Option<Credentials>,
+  url: Url,
+}
+
+macro_rules! define {
+  ($(
+    $(#[$doc:meta])*
+    fn $name:ident($($arg:ident: $ty:ty),*) -> $output:ty = $path:literal $(, $path_arg:expr)*;
+  )*) => {
+    impl Client {
+      $(
+        $(#[$doc])*
+        pub async fn $name(&self, $($arg: $ty),*) -> Result<$output> {
+          self.get(&format!($path $(, $path_arg)*)).await
+        }
+      )*
+    }
+  };
+}
+
+endpoints!(define);
+
+impl Client {
+  pub fn new(url: &str) -> Result<Self> {
+    Ok(Self {
+      client: reqwest::ClientBuilder::new()
+        .default_headers(default_headers())
+        .build()?,
+      credentials: None,
+      url: parse_url(url)?,
+    })
+  }
+
+  /// Authenticate with the `--server-username` and `--server-password` the
+  /// server was started with.
+  pub fn with_credentials(mut self, username: &str, password: &str) -> Self {
+    self.credentials = Some(Credentials {
+      username: username.into(),
+      password: password.into(),
+    });
+    self
+  }
+
+  /// Requests any JSON endpoint not covered by a typed method.
+  pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+    self.send(self.client.get(join(&self.url, path))).await
+  }
+
+  /// Outputs for `outpoints`, in the same order, in a single request.
+  pub async fn outputs(&self, outpoints: &[OutPoint]) -> Result<Vec<api::Output>> {
+    self
+      .send(
+        self
+          .client
+          .post(join(&self.url, "/outputs"))
+          .json(outpoints),
+      )
+      .await
+  }
+
+  async fn send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T> {
+    let request = match &self.credentials {
+      Some(credentials) => request.basic_auth(&credentials.username, Some(&credentials.password)),
+      None => request,
+    };
+
+    let response = request.send().await?;
+    let url = response.url().to_string();
+    let status = response.status();
+    let body = response.text().await?;
+
+    deserialize(url, status, &body)
+  }
+}
+
+/// Collects the items of every page returned by `get_page`, starting at page
+/// zero and stopping after the last page:
+///
+/// ```no_run
+/// # async fn run() -> dog_client::Result {
+/// let client = dog_client::Client::new("http://localhost:80")?;
+/// let ids = dog_client::all_pages(|page| client.inscriptions(page)).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn all_pages<P, F, Fut>(mut get_page: F) -> Result<Vec<P::Item>>
+where
+  P: Paginated,
+  F: FnMut(u32) -> Fut,
+  Fut: Future<Output = Result<P>>,
+{
+  let mut items = Vec::new();
+
+  for page in 0.. {
+    let (page_items, more) = get_page(page).await?.into_items();
+
+    items.extend(page_items);
+
+    if !more {
+      break;
+    }
+  }
+
+  Ok(items)
+}
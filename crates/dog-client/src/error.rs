@@ -0,0 +1,28 @@
+use super::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+  #[error("failed to deserialize response from {url}: {source}")]
+  Deserialize {
+    url: String,
+    source: serde_json::Error,
+  },
+  #[error("{url} not found")]
+  NotFound { url: String },
+  #[error(transparent)]
+  Request(#[from] reqwest::Error),
+  #[error("{url} returned {status}: {body}")]
+  Status {
+    url: String,
+    status: StatusCode,
+    body: String,
+  },
+  #[error("invalid server URL: {0}")]
+  Url(String),
+}
+
+impl Error {
+  pub fn is_not_found(&self) -> bool {
+    matches!(self, Self::NotFound { .. })
+  }
+}
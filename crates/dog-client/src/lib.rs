@@ -0,0 +1,235 @@
+//! Typed clients for the JSON API of `dog server`.
+//!
+//! [`Client`] is async and [`blocking::Client`] is blocking. Both expose the
+//! same methods, returning the types from [`dog::api`] that the server
+//! serializes, so responses deserialize into exactly what the server sent.
+//!
+//! ```no_run
+//! let client = dog_client::blocking::Client::new("http://localhost:80")?;
+//!
+//! for token in client.drc20_tokens()? {
+//!   println!("{}: {}", token.tick, token.minted_display());
+//! }
+//! # Ok::<(), dog_client::Error>(())
+//! ```
+
+use {
+  bitcoin::OutPoint,
+  dog::{
+    InscriptionId, api,
+    index::DnsEntry,
+    subcommand::{
+      dogemap::{DogemapInfo, DogemapTransfer},
+      drc20::Drc20Token,
+    },
+  },
+  reqwest::{StatusCode, Url},
+  serde::de::DeserializeOwned,
+  urlencoding::encode,
+};
+
+pub use {
+  client::{Client, all_pages},
+  error::Error,
+};
+
+pub mod blocking;
+mod client;
+mod error;
+
+pub type Result<T = (), E = Error> = std::result::Result<T, E>;
+
+/// A page of a paginated endpoint.
+pub trait Paginated: DeserializeOwned {
+  type Item;
+
+  fn into_items(self) -> (Vec<Self::Item>, bool);
+}
+
+impl Paginated for api::Inscriptions {
+  type Item = InscriptionId;
+
+  fn into_items(self) -> (Vec<InscriptionId>, bool) {
+    (self.ids, self.more)
+  }
+}
+
+impl Paginated for api::Children {
+  type Item = InscriptionId;
+
+  fn into_items(self) -> (Vec<InscriptionId>, bool) {
+    (self.ids, self.more)
+  }
+}
+
+impl Paginated for api::AddressInscriptions {
+  type Item = InscriptionId;
+
+  fn into_items(self) -> (Vec<InscriptionId>, bool) {
+    (self.ids, self.more)
+  }
+}
+
+impl Paginated for api::CollectionTraitInscriptions {
+  type Item = InscriptionId;
+
+  fn into_items(self) -> (Vec<InscriptionId>, bool) {
+    (self.ids, self.more)
+  }
+}
+
+impl Paginated for api::DuneActivities {
+  type Item = api::DuneActivity;
+
+  fn into_items(self) -> (Vec<api::DuneActivity>, bool) {
+    (self.activity, self.more)
+  }
+}
+
+impl Paginated for api::DuneHolders {
+  type Item = api::DuneHolder;
+
+  fn into_items(self) -> (Vec<api::DuneHolder>, bool) {
+    (self.holders, self.more)
+  }
+}
+
+impl Paginated for api::CollectionHolders {
+  type Item = api::CollectionHolder;
+
+  fn into_items(self) -> (Vec<api::CollectionHolder>, bool) {
+    (self.holders, self.more)
+  }
+}
+
+#[derive(Clone, Debug)]
+struct Credentials {
+  username: String,
+  password: String,
+}
+
+fn parse_url(url: &str) -> Result<Url> {
+  url
+    .parse()
+    .map_err(|err| Error::Url(format!("{url}: {err}")))
+}
+
+fn join(base: &Url, path: &str) -> String {
+  format!("{}{path}", base.as_str().trim_end_matches('/'))
+}
+
+fn default_headers() -> reqwest::header::HeaderMap {
+  let mut headers = reqwest::header::HeaderMap::new();
+  headers.insert(
+    reqwest::header::ACCEPT,
+    reqwest::header::HeaderValue::from_static("application/json"),
+  );
+  headers
+}
+
+fn deserialize<T: DeserializeOwned>(url: String, status: StatusCode, body: &str) -> Result<T> {
+  if status == StatusCode::NOT_FOUND {
+    return Err(Error::NotFound { url });
+  }
+
+  if !status.is_success() {
+    return Err(Error::Status {
+      url,
+      status,
+      body: body.into(),
+    });
+  }
+
+  serde_json::from_str(body).map_err(|source| Error::Deserialize { url, source })
+}
+
+/// Invokes `$callback` with the list of `GET` endpoints, so that the async and
+/// blocking clients define the same methods.
+macro_rules! endpoints {
+  ($callback:ident) => {
+    $callback! {
+      /// Index status, including which optional indices are enabled.
+      fn status() -> api::Status = "/status";
+
+      /// Height of the latest indexed block.
+      fn block_height() -> u32 = "/r/blockheight";
+
+      fn inscription(id: InscriptionId) -> api::Inscription = "/inscription/{}", id;
+
+      fn inscription_recursive(id: InscriptionId) -> api::InscriptionRecursive =
+        "/r/inscription/{}", id;
+
+      /// Latest inscriptions, newest first.
+      fn inscriptions(page: u32) -> api::Inscriptions = "/inscriptions/{}", page;
+
+      fn children(id: InscriptionId, page: u32) -> api::Children = "/r/children/{}/{}", id, page;
+
+      fn output(outpoint: OutPoint) -> api::Output = "/output/{}", outpoint;
+
+      fn address(address: &str) -> api::AddressInfo = "/address/{}", encode(address);
+
+      fn address_inscriptions(address: &str, page: u32) -> api::AddressInscriptions =
+        "/r/address/{}/inscriptions/{}", encode(address), page;
+
+      fn portfolio(address: &str, page: u32) -> api::AddressPortfolio =
+        "/r/address/{}/portfolio/{}", encode(address), page;
+
+      /// Dune by name, spaced name, or ID.
+      fn dune(dune: &str) -> api::Dune = "/dune/{}", encode(dune);
+
+      /// Etchings, mints, transfers, and burns of a dune, newest first.
+      fn dune_activity(dune: &str, page: u32) -> api::DuneActivities =
+        "/dune/{}/activity/{}", encode(dune), page;
+
+      /// Mints of a dune in each block in `start..end` that had any.
+      fn dune_mints(dune: &str, start: u32, end: u32) -> api::DuneMints =
+        "/r/dune/{}/mints/{}/{}", encode(dune), start, end;
+
+      /// Dunes that can be minted in the next block.
+      fn mintable_dunes() -> Vec<api::MintableDune> = "/r/dunes/mintable";
+
+      /// Addresses holding a dune, largest balance first.
+      fn dune_holders(dune: &str, page: u32) -> api::DuneHolders =
+        "/dune/{}/holders/{}", encode(dune), page;
+
+      fn collection_holders(parent: InscriptionId, page: u32) -> api::CollectionHolders =
+        "/r/collection/{}/holders/{}", parent, page;
+
+      fn collection_stats(parent: InscriptionId) -> api::CollectionStats =
+        "/r/collection/{}/stats", parent;
+
+      fn collection_traits(parent: InscriptionId) -> api::CollectionTraits =
+        "/r/collection/{}/traits", parent;
+
+      fn collection_trait_inscriptions(
+        parent: InscriptionId,
+        name: &str,
+        value: &str,
+        page: u32
+      ) -> api::CollectionTraitInscriptions =
+        "/r/collection/{}/traits/{}/{}/{}", parent, encode(name), encode(value), page;
+
+      /// All deployed DRC-20 tokens, sorted by tick.
+      fn drc20_tokens() -> Vec<Drc20Token> = "/r/drc20/tokens";
+
+      fn drc20_token(tick: &str) -> Drc20Token = "/r/drc20/token/{}", encode(tick);
+
+      fn drc20_balances(address: &str) -> Vec<api::Drc20BalanceEntry> =
+        "/r/drc20/balance/{}", encode(address);
+
+      fn drc20_balance(address: &str, tick: &str) -> api::Drc20Balance =
+        "/r/drc20/balance/{}/{}", encode(address), encode(tick);
+
+      fn dns_name(name: &str) -> DnsEntry = "/r/dns/name/{}", encode(name);
+
+      fn dns_namespace(namespace: &str) -> Vec<String> = "/r/dns/namespace/{}", encode(namespace);
+
+      fn dogemap(block_number: u32) -> DogemapInfo = "/r/dogemap/block/{}", block_number;
+
+      fn dogemap_transfers(block_number: u32) -> Vec<DogemapTransfer> =
+        "/r/dogemap/transfers/{}", block_number;
+    }
+  };
+}
+
+use endpoints;
@@ -0,0 +1,293 @@
+use {
+  axum_server::Handle,
+  bitcoin::{
+    Address, Network, OutPoint, PubkeyHash, Witness, hashes::Hash, script::Builder as ScriptBuilder,
+  },
+  dog::{Index, Inscription, InscriptionId, chain::Chain, parse_dog_server_args},
+  dog_client::{Error, blocking},
+  mockcore::TransactionTemplate,
+  pretty_assertions::assert_eq as pretty_assert_eq,
+  reqwest::StatusCode,
+  std::{fs, net::SocketAddr, sync::Arc, thread},
+  tempfile::TempDir,
+};
+
+struct TestServer {
+  handle: Handle<SocketAddr>,
+  port: u16,
+  #[allow(unused)]
+  tempdir: TempDir,
+}
+
+impl TestServer {
+  fn spawn(core: &mockcore::Handle, dog_args: &[&str]) -> Self {
+    let tempdir = TempDir::new().unwrap();
+
+    let cookiefile = tempdir.path().join("cookie");
+
+    fs::write(&cookiefile, "username:password").unwrap();
+
+    let (settings, server) = parse_dog_server_args(&format!(
+      "dog --dogecoin-rpc-url {} --cookie-file {} --dogecoin-data-dir {} --datadir {} {} server --http-port 0 --address 127.0.0.1",
+      core.url(),
+      cookiefile.to_str().unwrap(),
+      tempdir.path().display(),
+      tempdir.path().display(),
+      dog_args.join(" "),
+    ));
+
+    let index = Arc::new(Index::open(&settings).unwrap());
+    let handle = Handle::new();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    {
+      let handle = handle.clone();
+      thread::spawn(|| server.run(settings, index, handle, Some(tx)).unwrap());
+    }
+
+    Self {
+      handle,
+      port: rx.recv().unwrap(),
+      tempdir,
+    }
+  }
+
+  fn url(&self) -> String {
+    format!("http://127.0.0.1:{}", self.port)
+  }
+
+  fn client(&self) -> blocking::Client {
+    blocking::Client::new(&self.url()).unwrap()
+  }
+
+  fn sync(&self) {
+    let response = reqwest::blocking::get(format!("{}/update", self.url())).unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+}
+
+impl Drop for TestServer {
+  fn drop(&mut self) {
+    self.handle.shutdown();
+  }
+}
+
+#[test]
+fn block_height() {
+  let core = mockcore::spawn();
+  core.mine_blocks(2);
+
+  let server = TestServer::spawn(&core, &[]);
+  server.sync();
+
+  assert_eq!(server.client().block_height().unwrap(), 2);
+}
+
+#[test]
+fn status() {
+  let core = mockcore::spawn();
+
+  let server = TestServer::spawn(&core, &["--index-dunes"]);
+  server.sync();
+
+  let status = server.client().status().unwrap();
+
+  assert!(status.dune_index);
+  assert!(!status.address_index);
+}
+
+#[test]
+fn output_and_outputs_agree() {
+  let core = mockcore::spawn();
+  core.mine_blocks(1);
+
+  let server = TestServer::spawn(&core, &[]);
+  server.sync();
+
+  let outpoint = OutPoint {
+    txid: core.tx(1, 0).compute_txid(),
+    vout: 0,
+  };
+
+  let client = server.client();
+
+  let output = client.output(outpoint).unwrap();
+
+  assert_eq!(output.outpoint, outpoint);
+  assert_eq!(output.value, core.tx(1, 0).output[0].value.to_sat());
+
+  pretty_assert_eq!(client.outputs(&[outpoint]).unwrap(), vec![output]);
+}
+
+#[test]
+fn inscriptions_and_children() {
+  let core = mockcore::spawn();
+  core.mine_blocks(1);
+
+  let server = TestServer::spawn(&core, &["--index-addresses"]);
+
+  let recipient = Address::p2pkh(PubkeyHash::from_byte_array([1; 20]), Network::Bitcoin);
+
+  let script_sig = |inscription: Inscription| {
+    inscription
+      .append_reveal_script_to_builder(ScriptBuilder::new())
+      .into_script()
+  };
+
+  let parent = InscriptionId {
+    txid: core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      script_sig: script_sig(Inscription {
+        content_type: Some(b"text/plain;charset=utf-8".to_vec()),
+        body: Some(b"parent".to_vec()),
+        ..Default::default()
+      }),
+      recipient: Some(recipient.clone()),
+      ..Default::default()
+    }),
+    index: 0,
+  };
+
+  core.mine_blocks(1);
+
+  // The parent tag of an inscription with index zero is just its txid
+  let child = InscriptionId {
+    txid: core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new()), (2, 1, 0, Witness::new())],
+      script_sig: script_sig(Inscription {
+        content_type: Some(b"text/plain;charset=utf-8".to_vec()),
+        body: Some(b"child".to_vec()),
+        parents: vec![parent.txid.to_byte_array().to_vec()],
+        ..Default::default()
+      }),
+      recipient: Some(recipient.clone()),
+      ..Default::default()
+    }),
+    index: 0,
+  };
+
+  core.mine_blocks(1);
+
+  server.sync();
+
+  let client = server.client();
+
+  let address = Chain::Dogecoin
+    .address_string_from_script(&recipient.script_pubkey())
+    .unwrap();
+
+  let inscription = client.inscription(child).unwrap();
+
+  assert_eq!(inscription.id, child);
+  assert_eq!(inscription.parents, vec![parent]);
+  assert_eq!(inscription.satpoint.outpoint.txid, child.txid);
+  assert_eq!(
+    inscription.content_type.as_deref(),
+    Some("text/plain;charset=utf-8")
+  );
+
+  let children = client.children(parent, 0).unwrap();
+
+  assert_eq!(children.ids, vec![child]);
+  assert!(!children.more);
+
+  let mut inscriptions = client.address_inscriptions(&address, 0).unwrap().ids;
+  inscriptions.sort();
+
+  let mut expected = vec![parent, child];
+  expected.sort();
+
+  assert_eq!(inscriptions, expected);
+}
+
+#[test]
+fn missing_resources_are_not_found() {
+  let core = mockcore::spawn();
+
+  let server = TestServer::spawn(&core, &[]);
+  server.sync();
+
+  let client = server.client();
+
+  assert!(client.drc20_token("dogi").unwrap_err().is_not_found());
+  assert!(client.dns_name("satoshi.doge").unwrap_err().is_not_found());
+  assert!(client.dogemap(5056597).unwrap_err().is_not_found());
+}
+
+#[test]
+fn empty_collections() {
+  let core = mockcore::spawn();
+
+  let server = TestServer::spawn(&core, &[]);
+  server.sync();
+
+  let client = server.client();
+
+  assert!(client.drc20_tokens().unwrap().is_empty());
+
+  assert!(
+    blocking::pages(|page| client.inscriptions(page))
+      .collect::<dog_client::Result<Vec<_>>>()
+      .unwrap()
+      .is_empty()
+  );
+}
+
+#[test]
+fn credentials_are_sent() {
+  let core = mockcore::spawn();
+
+  let server = TestServer::spawn(
+    &core,
+    &["--server-username", "foo", "--server-password", "bar"],
+  );
+
+  assert!(matches!(
+    server.client().block_height().unwrap_err(),
+    Error::Status {
+      status: StatusCode::UNAUTHORIZED,
+      ..
+    }
+  ));
+
+  assert!(matches!(
+    server
+      .client()
+      .with_credentials("foo", "baz")
+      .block_height()
+      .unwrap_err(),
+    Error::Status {
+      status: StatusCode::UNAUTHORIZED,
+      ..
+    }
+  ));
+
+  server
+    .client()
+    .with_credentials("foo", "bar")
+    .block_height()
+    .unwrap();
+}
+
+#[test]
+fn async_client() {
+  let core = mockcore::spawn();
+  core.mine_blocks(1);
+
+  let server = TestServer::spawn(&core, &[]);
+  server.sync();
+
+  let client = dog_client::Client::new(&server.url()).unwrap();
+
+  tokio::runtime::Runtime::new().unwrap().block_on(async {
+    assert_eq!(client.block_height().await.unwrap(), 1);
+
+    assert!(
+      dog_client::all_pages(|page| client.inscriptions(page))
+        .await
+        .unwrap()
+        .is_empty()
+    );
+  });
+}
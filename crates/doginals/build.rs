@@ -0,0 +1,106 @@
+//! Converts the wonky-era subsidy JSON files in this crate's root into
+//! compact little-endian `u64` tables that `src/epoch.rs` embeds with
+//! `include_bytes!`, so nothing is parsed at runtime.
+
+use std::{env, fs, path::Path};
+
+fn read_json(path: &Path) -> serde_json::Value {
+  println!("cargo:rerun-if-changed={}", path.display());
+
+  serde_json::from_str(
+    &fs::read_to_string(path)
+      .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display())),
+  )
+  .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()))
+}
+
+fn write_table(path: &Path, values: &[u64]) {
+  let bytes = values
+    .iter()
+    .flat_map(|value| value.to_le_bytes())
+    .collect::<Vec<u8>>();
+
+  fs::write(path, bytes).unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+}
+
+fn main() {
+  let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+  let manifest_dir = Path::new(&manifest_dir);
+  let out_dir = env::var("OUT_DIR").unwrap();
+  let out_dir = Path::new(&out_dir);
+
+  let subsidies = read_json(&manifest_dir.join("subsidies.json"));
+
+  let epochs = subsidies["epochs"]
+    .as_object()
+    .expect("subsidies.json must have an `epochs` object");
+
+  let mut subsidies = vec![None; epochs.len()];
+
+  for (height, subsidy) in epochs {
+    let height = height
+      .parse::<usize>()
+      .unwrap_or_else(|err| panic!("invalid height `{height}` in subsidies.json: {err}"));
+
+    let subsidy = subsidy
+      .as_u64()
+      .unwrap_or_else(|| panic!("invalid subsidy for height {height} in subsidies.json"));
+
+    assert!(
+      subsidy > 0,
+      "zero subsidy for height {height} in subsidies.json"
+    );
+
+    let slot = subsidies
+      .get_mut(height)
+      .unwrap_or_else(|| panic!("height {height} in subsidies.json is out of range"));
+
+    assert!(
+      slot.is_none(),
+      "duplicate height {height} in subsidies.json"
+    );
+
+    *slot = Some(subsidy);
+  }
+
+  let subsidies = subsidies
+    .into_iter()
+    .map(Option::unwrap)
+    .collect::<Vec<u64>>();
+
+  let starting_koinu = read_json(&manifest_dir.join("starting_koinu.json"))
+    .as_array()
+    .expect("starting_koinu.json must be an array")
+    .iter()
+    .enumerate()
+    .map(|(height, koinu)| {
+      koinu
+        .as_u64()
+        .unwrap_or_else(|| panic!("invalid starting koinu for height {height}"))
+    })
+    .collect::<Vec<u64>>();
+
+  assert_eq!(
+    starting_koinu.len(),
+    subsidies.len(),
+    "starting_koinu.json and subsidies.json cover different heights",
+  );
+
+  assert_eq!(
+    starting_koinu.first(),
+    Some(&0),
+    "genesis must start at koinu 0"
+  );
+
+  for (height, pair) in starting_koinu.windows(2).enumerate() {
+    assert_eq!(
+      pair[0].checked_add(subsidies[height]),
+      Some(pair[1]),
+      "starting koinu at height {} does not follow the subsidy at height {height}",
+      height + 1,
+    );
+  }
+
+  write_table(&out_dir.join("subsidies.bin"), &subsidies);
+  write_table(&out_dir.join("starting_koinu.bin"), &starting_koinu);
+}
@@ -16,10 +16,15 @@ pub enum Charm {
   Mythic = 11,
   Burned = 12,
   Palindrome = 13,
+  Wonky = 14,
+  Jackpot = 15,
+  Pittance = 16,
+  Auxpow = 17,
+  Jubilee = 18,
 }
 
 impl Charm {
-  pub const ALL: [Self; 14] = [
+  pub const ALL: [Self; 19] = [
     Self::Coin,
     Self::Uncommon,
     Self::Rare,
@@ -28,6 +33,11 @@ impl Charm {
     Self::Mythic,
     Self::Nineball,
     Self::Palindrome,
+    Self::Wonky,
+    Self::Jackpot,
+    Self::Pittance,
+    Self::Auxpow,
+    Self::Jubilee,
     Self::Reinscription,
     Self::Cursed,
     Self::Unbound,
@@ -36,42 +46,47 @@ impl Charm {
     Self::Burned,
   ];
 
-  pub fn flag(self) -> u16 {
-    1 << self as u16
+  pub fn flag(self) -> u32 {
+    1 << self as u32
   }
 
-  pub fn set(self, charms: &mut u16) {
+  pub fn set(self, charms: &mut u32) {
     *charms |= self.flag();
   }
 
-  pub fn is_set(self, charms: u16) -> bool {
+  pub fn is_set(self, charms: u32) -> bool {
     charms & self.flag() != 0
   }
 
-  pub fn unset(self, charms: u16) -> u16 {
+  pub fn unset(self, charms: u32) -> u32 {
     charms & !self.flag()
   }
 
   pub fn icon(self) -> &'static str {
     match self {
+      Self::Auxpow => "⛓️",
       Self::Burned => "🔥",
       Self::Coin => "🪙",
       Self::Cursed => "👹",
       Self::Epic => "🪻",
+      Self::Jackpot => "💰",
+      Self::Jubilee => "🎉",
       Self::Legendary => "🌝",
       Self::Lost => "🤔",
       Self::Mythic => "🎃",
       Self::Nineball => "\u{39}\u{fe0f}\u{20e3}",
       Self::Palindrome => "🦋",
+      Self::Pittance => "🪶",
       Self::Rare => "🧿",
       Self::Reinscription => "♻️",
       Self::Unbound => "🔓",
       Self::Uncommon => "🌱",
       Self::Vindicated => "\u{2764}\u{fe0f}\u{200d}\u{1f525}",
+      Self::Wonky => "🎲",
     }
   }
 
-  pub fn charms(charms: u16) -> Vec<Charm> {
+  pub fn charms(charms: u32) -> Vec<Charm> {
     Self::ALL
       .into_iter()
       .filter(|charm| charm.is_set(charms))
@@ -85,20 +100,25 @@ impl Display for Charm {
       f,
       "{}",
       match self {
+        Self::Auxpow => "auxpow",
         Self::Burned => "burned",
         Self::Coin => "coin",
         Self::Cursed => "cursed",
         Self::Epic => "epic",
+        Self::Jackpot => "jackpot",
+        Self::Jubilee => "jubilee",
         Self::Legendary => "legendary",
         Self::Lost => "lost",
         Self::Mythic => "mythic",
         Self::Nineball => "nineball",
         Self::Palindrome => "palindrome",
+        Self::Pittance => "pittance",
         Self::Rare => "rare",
         Self::Reinscription => "reinscription",
         Self::Unbound => "unbound",
         Self::Uncommon => "uncommon",
         Self::Vindicated => "vindicated",
+        Self::Wonky => "wonky",
       }
     )
   }
@@ -109,20 +129,25 @@ impl FromStr for Charm {
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     Ok(match s {
+      "auxpow" => Self::Auxpow,
       "burned" => Self::Burned,
       "coin" => Self::Coin,
       "cursed" => Self::Cursed,
       "epic" => Self::Epic,
+      "jackpot" => Self::Jackpot,
+      "jubilee" => Self::Jubilee,
       "legendary" => Self::Legendary,
       "lost" => Self::Lost,
       "mythic" => Self::Mythic,
       "nineball" => Self::Nineball,
       "palindrome" => Self::Palindrome,
+      "pittance" => Self::Pittance,
       "rare" => Self::Rare,
       "reinscription" => Self::Reinscription,
       "unbound" => Self::Unbound,
       "uncommon" => Self::Uncommon,
       "vindicated" => Self::Vindicated,
+      "wonky" => Self::Wonky,
       _ => return Err(format!("invalid charm `{s}`")),
     })
   }
@@ -138,6 +163,16 @@ mod tests {
     assert_eq!(Charm::Cursed.flag(), 0b10);
   }
 
+  #[test]
+  fn flags_are_unique() {
+    let mut flags = 0;
+    for charm in Charm::ALL {
+      assert!(!charm.is_set(flags), "duplicate flag for {charm}");
+      charm.set(&mut flags);
+    }
+    assert_eq!(flags, (1 << Charm::ALL.len()) - 1);
+  }
+
   #[test]
   fn set() {
     let mut flags = 0;
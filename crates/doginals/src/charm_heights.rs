@@ -0,0 +1,13 @@
+/// Heights of the blocks that chain-specific koinu charms refer to. Each is
+/// `None` on chains where the event never happened.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CharmHeights {
+  /// First block that could be merge-mined.
+  pub auxpow: Option<u32>,
+  /// Wonky-era block with the largest subsidy.
+  pub jackpot: Option<u32>,
+  /// Block of the first Doginals inscription.
+  pub jubilee: Option<u32>,
+  /// Wonky-era block with the smallest subsidy.
+  pub pittance: Option<u32>,
+}
@@ -22,8 +22,8 @@ impl From<Koinu> for Degree {
   fn from(sat: Koinu) -> Self {
     let height = sat.height().n();
     Degree {
-      hour: height / (CYCLE_EPOCHS * SUBSIDY_HALVING_INTERVAL),
-      minute: height % SUBSIDY_HALVING_INTERVAL,
+      hour: height / (CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL),
+      minute: height % DOGECOIN_HALVING_INTERVAL,
       second: height % DIFFCHANGE_INTERVAL,
       third: sat.third(),
     }
@@ -34,14 +34,14 @@ impl From<Koinu> for Degree {
 mod tests {
   use super::*;
 
-  fn case(sat: u64, hour: u32, minute: u32, second: u32, third: u64) {
+  fn case(height: u32, offset: u64, hour: u32, minute: u32, second: u32) {
     assert_eq!(
-      Degree::from(Koinu(sat)),
+      Degree::from(Height(height).starting_sat() + offset),
       Degree {
         hour,
         minute,
         second,
-        third,
+        third: offset,
       }
     );
   }
@@ -49,29 +49,14 @@ mod tests {
   #[test]
   fn from() {
     case(0, 0, 0, 0, 0);
-    case(1, 0, 0, 0, 1);
-    case(5_000_000_000, 0, 1, 1, 0);
-    case(
-      5_000_000_000 * u64::from(DIFFCHANGE_INTERVAL),
-      0,
-      DIFFCHANGE_INTERVAL,
-      0,
-      0,
-    );
-    case(
-      5_000_000_000 * u64::from(SUBSIDY_HALVING_INTERVAL),
-      0,
-      0,
-      336,
-      0,
-    );
-    case(
-      (5_000_000_000 + 2_500_000_000 + 1_250_000_000 + 625_000_000 + 312_500_000 + 156_250_000)
-        * u64::from(SUBSIDY_HALVING_INTERVAL),
-      1,
-      0,
-      0,
-      0,
-    );
+    case(0, 1, 0, 0, 0);
+    case(1, 0, 0, 1, 1);
+    case(1, 1, 0, 1, 1);
+    case(DIFFCHANGE_INTERVAL - 1, 0, 0, DIFFCHANGE_INTERVAL - 1, 239);
+    case(DIFFCHANGE_INTERVAL, 0, 0, DIFFCHANGE_INTERVAL, 0);
+    case(DOGECOIN_HALVING_INTERVAL, 0, 0, 0, 160);
+    case(2 * DOGECOIN_HALVING_INTERVAL, 0, 0, 0, 80);
+    case(CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL, 0, 1, 0, 0);
+    case(CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL + 1, 7, 1, 1, 1);
   }
 }
@@ -1,50 +1,82 @@
 use super::*;
 
 // ---------------------------------------------------------------------------
-// Dogecoin subsidy data loaded at compile time from the JSON files in the
-// repository root.  The "wonky era" covers blocks 0–~145,005 where each block
-// received a random reward.  Beyond that range, the post-wonky halving
-// schedule is used.
+// Dogecoin subsidy data, converted at build time from the JSON files in the
+// crate root into little-endian `u64` tables (see `build.rs`).  The
+// "wonky era" covers blocks 0–144,999 where each block received a random
+// reward.  Beyond that range, the post-wonky halving schedule is used.
 // ---------------------------------------------------------------------------
 
+/// Number of blocks in the random-reward wonky era, and so covered by the
+/// wonky-era tables.
+pub const WONKY_ERA_LEN: u32 = 145_000;
+
 /// Cumulative shiboshi totals at each block boundary during the wonky era.
-/// `STARTING_KOINU[n]` is the total number of shiboshis minted before block n.
-static STARTING_KOINU: LazyLock<Vec<u64>> = LazyLock::new(|| {
-  serde_json::from_str(include_str!("../../../starting_koinu.json"))
-    .expect("starting_koinu.json must be valid JSON")
-});
+/// `WONKY_STARTING_KOINU[n]` is the total number of shiboshis minted before
+/// block n.
+pub static WONKY_STARTING_KOINU: [u64; WONKY_ERA_LEN as usize] = decode_table(include_bytes!(
+  concat!(env!("OUT_DIR"), "/starting_koinu.bin")
+));
 
 /// Per-block subsidy (in shiboshis) for every block in the wonky era.
-/// Keyed by block height as a string (matches the JSON format).
-static SUBSIDIES: LazyLock<std::collections::HashMap<u32, u64>> = LazyLock::new(|| {
-  let raw: serde_json::Value = serde_json::from_str(include_str!("../../../subsidies.json"))
-    .expect("subsidies.json must be valid JSON");
-  raw["epochs"]
-    .as_object()
-    .expect("subsidies.json must have an 'epochs' object")
-    .iter()
-    .map(|(k, v)| {
-      (
-        k.parse::<u32>().expect("epoch key must be a valid u32"),
-        v.as_u64().expect("epoch value must be a valid u64"),
-      )
-    })
-    .collect()
-});
+pub static WONKY_SUBSIDIES: [u64; WONKY_ERA_LEN as usize] =
+  decode_table(include_bytes!(concat!(env!("OUT_DIR"), "/subsidies.bin")));
+
+/// Height of the largest wonky-era subsidy, the mainnet jackpot block.
+pub const WONKY_JACKPOT_HEIGHT: u32 = extreme_height(&WONKY_SUBSIDIES, true);
+
+/// Height of the smallest wonky-era subsidy, the mainnet pittance block.
+pub const WONKY_PITTANCE_HEIGHT: u32 = extreme_height(&WONKY_SUBSIDIES, false);
+
+/// First height whose subsidy is the largest (`max`) or smallest in `table`.
+const fn extreme_height(table: &[u64; WONKY_ERA_LEN as usize], max: bool) -> u32 {
+  let mut best = 0;
+  let mut i = 1;
+
+  while i < table.len() {
+    if (max && table[i] > table[best]) || (!max && table[i] < table[best]) {
+      best = i;
+    }
+    i += 1;
+  }
+
+  best as u32
+}
+
+const fn decode_table(bytes: &[u8]) -> [u64; WONKY_ERA_LEN as usize] {
+  assert!(bytes.len() == WONKY_ERA_LEN as usize * 8);
+
+  let mut table = [0; WONKY_ERA_LEN as usize];
+  let mut i = 0;
+
+  while i < table.len() {
+    let j = i * 8;
+    table[i] = u64::from_le_bytes([
+      bytes[j],
+      bytes[j + 1],
+      bytes[j + 2],
+      bytes[j + 3],
+      bytes[j + 4],
+      bytes[j + 5],
+      bytes[j + 6],
+      bytes[j + 7],
+    ]);
+    i += 1;
+  }
+
+  table
+}
 
 /// The permanent Dogecoin block reward floor (after the halving schedule
 /// converges): 10,000 DOGE = 1_000_000_000_000 shiboshis.
 pub const DOGE_MIN_SUBSIDY: u64 = 10_000 * COIN_VALUE;
 
-/// Number of blocks covered by the wonky-era JSON files.
-const WONKY_ERA_LEN: u32 = 145_006;
-
 /// Return the subsidy (in shiboshis) for a given Dogecoin block height.
 ///
-/// * Heights 0–(WONKY_ERA_LEN-1) are looked up from `subsidies.json`.
+/// * Heights 0–(WONKY_ERA_LEN-1) are looked up from `WONKY_SUBSIDIES`.
 /// * Heights beyond that use the standard post-wonky halving schedule.
 pub fn dogecoin_block_subsidy(height: u32) -> u64 {
-  if let Some(&s) = SUBSIDIES.get(&height) {
+  if let Some(&s) = WONKY_SUBSIDIES.get(height as usize) {
     return s;
   }
   dogecoin_standard_subsidy(height)
@@ -52,41 +84,44 @@ pub fn dogecoin_block_subsidy(height: u32) -> u64 {
 
 /// Return the cumulative shiboshis minted before `height`.
 ///
-/// For wonky-era heights this is read directly from `starting_koinu.json`.
+/// For wonky-era heights this is read directly from `WONKY_STARTING_KOINU`.
 /// For post-wonky heights it is computed by summing the fixed epoch rewards.
 pub fn dogecoin_starting_koinu(height: u32) -> u64 {
   let h = height as usize;
-  if h < STARTING_KOINU.len() {
-    return STARTING_KOINU[h];
+  if h < WONKY_STARTING_KOINU.len() {
+    return WONKY_STARTING_KOINU[h];
   }
   // Sum up all wonky-era koinu then add standard-era rewards.
-  let wonky_total = *STARTING_KOINU.last().unwrap_or(&0);
+  let wonky_total = wonky_total();
   let post_wonky = cumulative_post_wonky_sats(height);
   wonky_total.saturating_add(post_wonky)
 }
 
+/// Total shiboshis minted during the wonky era, including the subsidy of its
+/// last block.
+fn wonky_total() -> u64 {
+  let last = WONKY_ERA_LEN as usize - 1;
+  WONKY_STARTING_KOINU[last] + WONKY_SUBSIDIES[last]
+}
+
 /// Dogecoin post-wonky halving schedule (blocks ≥ 145,000):
 ///
 /// | Block range       | Reward per block |
 /// |-------------------|-----------------|
-/// | 145,000–199,999   | 500,000 DOGE    |
-/// | 200,000–299,999   | 250,000 DOGE    |
-/// | 300,000–399,999   | 125,000 DOGE    |
-/// | 400,000–499,999   | 62,500  DOGE    |
-/// | 500,000–599,999   | 31,250  DOGE    |
+/// | 145,000–199,999   | 250,000 DOGE    |
+/// | 200,000–299,999   | 125,000 DOGE    |
+/// | 300,000–399,999   | 62,500  DOGE    |
+/// | 400,000–499,999   | 31,250  DOGE    |
+/// | 500,000–599,999   | 15,625  DOGE    |
 /// | 600,000+          | 10,000  DOGE    |
+///
+/// Source: `dogecoin/src/dogecoin.cpp` `GetDogecoinBlockSubsidy`, which
+/// returns `(500000 * COIN) >> (height / 100000)` until block 600,000.
 fn dogecoin_standard_subsidy(height: u32) -> u64 {
-  if height >= 600_000 {
+  if height >= 6 * DOGECOIN_HALVING_INTERVAL {
     return DOGE_MIN_SUBSIDY;
   }
-  match height / 100_000 {
-    0 | 1 => 500_000 * COIN_VALUE, // 0–199,999 (wonky for 0–144,999; fixed after)
-    2 => 250_000 * COIN_VALUE,
-    3 => 125_000 * COIN_VALUE,
-    4 => 62_500 * COIN_VALUE,
-    5 => 31_250 * COIN_VALUE,
-    _ => DOGE_MIN_SUBSIDY,
-  }
+  (500_000 * COIN_VALUE) >> (height / DOGECOIN_HALVING_INTERVAL)
 }
 
 /// Cumulative shiboshis minted from block WONKY_ERA_LEN up to (but not
@@ -141,10 +176,10 @@ impl Epoch {
     Height(self.0 * SUBSIDY_HALVING_INTERVAL)
   }
 
-  /// Iterator over every epoch's starting sat (from `starting_koinu.json`).
+  /// Iterator over every wonky-era epoch's starting sat.
   /// Used by the `ord epochs` subcommand.
   pub fn all_starting_koinu() -> impl Iterator<Item = Koinu> {
-    STARTING_KOINU.iter().copied().map(Koinu)
+    WONKY_STARTING_KOINU.iter().copied().map(Koinu)
   }
 }
 
@@ -156,9 +191,9 @@ impl PartialEq<u32> for Epoch {
 
 impl From<Koinu> for Epoch {
   fn from(sat: Koinu) -> Self {
-    // Binary search through the STARTING_KOINU array, then fall back to
+    // Binary search through the WONKY_STARTING_KOINU table, then fall back to
     // post-wonky computation.
-    let starting_koinu = &*STARTING_KOINU;
+    let starting_koinu = &WONKY_STARTING_KOINU;
     let target = sat.n();
 
     // Find the last entry ≤ target (this is the epoch/block where sat lives).
@@ -173,24 +208,27 @@ impl From<Koinu> for Epoch {
         } else {
           // Beyond wonky era: binary search in post-wonky range
           // Approximate: start from WONKY_ERA_LEN and search forward.
-          let wonky_total = *starting_koinu.last().unwrap_or(&0);
+          let wonky_total = wonky_total();
           if target < wonky_total {
             Epoch(starting_koinu.len().saturating_sub(1) as u32)
           } else {
-            // Find the block in the post-wonky range
+            // Walk the post-wonky halving periods, which have a fixed
+            // subsidy, instead of individual blocks.
             let mut h = WONKY_ERA_LEN;
             let mut cumulative = wonky_total;
             loop {
-              let subsidy = dogecoin_block_subsidy(h);
-              if cumulative + subsidy > target || subsidy == 0 {
-                return Epoch(h);
-              }
-              cumulative += subsidy;
-              h += 1;
-              if h > 10_000_000 {
-                // Safety cap — should never be reached in practice
-                return Epoch(h);
+              let subsidy = dogecoin_standard_subsidy(h);
+              let period_end = if h >= 600_000 {
+                u32::MAX
+              } else {
+                (h / 100_000 + 1) * 100_000
+              };
+              let blocks = (target - cumulative) / subsidy;
+              if blocks < u64::from(period_end - h) {
+                return Epoch(h + u32::try_from(blocks).unwrap());
               }
+              cumulative += u64::from(period_end - h) * subsidy;
+              h = period_end;
             }
           }
         }
@@ -208,7 +246,7 @@ impl From<Height> for Epoch {
 
 #[cfg(test)]
 mod tests {
-  use super::super::*;
+  use super::*;
 
   #[test]
   fn subsidy_block_0_is_nonzero() {
@@ -219,8 +257,8 @@ mod tests {
   #[test]
   fn subsidy_post_wonky_era() {
     // Post-wonky halving schedule sanity checks.
-    assert_eq!(Epoch(200_000).subsidy(), 250_000 * 100_000_000);
-    assert_eq!(Epoch(300_000).subsidy(), 125_000 * 100_000_000);
+    assert_eq!(Epoch(200_000).subsidy(), 125_000 * 100_000_000);
+    assert_eq!(Epoch(300_000).subsidy(), 62_500 * 100_000_000);
     assert_eq!(Epoch(600_000).subsidy(), 10_000 * 100_000_000);
     assert_eq!(Epoch(1_000_000).subsidy(), 10_000 * 100_000_000);
   }
@@ -275,11 +313,105 @@ mod tests {
   #[test]
   fn subsidy_halving_schedule_matches_dogecoin_core() {
     // Post-wonky schedule mirrors GetBlockSubsidy() in dogecoin/src/validation.cpp.
-    assert_eq!(Epoch(145_000).subsidy(), 500_000 * COIN_VALUE);
-    assert_eq!(Epoch(200_000).subsidy(), 250_000 * COIN_VALUE);
-    assert_eq!(Epoch(300_000).subsidy(), 125_000 * COIN_VALUE);
-    assert_eq!(Epoch(400_000).subsidy(), 62_500 * COIN_VALUE);
-    assert_eq!(Epoch(500_000).subsidy(), 31_250 * COIN_VALUE);
+    assert_eq!(Epoch(145_000).subsidy(), 250_000 * COIN_VALUE);
+    assert_eq!(Epoch(200_000).subsidy(), 125_000 * COIN_VALUE);
+    assert_eq!(Epoch(300_000).subsidy(), 62_500 * COIN_VALUE);
+    assert_eq!(Epoch(400_000).subsidy(), 31_250 * COIN_VALUE);
+    assert_eq!(Epoch(500_000).subsidy(), 15_625 * COIN_VALUE);
+    assert_eq!(Epoch(599_999).subsidy(), 15_625 * COIN_VALUE);
     assert_eq!(Epoch(600_000).subsidy(), 10_000 * COIN_VALUE);
   }
+
+  #[test]
+  fn wonky_subsidies_match_json() {
+    let json: serde_json::Value = serde_json::from_str(include_str!("../subsidies.json")).unwrap();
+
+    let epochs = json["epochs"].as_object().unwrap();
+
+    assert_eq!(epochs.len(), WONKY_SUBSIDIES.len());
+
+    for height in 0..WONKY_ERA_LEN {
+      assert_eq!(
+        dogecoin_block_subsidy(height),
+        epochs[&height.to_string()].as_u64().unwrap(),
+        "subsidy mismatch at height {height}",
+      );
+    }
+  }
+
+  #[test]
+  fn wonky_starting_koinu_match_json() {
+    let json: Vec<u64> = serde_json::from_str(include_str!("../starting_koinu.json")).unwrap();
+
+    assert_eq!(json.len(), WONKY_STARTING_KOINU.len());
+
+    for height in 0..WONKY_ERA_LEN {
+      assert_eq!(
+        dogecoin_starting_koinu(height),
+        json[height as usize],
+        "starting koinu mismatch at height {height}",
+      );
+    }
+  }
+
+  #[test]
+  fn first_post_wonky_block_follows_last_wonky_block() {
+    assert_eq!(
+      dogecoin_starting_koinu(WONKY_ERA_LEN),
+      dogecoin_starting_koinu(WONKY_ERA_LEN - 1) + dogecoin_block_subsidy(WONKY_ERA_LEN - 1),
+    );
+  }
+
+  // ------------------------------------------------------------------
+  // Subsidy table fixtures
+  //
+  // The wonky-era tables used to run to block 145,005. Their last six
+  // rows held one entry per halving period rather than per block, so
+  // block 145,001 started where block 200,000 should, and every starting
+  // koinu had been float-rounded. The old values are noted as "before".
+  // ------------------------------------------------------------------
+
+  #[test]
+  fn starting_koinu_fixtures() {
+    // before: 4_990_040_412_855_874_000
+    assert_eq!(
+      Epoch(100_000).starting_sat(),
+      Koinu(4_990_040_412_856_337_033)
+    );
+    // before: 6_118_637_906_581_682_000
+    assert_eq!(
+      Epoch(144_999).starting_sat(),
+      Koinu(6_118_637_906_582_299_946)
+    );
+    // before: 6_118_676_390_883_681_000
+    assert_eq!(
+      Epoch(145_000).starting_sat(),
+      Koinu(6_118_676_390_884_299_946)
+    );
+    // before: 7_493_676_390_883_682_000
+    assert_eq!(
+      Epoch(145_001).starting_sat(),
+      Koinu(6_118_701_390_884_299_946)
+    );
+    assert_eq!(
+      Epoch(200_000).starting_sat(),
+      Koinu(7_493_676_390_884_299_946)
+    );
+    assert_eq!(
+      Epoch(600_000).starting_sat(),
+      Koinu(9_837_426_390_884_299_946)
+    );
+  }
+
+  #[test]
+  fn subsidy_fixtures() {
+    assert_eq!(Epoch(144_999).subsidy(), 38_484_302_000_000);
+
+    // before: 250,000, 125,000, 62,500, 31,250, 15,625 and 10,000 DOGE
+    for height in 145_000..=145_005 {
+      assert_eq!(Epoch(height).subsidy(), 250_000 * COIN_VALUE);
+    }
+
+    assert_eq!(Epoch(199_999).subsidy(), 250_000 * COIN_VALUE);
+  }
 }
@@ -105,9 +105,10 @@ mod tests {
 
   #[test]
   fn period_offset() {
-    // DIFFCHANGE_INTERVAL = 1 for Dogecoin (AuxPoW retargets every block).
     assert_eq!(Height(0).period_offset(), 0);
-    assert_eq!(Height(1).period_offset(), 0);
-    assert_eq!(Height(1000).period_offset(), 0);
+    assert_eq!(Height(1).period_offset(), 1);
+    assert_eq!(Height(239).period_offset(), 239);
+    assert_eq!(Height(240).period_offset(), 0);
+    assert_eq!(Height(1000).period_offset(), 40);
   }
 }
@@ -27,7 +27,7 @@ impl Koinu {
   }
 
   pub fn cycle(self) -> u32 {
-    Epoch::from(self).0 / CYCLE_EPOCHS
+    self.height().n() / (CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL)
   }
 
   pub fn nineball(self) -> bool {
@@ -35,7 +35,9 @@ impl Koinu {
   }
 
   pub fn palindrome(self) -> bool {
-    let mut n = self.0;
+    // Dogecoin koinu numbers have up to 20 digits, so the reversal may not
+    // fit in a u64.
+    let mut n = u128::from(self.0);
     let mut reversed = 0;
 
     while n > 0 {
@@ -43,7 +45,7 @@ impl Koinu {
       n /= 10;
     }
 
-    self.0 == reversed
+    u128::from(self.0) == reversed
   }
 
   pub fn percentile(self) -> String {
@@ -76,16 +78,36 @@ impl Koinu {
 
   /// Is this sat common or not?  Much faster than `Koinu::rarity()`.
   pub fn common(self) -> bool {
-    // The block rewards for epochs 0 through 9 are all multiples
-    // of 9765625 (the epoch 9 reward), so any sat from epoch 9 or
-    // earlier that isn't divisible by 9765625 is definitely common.
-    if self < Epoch(10).starting_sat() && !self.0.is_multiple_of(Epoch(9).subsidy()) {
-      return true;
-    }
+    // Every block is its own epoch, so only the first koinu of each block
+    // can be uncommon or better.
+    self.epoch_position() != 0
+  }
+
+  /// Was this koinu mined during the random-reward wonky era?
+  pub fn wonky(self) -> bool {
+    self.height().n() < WONKY_ERA_LEN
+  }
+
+  /// Was this koinu mined in the wonky-era block with the largest reward?
+  pub fn jackpot(self, heights: CharmHeights) -> bool {
+    heights.jackpot == Some(self.height().n())
+  }
+
+  /// Was this koinu mined in the wonky-era block with the smallest reward?
+  pub fn pittance(self, heights: CharmHeights) -> bool {
+    heights.pittance == Some(self.height().n())
+  }
+
+  /// Is this the first koinu mined after AuxPoW activation?
+  pub fn auxpow(self, heights: CharmHeights) -> bool {
+    heights
+      .auxpow
+      .is_some_and(|height| self == Height(height).starting_sat())
+  }
 
-    // Fall back to the full calculation.
-    let epoch = self.epoch();
-    !(self.0 - epoch.starting_sat().0).is_multiple_of(epoch.subsidy())
+  /// Was this koinu mined in the jubilee block?
+  pub fn jubilee(self, heights: CharmHeights) -> bool {
+    heights.jubilee == Some(self.height().n())
   }
 
   pub fn coin(self) -> bool {
@@ -107,7 +129,7 @@ impl Koinu {
     name.chars().rev().collect()
   }
 
-  pub fn charms(self) -> u16 {
+  pub fn charms(self, heights: CharmHeights) -> u32 {
     let mut charms = 0;
 
     if self.nineball() {
@@ -122,6 +144,26 @@ impl Koinu {
       Charm::Coin.set(&mut charms);
     }
 
+    if self.wonky() {
+      Charm::Wonky.set(&mut charms);
+    }
+
+    if self.jackpot(heights) {
+      Charm::Jackpot.set(&mut charms);
+    }
+
+    if self.pittance(heights) {
+      Charm::Pittance.set(&mut charms);
+    }
+
+    if self.auxpow(heights) {
+      Charm::Auxpow.set(&mut charms);
+    }
+
+    if self.jubilee(heights) {
+      Charm::Jubilee.set(&mut charms);
+    }
+
     match self.rarity() {
       Rarity::Common => {}
       Rarity::Epic => Charm::Epic.set(&mut charms),
@@ -167,7 +209,7 @@ impl Koinu {
       .parse::<u32>()
       .map_err(|source| ErrorKind::ParseInt { source }.error(degree))?;
 
-    if epoch_offset >= SUBSIDY_HALVING_INTERVAL {
+    if epoch_offset >= DOGECOIN_HALVING_INTERVAL {
       return Err(ErrorKind::EpochOffset.error(degree));
     }
 
@@ -183,29 +225,19 @@ impl Koinu {
       return Err(ErrorKind::PeriodOffset.error(degree));
     }
 
-    let cycle_start_epoch = cycle_number * CYCLE_EPOCHS;
+    let cycle_start_height =
+      u64::from(cycle_number) * u64::from(CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL);
 
-    // Guard against zero (Dogecoin has SUBSIDY_HALVING_INTERVAL=1, DIFFCHANGE_INTERVAL=1;
-    // 1 % 1 = 0 which would cause a divide-by-zero; the degree system doesn't
-    // apply to Dogecoin anyway so any non-zero value is safe here).
-    const HALVING_INCREMENT: u32 = {
-      let h = SUBSIDY_HALVING_INTERVAL % DIFFCHANGE_INTERVAL;
-      if h == 0 { 1 } else { h }
-    };
+    // A cycle starts on both a halving and a period boundary, and each
+    // halving epoch shifts the period offset by 160 blocks, so exactly one
+    // epoch in the cycle can match a valid degree.
+    let height = (0..CYCLE_EPOCHS)
+      .map(|epoch| epoch * DOGECOIN_HALVING_INTERVAL + epoch_offset)
+      .map(|offset| cycle_start_height + u64::from(offset))
+      .find(|height| height % u64::from(DIFFCHANGE_INTERVAL) == u64::from(period_offset))
+      .ok_or_else(|| ErrorKind::EpochPeriodMismatch.error(degree))?;
 
-    // For valid degrees the relationship between epoch_offset and period_offset
-    // will increment by 336 every halving.
-    let relationship = period_offset + SUBSIDY_HALVING_INTERVAL * CYCLE_EPOCHS - epoch_offset;
-
-    if !relationship.is_multiple_of(HALVING_INCREMENT) {
-      return Err(ErrorKind::EpochPeriodMismatch.error(degree));
-    }
-
-    let epochs_since_cycle_start = relationship % DIFFCHANGE_INTERVAL / HALVING_INCREMENT;
-
-    let epoch = cycle_start_epoch + epochs_since_cycle_start;
-
-    let height = Height(epoch * SUBSIDY_HALVING_INTERVAL + epoch_offset);
+    let height = Height(u32::try_from(height).map_err(|_| ErrorKind::CycleNumber.error(degree))?);
 
     let (block_offset, rest) = match rest.split_once('‴') {
       Some((block_offset, rest)) => (
@@ -225,7 +257,13 @@ impl Koinu {
       return Err(ErrorKind::BlockOffset.error(degree));
     }
 
-    Ok(height.starting_sat() + block_offset)
+    let sat = height.starting_sat();
+
+    if sat > Self::LAST {
+      return Err(ErrorKind::CycleNumber.error(degree));
+    }
+
+    Ok(sat + block_offset)
   }
 
   fn from_decimal(decimal: &str) -> Result<Self, Error> {
@@ -305,6 +343,7 @@ pub enum ErrorKind {
   PeriodOffset,
   EpochOffset,
   EpochPeriodMismatch,
+  CycleNumber,
   ParseInt { source: ParseIntError },
   ParseFloat { source: ParseFloatError },
 }
@@ -335,8 +374,9 @@ impl Display for ErrorKind {
       Self::EpochOffset => write!(f, "invalid epoch offset"),
       Self::EpochPeriodMismatch => write!(
         f,
-        "relationship between epoch offset and period offset must be multiple of 336"
+        "relationship between epoch offset and period offset must be multiple of 80"
       ),
+      Self::CycleNumber => write!(f, "invalid cycle number"),
       Self::ParseInt { source } => write!(f, "invalid integer: {source}"),
       Self::ParseFloat { source } => write!(f, "invalid float: {source}"),
     }
@@ -439,93 +479,81 @@ mod tests {
 
   #[test]
   fn degree() {
-    assert_eq!(Koinu(0).degree().to_string(), "0°0′0″0‴");
-    assert_eq!(Koinu(1).degree().to_string(), "0°0′0″1‴");
-    assert_eq!(
-      Koinu(50 * COIN_VALUE - 1).degree().to_string(),
-      "0°0′0″4999999999‴"
-    );
-    assert_eq!(Koinu(50 * COIN_VALUE).degree().to_string(), "0°1′1″0‴");
-    assert_eq!(Koinu(50 * COIN_VALUE + 1).degree().to_string(), "0°1′1″1‴");
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(DIFFCHANGE_INTERVAL) - 1)
-        .degree()
-        .to_string(),
-      "0°2015′2015″4999999999‴"
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(DIFFCHANGE_INTERVAL))
-        .degree()
-        .to_string(),
-      "0°2016′0″0‴"
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(DIFFCHANGE_INTERVAL) + 1)
-        .degree()
-        .to_string(),
-      "0°2016′0″1‴"
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(SUBSIDY_HALVING_INTERVAL) - 1)
-        .degree()
-        .to_string(),
-      "0°209999′335″4999999999‴"
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(SUBSIDY_HALVING_INTERVAL))
-        .degree()
-        .to_string(),
-      "0°0′336″0‴"
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(SUBSIDY_HALVING_INTERVAL) + 1)
-        .degree()
-        .to_string(),
-      "0°0′336″1‴"
-    );
-    assert_eq!(
-      Koinu(2067187500000000 - 1).degree().to_string(),
-      "0°209999′2015″156249999‴"
-    );
-    assert_eq!(Koinu(2067187500000000).degree().to_string(), "1°0′0″0‴");
-    assert_eq!(Koinu(2067187500000000 + 1).degree().to_string(), "1°0′0″1‴");
+    #[track_caller]
+    fn case(height: u32, offset: u64, expected: &str) {
+      assert_eq!(
+        (Height(height).starting_sat() + offset)
+          .degree()
+          .to_string(),
+        expected
+      );
+    }
+
+    case(0, 0, "0°0′0″0‴");
+    case(0, 1, "0°0′0″1‴");
+    case(1, 0, "0°1′1″0‴");
+    case(1, 1, "0°1′1″1‴");
+    case(239, 0, "0°239′239″0‴");
+    case(240, 0, "0°240′0″0‴");
+    case(240, 1, "0°240′0″1‴");
+    case(99_999, 0, "0°99999′159″0‴");
+    case(100_000, 0, "0°0′160″0‴");
+    case(200_000, 0, "0°0′80″0‴");
+    case(299_999, 0, "0°99999′239″0‴");
+    case(300_000, 0, "1°0′0″0‴");
+    case(300_000, 1, "1°0′0″1‴");
+    case(1_000_000, 0, "3°0′160″0‴");
+  }
+
+  #[test]
+  fn degree_round_trip() {
+    for height in (0..1_000).chain((0..2 * CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL).step_by(997)) {
+      let expected = Height(height).starting_sat();
+      let degree = expected.degree();
+      assert_eq!(
+        degree.to_string().parse::<Koinu>().unwrap(),
+        expected,
+        "koinu at height {height} did not round-trip from degree {degree}",
+      );
+    }
   }
 
   #[test]
   fn invalid_degree_bugfix() {
     // Break glass in case of emergency:
-    // for height in 0..(2 * CYCLE_EPOCHS * Epoch::BLOCKS) {
-    //   // 1054200000000000
+    // for height in 0..(2 * CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL) {
     //   let expected = Height(height).starting_sat();
-    //   // 0°1680′0″0‴
     //   let degree = expected.degree();
-    //   // 2034637500000000
     //   let actual = degree.to_string().parse::<Koinu>().unwrap();
     //   assert_eq!(
     //     actual, expected,
     //     "Koinu at height {height} did not round-trip from degree {degree} successfully"
     //   );
     // }
-    assert_eq!(Koinu(1054200000000000).degree().to_string(), "0°1680′0″0‴");
-    assert_eq!(parse("0°1680′0″0‴").unwrap(), 1054200000000000);
     assert_eq!(
-      Koinu(1914226250000000).degree().to_string(),
-      "0°122762′794″0‴"
+      Koinu(4996094953917008323).degree().to_string(),
+      "0°240′160″0‴"
     );
-    assert_eq!(parse("0°122762′794″0‴").unwrap(), 1914226250000000);
+    assert_eq!(parse("0°240′160″0‴").unwrap(), 4996094953917008323);
+    assert_eq!(
+      Koinu(8068663890884299946).degree().to_string(),
+      "0°45999′239″0‴"
+    );
+    assert_eq!(parse("0°45999′239″0‴").unwrap(), 8068663890884299946);
+    assert_eq!(Koinu(9837426390884299946).degree().to_string(), "2°0′0″0‴");
+    assert_eq!(parse("2°0′0″0‴").unwrap(), 9837426390884299946);
   }
 
   #[test]
   fn period() {
     assert_eq!(Koinu(0).period(), 0);
-    assert_eq!(Koinu(10080000000000).period(), 1);
-    assert_eq!(Koinu(2099999997689999).period(), 3437);
-    assert_eq!(Koinu(10075000000000).period(), 0);
-    assert_eq!(Koinu(10080000000000 - 1).period(), 0);
-    assert_eq!(Koinu(10080000000000).period(), 1);
-    assert_eq!(Koinu(10080000000000 + 1).period(), 1);
-    assert_eq!(Koinu(10085000000000).period(), 1);
-    assert_eq!(Koinu(2099999997689999).period(), 3437);
+    assert_eq!(Height(1).starting_sat().period(), 0);
+    assert_eq!(Koinu(Height(240).starting_sat().n() - 1).period(), 0);
+    assert_eq!(Height(240).starting_sat().period(), 1);
+    assert_eq!((Height(240).starting_sat() + 1).period(), 1);
+    assert_eq!(Height(479).starting_sat().period(), 1);
+    assert_eq!(Height(480).starting_sat().period(), 2);
+    assert_eq!(Height(1_000_000).starting_sat().period(), 4_166);
   }
 
   #[test]
@@ -632,25 +660,22 @@ mod tests {
     assert_eq!(parse("0°0′0″0‴").unwrap(), 0);
     assert_eq!(parse("0°0′0″").unwrap(), 0);
     assert_eq!(parse("0°0′0″1‴").unwrap(), 1);
-    assert_eq!(parse("0°2015′2015″0‴").unwrap(), 10075000000000);
-    assert_eq!(parse("0°2016′0″0‴").unwrap(), 10080000000000);
-    assert_eq!(parse("0°2017′1″0‴").unwrap(), 10085000000000);
-    assert_eq!(parse("0°2016′0″1‴").unwrap(), 10080000000001);
-    assert_eq!(parse("0°2017′1″1‴").unwrap(), 10085000000001);
-    assert_eq!(parse("0°209999′335″0‴").unwrap(), 1049995000000000);
-    assert_eq!(parse("0°0′336″0‴").unwrap(), 1050000000000000);
-    assert_eq!(parse("0°0′672″0‴").unwrap(), 1575000000000000);
-    assert_eq!(parse("0°209999′1007″0‴").unwrap(), 1837498750000000);
-    assert_eq!(parse("0°0′1008″0‴").unwrap(), 1837500000000000);
-    assert_eq!(parse("1°0′0″0‴").unwrap(), 2067187500000000);
-    assert_eq!(parse("2°0′0″0‴").unwrap(), 2099487304530000);
-    assert_eq!(parse("3°0′0″0‴").unwrap(), 2099991988080000);
-    assert_eq!(parse("4°0′0″0‴").unwrap(), 2099999873370000);
-    assert_eq!(parse("5°0′0″0‴").unwrap(), 2099999996220000);
-    assert_eq!(parse("5°0′336″0‴").unwrap(), 2099999997060000);
-    assert_eq!(parse("5°0′672″0‴").unwrap(), 2099999997480000);
-    assert_eq!(parse("5°1′673″0‴").unwrap(), 2099999997480001);
-    assert_eq!(parse("5°209999′1007″0‴").unwrap(), 2099999997689999);
+    assert_eq!(parse("0°1′1″0‴").unwrap(), Height(1).starting_sat());
+    assert_eq!(parse("0°1′1″1‴").unwrap(), Height(1).starting_sat() + 1);
+    assert_eq!(parse("0°239′239″0‴").unwrap(), Height(239).starting_sat());
+    assert_eq!(parse("0°240′0″0‴").unwrap(), Height(240).starting_sat());
+    assert_eq!(
+      parse("0°99999′159″0‴").unwrap(),
+      Height(99_999).starting_sat()
+    );
+    assert_eq!(parse("0°0′160″0‴").unwrap(), Height(100_000).starting_sat());
+    assert_eq!(parse("0°0′80″0‴").unwrap(), Height(200_000).starting_sat());
+    assert_eq!(parse("0°1′81″0‴").unwrap(), Height(200_001).starting_sat());
+    assert_eq!(parse("1°0′0″0‴").unwrap(), Height(300_000).starting_sat());
+    assert_eq!(
+      parse("3°0′160″0‴").unwrap(),
+      Height(1_000_000).starting_sat()
+    );
   }
 
   #[test]
@@ -662,42 +687,49 @@ mod tests {
 
   #[test]
   fn from_str_degree_invalid_cycle_number() {
-    assert!(parse("5°0′0″0‴").is_ok());
-    assert!(parse("6°0′0″0‴").is_err());
+    let last = Koinu::LAST.cycle();
+    assert!(parse(&format!("{last}°0′0″0‴")).is_ok());
+    assert!(parse(&format!("{}°0′0″0‴", last + 1)).is_err());
+    assert!(parse(&format!("{}°0′0″0‴", u32::MAX)).is_err());
   }
 
   #[test]
   fn from_str_degree_invalid_epoch_offset() {
-    assert!(parse("0°209999′335″0‴").is_ok());
-    assert!(parse("0°210000′336″0‴").is_err());
+    assert!(parse("0°99999′159″0‴").is_ok());
+    assert!(parse("0°100000′160″0‴").is_err());
   }
 
   #[test]
   fn from_str_degree_invalid_period_offset() {
-    assert!(parse("0°2015′2015″0‴").is_ok());
-    assert!(parse("0°2016′2016″0‴").is_err());
+    assert!(parse("0°239′239″0‴").is_ok());
+    assert!(parse("0°240′240″0‴").is_err());
   }
 
   #[test]
   fn from_str_degree_invalid_block_offset() {
-    assert!(parse("0°0′0″4999999999‴").is_ok());
-    assert!(parse("0°0′0″5000000000‴").is_err());
-    assert!(parse("0°209999′335″4999999999‴").is_ok());
-    assert!(parse("0°0′336″4999999999‴").is_err());
+    let subsidy = Height(0).subsidy();
+    assert!(parse(&format!("0°0′0″{}‴", subsidy - 1)).is_ok());
+    assert!(parse(&format!("0°0′0″{subsidy}‴")).is_err());
+    let subsidy = Height(300_000).subsidy();
+    assert!(parse(&format!("1°0′0″{}‴", subsidy - 1)).is_ok());
+    assert!(parse(&format!("1°0′0″{subsidy}‴")).is_err());
   }
 
   #[test]
   fn from_str_degree_invalid_period_block_relationship() {
-    assert!(parse("0°2015′2015″0‴").is_ok());
-    assert!(parse("0°2016′0″0‴").is_ok());
-    assert!(parse("0°2016′1″0‴").is_err());
-    assert!(parse("0°0′336″0‴").is_ok());
+    assert!(parse("0°240′0″0‴").is_ok());
+    assert!(parse("0°240′1″0‴").is_err());
+    assert!(parse("0°0′160″0‴").is_ok());
+    assert!(parse("0°0′80″0‴").is_ok());
+    assert!(parse("0°0′40″0‴").is_err());
   }
 
   #[test]
   fn from_str_degree_post_distribution() {
-    assert!(parse("5°209999′1007″0‴").is_ok());
-    assert!(parse("5°0′1008″0‴").is_err());
+    // Dogecoin's 10,000 DOGE tail emission never ends, so every degree up to
+    // the practical supply ceiling is valid.
+    assert!(parse("3°0′0″0‴").is_ok());
+    assert!(parse("3°0′0″999999999999‴").is_ok());
   }
 
   #[test]
@@ -713,23 +745,19 @@ mod tests {
   #[test]
   fn cycle() {
     assert_eq!(
-      SUBSIDY_HALVING_INTERVAL * CYCLE_EPOCHS % DIFFCHANGE_INTERVAL,
+      DOGECOIN_HALVING_INTERVAL * CYCLE_EPOCHS % DIFFCHANGE_INTERVAL,
       0
     );
 
     for i in 1..CYCLE_EPOCHS {
-      assert_ne!(i * SUBSIDY_HALVING_INTERVAL % DIFFCHANGE_INTERVAL, 0);
+      assert_ne!(i * DOGECOIN_HALVING_INTERVAL % DIFFCHANGE_INTERVAL, 0);
     }
 
-    assert_eq!(
-      CYCLE_EPOCHS * SUBSIDY_HALVING_INTERVAL % DIFFCHANGE_INTERVAL,
-      0
-    );
-
     assert_eq!(Koinu(0).cycle(), 0);
-    assert_eq!(Koinu(2067187500000000 - 1).cycle(), 0);
-    assert_eq!(Koinu(2067187500000000).cycle(), 1);
-    assert_eq!(Koinu(2067187500000000 + 1).cycle(), 1);
+    assert_eq!(Koinu(Height(300_000).starting_sat().n() - 1).cycle(), 0);
+    assert_eq!(Height(300_000).starting_sat().cycle(), 1);
+    assert_eq!((Height(300_000).starting_sat() + 1).cycle(), 1);
+    assert_eq!(Height(600_000).starting_sat().cycle(), 2);
   }
 
   #[test]
@@ -792,11 +820,11 @@ mod tests {
 
   #[test]
   fn common_fast_path() {
-    // Exhaustively test the Koinu::common() fast path on every
-    // uncommon sat.
-    for height in 0..Epoch::FIRST_POST_SUBSIDY.starting_height().0 {
-      let height = Height(height);
-      assert!(!Koinu::common(height.starting_sat()));
+    for height in (0..1_000).chain((0..1_000_000).step_by(997)) {
+      let sat = Height(height).starting_sat();
+      assert!(!sat.common());
+      assert!((sat + 1).common());
+      assert_eq!(sat.common(), sat.rarity() == Rarity::Common);
     }
   }
 
@@ -808,6 +836,112 @@ mod tests {
     assert!(!Koinu(COIN_VALUE + 1).coin());
   }
 
+  #[test]
+  fn wonky() {
+    assert!(Koinu(0).wonky());
+    assert!(Koinu(Height(WONKY_ERA_LEN).starting_sat().n() - 1).wonky());
+    assert!(!Height(WONKY_ERA_LEN).starting_sat().wonky());
+  }
+
+  const MAINNET: CharmHeights = CharmHeights {
+    auxpow: Some(371_337),
+    jackpot: Some(13_329),
+    jubilee: Some(4_609_720),
+    pittance: Some(129_756),
+  };
+
+  #[test]
+  fn jackpot_and_pittance() {
+    let subsidies = &WONKY_SUBSIDIES;
+
+    let max = subsidies.iter().max().unwrap();
+    let min = subsidies.iter().min().unwrap();
+
+    assert_eq!(
+      subsidies.iter().filter(|subsidy| *subsidy == max).count(),
+      1
+    );
+    assert_eq!(
+      subsidies.iter().filter(|subsidy| *subsidy == min).count(),
+      1
+    );
+
+    let jackpot = MAINNET.jackpot.unwrap();
+    let pittance = MAINNET.pittance.unwrap();
+
+    assert_eq!(jackpot, WONKY_JACKPOT_HEIGHT);
+    assert_eq!(pittance, WONKY_PITTANCE_HEIGHT);
+
+    assert_eq!(subsidies[jackpot as usize], *max);
+    assert_eq!(subsidies[pittance as usize], *min);
+
+    for height in [jackpot, pittance] {
+      let first = Height(height).starting_sat();
+      let last = Koinu(Height(height + 1).starting_sat().n() - 1);
+
+      for sat in [first, last] {
+        assert_eq!(sat.jackpot(MAINNET), height == jackpot);
+        assert_eq!(sat.pittance(MAINNET), height == pittance);
+        assert!(!sat.jackpot(CharmHeights::default()));
+        assert!(!sat.pittance(CharmHeights::default()));
+      }
+
+      assert!(!Koinu(first.n() - 1).jackpot(MAINNET));
+      assert!(!Koinu(first.n() - 1).pittance(MAINNET));
+      assert!(!(last + 1).jackpot(MAINNET));
+      assert!(!(last + 1).pittance(MAINNET));
+    }
+  }
+
+  #[test]
+  fn auxpow() {
+    let first = Height(MAINNET.auxpow.unwrap()).starting_sat();
+    assert!(first.auxpow(MAINNET));
+    assert!(!(first + 1).auxpow(MAINNET));
+    assert!(!Koinu(first.n() - 1).auxpow(MAINNET));
+    assert!(!first.auxpow(CharmHeights::default()));
+  }
+
+  #[test]
+  fn jubilee() {
+    let height = MAINNET.jubilee.unwrap();
+    let first = Height(height).starting_sat();
+    assert!(first < Koinu::LAST);
+    assert!(first.jubilee(MAINNET));
+    assert!((first + 1).jubilee(MAINNET));
+    assert!(!Koinu(first.n() - 1).jubilee(MAINNET));
+    assert!(!Height(height + 1).starting_sat().jubilee(MAINNET));
+    assert!(!first.jubilee(CharmHeights::default()));
+  }
+
+  #[test]
+  fn dogecoin_charms() {
+    assert_eq!(
+      Charm::charms(Height(13_329).starting_sat().charms(MAINNET)),
+      [Charm::Uncommon, Charm::Wonky, Charm::Jackpot]
+    );
+    assert_eq!(
+      Charm::charms((Height(129_756).starting_sat() + 1).charms(MAINNET)),
+      [Charm::Wonky, Charm::Pittance]
+    );
+    assert_eq!(
+      Charm::charms(Height(371_337).starting_sat().charms(MAINNET)),
+      [Charm::Uncommon, Charm::Auxpow]
+    );
+    assert_eq!(
+      Charm::charms((Height(4_609_720).starting_sat() + 1).charms(MAINNET)),
+      [Charm::Jubilee]
+    );
+    assert_eq!(
+      Charm::charms(
+        Height(13_329)
+          .starting_sat()
+          .charms(CharmHeights::default())
+      ),
+      [Charm::Uncommon, Charm::Wonky]
+    );
+  }
+
   #[test]
   fn nineball() {
     for height in 0..10 {
@@ -839,13 +973,15 @@ mod tests {
     assert!(Koinu(0).palindrome());
     assert!(!Koinu(10).palindrome());
     assert!(Koinu(11).palindrome());
+    assert!(Koinu(10_000_000_000_000_000_001).palindrome());
+    assert!(!Koinu(u64::MAX).palindrome());
   }
 
   #[test]
   fn palindrome_charm() {
-    assert!(Charm::Palindrome.is_set(Koinu(0).charms()));
-    assert!(!Charm::Palindrome.is_set(Koinu(10).charms()));
-    assert!(Charm::Palindrome.is_set(Koinu(11).charms()));
+    assert!(Charm::Palindrome.is_set(Koinu(0).charms(MAINNET)));
+    assert!(!Charm::Palindrome.is_set(Koinu(10).charms(MAINNET)));
+    assert!(Charm::Palindrome.is_set(Koinu(11).charms(MAINNET)));
   }
 
   #[test]
@@ -857,8 +993,8 @@ mod tests {
 
     case("0°0′0″1‴", Rarity::Common);
     case("0°1′1″0‴", Rarity::Uncommon);
-    case("0°2016′0″0‴", Rarity::Rare);
-    case("0°0′336″0‴", Rarity::Epic);
+    case("0°240′0″0‴", Rarity::Rare);
+    case("0°0′160″0‴", Rarity::Epic);
     case("1°0′0″0‴", Rarity::Legendary);
     case("0°0′0″0‴", Rarity::Mythic);
   }
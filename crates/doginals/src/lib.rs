@@ -17,23 +17,39 @@ use {
     fmt::{self, Formatter},
     num::ParseIntError,
     ops::{Add, AddAssign, Sub},
-    sync::LazyLock,
   },
   thiserror::Error,
 };
 
 pub use {
-  artifact::Artifact, cenotaph::Cenotaph, charm::Charm, decimal_koinu::DecimalKoinu,
-  degree::Degree, dune::Dune, dune_id::DuneId, dunestone::Dunestone, edict::Edict, epoch::Epoch,
-  etching::Etching, flaw::Flaw, height::Height, koinu::Koinu, koinu_point::KoinuPoint, pile::Pile,
-  rarity::Rarity, spaced_dune::SpacedDune, terms::Terms,
+  artifact::Artifact, cenotaph::Cenotaph, charm::Charm, charm_heights::CharmHeights,
+  decimal_koinu::DecimalKoinu, degree::Degree, dune::Dune, dune_id::DuneId, dunestone::Dunestone,
+  edict::Edict, epoch::Epoch, etching::Etching, flaw::Flaw, height::Height, koinu::Koinu,
+  koinu_point::KoinuPoint, pile::Pile, rarity::Rarity, spaced_dune::SpacedDune, terms::Terms,
+};
+
+pub use epoch::{
+  WONKY_ERA_LEN, WONKY_JACKPOT_HEIGHT, WONKY_PITTANCE_HEIGHT, WONKY_STARTING_KOINU, WONKY_SUBSIDIES,
 };
 
 pub const COIN_VALUE: u64 = 100_000_000;
-pub const CYCLE_EPOCHS: u32 = 6;
+
+/// Number of Dogecoin halving epochs in a degree cycle: the smallest number
+/// of `DOGECOIN_HALVING_INTERVAL`s that is also a multiple of
+/// `DIFFCHANGE_INTERVAL`, so that a new cycle starts on both a halving and a
+/// period boundary: lcm(100,000, 240) = 300,000 blocks.
+pub const CYCLE_EPOCHS: u32 = 3;
 
 // Dogecoin-specific chain constants.
-pub const DIFFCHANGE_INTERVAL: u32 = 1;
+
+/// Dogecoin's original difficulty retarget interval: 4 hours of one-minute
+/// blocks.  DigiShield retargets every block since 145,000, but degree
+/// notation and rarity keep the 240-block period so that rare koinu remain
+/// rare.
+///
+/// Source: `dogecoin/src/chainparams.cpp` `nPowTargetTimespan /
+/// nPowTargetSpacing` for the pre-DigiShield consensus.
+pub const DIFFCHANGE_INTERVAL: u32 = 240;
 
 /// The actual Dogecoin subsidy halving interval: every 100,000 blocks after
 /// the wonky era.
@@ -57,6 +73,7 @@ fn default<T: Default>() -> T {
 mod artifact;
 mod cenotaph;
 mod charm;
+mod charm_heights;
 mod decimal_koinu;
 mod degree;
 mod dune;
@@ -1,7 +1,7 @@
 use super::*;
 
 #[derive(
-  Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, PartialOrd, SerializeDisplay,
+  Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, Ord, PartialEq, PartialOrd, SerializeDisplay,
 )]
 pub enum Rarity {
   Common,
@@ -22,14 +22,28 @@ impl Rarity {
     Rarity::Mythic,
   ];
 
-  pub fn supply(self) -> u64 {
+  /// Number of koinu of this rarity mined in blocks before `height`.
+  ///
+  /// Dogecoin has no final block subsidy, so supply only makes sense at a
+  /// given height.  Only the first koinu of a block can be uncommon or
+  /// better; the rest of every block reward is common.
+  pub fn supply(self, height: Height) -> u64 {
+    let blocks = u64::from(height.n());
+
+    let boundaries = |interval: u32| blocks.div_ceil(u64::from(interval));
+
+    let mythic = blocks.min(1);
+    let legendary = boundaries(CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL) - mythic;
+    let epic = boundaries(DOGECOIN_HALVING_INTERVAL) - legendary - mythic;
+    let rare = boundaries(DIFFCHANGE_INTERVAL) - legendary - mythic;
+
     match self {
-      Self::Common => 2_099_999_990_760_000,
-      Self::Uncommon => 6_926_535,
-      Self::Rare => 3_432,
-      Self::Epic => 27,
-      Self::Legendary => 5,
-      Self::Mythic => 1,
+      Self::Common => height.starting_sat().n() - blocks,
+      Self::Uncommon => blocks - rare - epic - legendary - mythic,
+      Self::Rare => rare,
+      Self::Epic => epic,
+      Self::Legendary => legendary,
+      Self::Mythic => mythic,
     }
   }
 }
@@ -120,42 +134,28 @@ mod tests {
 
   #[test]
   fn rarity() {
-    assert_eq!(Koinu(0).rarity(), Rarity::Mythic);
-    assert_eq!(Koinu(1).rarity(), Rarity::Common);
-
-    assert_eq!(Koinu(50 * COIN_VALUE - 1).rarity(), Rarity::Common);
-    assert_eq!(Koinu(50 * COIN_VALUE).rarity(), Rarity::Uncommon);
-    assert_eq!(Koinu(50 * COIN_VALUE + 1).rarity(), Rarity::Common);
-
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(DIFFCHANGE_INTERVAL) - 1).rarity(),
-      Rarity::Common
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(DIFFCHANGE_INTERVAL)).rarity(),
-      Rarity::Rare
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(DIFFCHANGE_INTERVAL) + 1).rarity(),
-      Rarity::Common
-    );
-
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(SUBSIDY_HALVING_INTERVAL) - 1).rarity(),
-      Rarity::Common
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(SUBSIDY_HALVING_INTERVAL)).rarity(),
-      Rarity::Epic
-    );
-    assert_eq!(
-      Koinu(50 * COIN_VALUE * u64::from(SUBSIDY_HALVING_INTERVAL) + 1).rarity(),
-      Rarity::Common
-    );
-
-    assert_eq!(Koinu(2067187500000000 - 1).rarity(), Rarity::Common);
-    assert_eq!(Koinu(2067187500000000).rarity(), Rarity::Legendary);
-    assert_eq!(Koinu(2067187500000000 + 1).rarity(), Rarity::Common);
+    #[track_caller]
+    fn case(height: u32, rarity: Rarity) {
+      let sat = Height(height).starting_sat();
+      assert_eq!(sat.rarity(), rarity, "rarity of first koinu at {height}");
+      assert_eq!((sat + 1).rarity(), Rarity::Common);
+      assert_eq!(
+        Koinu(Height(height + 1).starting_sat().n() - 1).rarity(),
+        Rarity::Common
+      );
+    }
+
+    case(0, Rarity::Mythic);
+    case(1, Rarity::Uncommon);
+    case(239, Rarity::Uncommon);
+    case(240, Rarity::Rare);
+    case(99_999, Rarity::Uncommon);
+    case(100_000, Rarity::Epic);
+    case(100_080, Rarity::Rare);
+    case(200_000, Rarity::Epic);
+    case(300_000, Rarity::Legendary);
+    case(600_000, Rarity::Legendary);
+    case(700_000, Rarity::Epic);
   }
 
   #[test]
@@ -196,34 +196,43 @@ mod tests {
 
   #[test]
   fn supply() {
-    let mut i = 0;
-
-    let mut supply = HashMap::<Rarity, u64>::new();
-
-    for height in 0.. {
-      let subsidy = Height(height).subsidy();
-
-      if subsidy == 0 {
-        break;
+    for height in [
+      0, 1, 2, 240, 241, 100_000, 100_001, 300_000, 300_001, 610_000,
+    ] {
+      let mut supply = HashMap::<Rarity, u64>::new();
+
+      for h in 0..height {
+        let first = Height(h).starting_sat();
+        *supply.entry(first.rarity()).or_default() += 1;
+        *supply.entry(Rarity::Common).or_default() +=
+          Height(h + 1).starting_sat().n() - first.n() - 1;
       }
 
-      *supply.entry(Koinu(i).rarity()).or_default() += 1;
-
-      *supply.entry(Rarity::Common).or_default() += subsidy.saturating_sub(1);
-
-      i += subsidy;
-    }
+      for rarity in Rarity::ALL {
+        assert_eq!(
+          rarity.supply(Height(height)),
+          supply.get(&rarity).copied().unwrap_or_default(),
+          "invalid supply for rarity {rarity} at height {height}"
+        );
+      }
 
-    for (rarity, supply) in &supply {
       assert_eq!(
-        rarity.supply(),
-        *supply,
-        "invalid supply for rarity {rarity}"
+        Rarity::ALL
+          .iter()
+          .map(|rarity| rarity.supply(Height(height)))
+          .sum::<u64>(),
+        Height(height).starting_sat().n(),
       );
     }
+  }
 
-    assert_eq!(supply.values().sum::<u64>(), Koinu::SUPPLY);
-
-    assert_eq!(supply.len(), Rarity::ALL.len());
+  #[test]
+  fn supply_at_cycle_boundaries() {
+    let height = Height(2 * CYCLE_EPOCHS * DOGECOIN_HALVING_INTERVAL + 1);
+    assert_eq!(Rarity::Mythic.supply(height), 1);
+    assert_eq!(Rarity::Legendary.supply(height), 2);
+    assert_eq!(Rarity::Epic.supply(height), 4);
+    assert_eq!(Rarity::Rare.supply(height), 2498);
+    assert_eq!(Rarity::Uncommon.supply(height), 600_001 - 2498 - 4 - 2 - 1);
   }
 }
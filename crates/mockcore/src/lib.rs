@@ -15,7 +15,7 @@ use {
     consensus::encode::{deserialize, serialize},
     hash_types::{BlockHash, TxMerkleNode},
     hashes::Hash,
-    key::{Keypair, Secp256k1, TapTweak, XOnlyPublicKey},
+    key::{Keypair, PublicKey, Secp256k1, TapTweak, XOnlyPublicKey},
     locktime::absolute::LockTime,
     opcodes,
     pow::CompactTarget,
@@ -94,6 +94,7 @@ pub struct ListDescriptorsResult {
 pub fn builder() -> Builder {
   Builder {
     fail_lock_unspent: false,
+    legacy_addresses: false,
     network: Network::Bitcoin,
     version: 280000,
   }
@@ -101,6 +102,7 @@ pub fn builder() -> Builder {
 
 pub struct Builder {
   fail_lock_unspent: bool,
+  legacy_addresses: bool,
   network: Network,
   version: usize,
 }
@@ -113,6 +115,13 @@ impl Builder {
     }
   }
 
+  pub fn legacy_addresses(self, legacy_addresses: bool) -> Self {
+    Self {
+      legacy_addresses,
+      ..self
+    }
+  }
+
   pub fn network(self, network: Network) -> Self {
     Self { network, ..self }
   }
@@ -126,6 +135,7 @@ impl Builder {
       self.network,
       self.version,
       self.fail_lock_unspent,
+      self.legacy_addresses,
     )));
     let server = Server::new(state.clone());
     let mut io = IoHandler::default();
@@ -169,6 +179,7 @@ pub struct TransactionTemplate<'a> {
   pub outputs: usize,
   pub p2tr: bool,
   pub recipient: Option<Address>,
+  pub script_sig: ScriptBuf,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -218,6 +229,7 @@ impl Default for TransactionTemplate<'_> {
       outputs: 1,
       p2tr: false,
       recipient: None,
+      script_sig: ScriptBuf::new(),
     }
   }
 }
@@ -406,7 +406,12 @@ impl Api for Server {
 
     if (output_value + estimated_fee) > input_value {
       for (value, outpoint) in utxos {
-        if state.locked.contains(&outpoint) {
+        if state.locked.contains(&outpoint)
+          || transaction
+            .input
+            .iter()
+            .any(|txin| txin.previous_output == outpoint)
+        {
           continue;
         }
 
@@ -987,10 +992,13 @@ impl Api for Server {
     &self,
     psbt: String,
     sign: Option<bool>,
-    sighash_type: Option<()>,
+    sighash_type: Option<String>,
     bip32derivs: Option<bool>,
   ) -> Result<WalletProcessPsbtResult, jsonrpc_core::Error> {
-    assert!(sighash_type.is_none());
+    assert!(
+      sighash_type.is_none() || sighash_type.as_deref() == Some("SINGLE|ANYONECANPAY"),
+      "unsupported sighash_type",
+    );
     assert!(bip32derivs.is_none());
 
     let mut psbt = Psbt::deserialize(
@@ -1001,29 +1009,31 @@ impl Api for Server {
     .unwrap();
 
     for (i, txin) in psbt.unsigned_tx.input.iter().enumerate() {
-      psbt.inputs[i].witness_utxo = Some(
-        self
-          .state()
-          .transactions
-          .get(&txin.previous_output.txid)
-          .unwrap()
-          .output[txin.previous_output.vout as usize]
-          .clone(),
-      );
+      if let Some(tx) = self.state().transactions.get(&txin.previous_output.txid) {
+        psbt.inputs[i].witness_utxo = Some(tx.output[txin.previous_output.vout as usize].clone());
+      }
+    }
+
+    // Signatures are placeholders, but carry the sighash type, which is
+    // appended to taproot signatures other than the default
+    let mut signature = vec![0; 64];
+
+    if sighash_type.is_some() {
+      signature.push(0x83);
     }
 
     if let Some(sign) = sign
       && sign
     {
       for input in psbt.inputs.iter_mut() {
-        let address = Address::from_script(
-          &input.witness_utxo.as_ref().unwrap().script_pubkey,
-          self.network,
-        )
-        .unwrap();
+        let Some(witness_utxo) = &input.witness_utxo else {
+          continue;
+        };
+
+        let address = Address::from_script(&witness_utxo.script_pubkey, self.network).unwrap();
 
         if self.state().is_wallet_address(&address) {
-          input.final_script_witness = Some(Witness::from_slice(&[&[0; 64]]));
+          input.final_script_witness = Some(Witness::from_slice(&[&signature]));
         }
       }
     }
@@ -1041,15 +1051,20 @@ impl Api for Server {
   ) -> Result<FinalizePsbtResult, jsonrpc_core::Error> {
     assert!(extract.is_none());
 
-    let mut transaction = Psbt::deserialize(
+    let psbt = Psbt::deserialize(
       &base64::engine::general_purpose::STANDARD
         .decode(psbt)
         .unwrap(),
     )
-    .unwrap()
-    .unsigned_tx;
+    .unwrap();
+
+    let mut transaction = psbt.unsigned_tx;
+
+    for (input, psbt_input) in transaction.input.iter_mut().zip(psbt.inputs) {
+      if let Some(witness) = psbt_input.final_script_witness {
+        input.witness = witness;
+      }
 
-    for input in &mut transaction.input {
       if input.witness.is_empty() {
         input.witness = Witness::from_slice(&[&[0; 64]]);
       }
@@ -7,6 +7,7 @@ pub struct State {
   pub descriptors: Vec<(String, bitcoincore_rpc::json::Timestamp)>,
   pub fail_lock_unspent: bool,
   pub hashes: Vec<BlockHash>,
+  pub legacy_addresses: bool,
   pub loaded_wallets: BTreeSet<String>,
   pub locked: BTreeSet<OutPoint>,
   pub mempool: Vec<Transaction>,
@@ -22,7 +23,12 @@ pub struct State {
 }
 
 impl State {
-  pub(crate) fn new(network: Network, version: usize, fail_lock_unspent: bool) -> Self {
+  pub(crate) fn new(
+    network: Network,
+    version: usize,
+    fail_lock_unspent: bool,
+    legacy_addresses: bool,
+  ) -> Self {
     let mut hashes = Vec::new();
     let mut blocks = BTreeMap::new();
 
@@ -37,6 +43,7 @@ impl State {
       descriptors: Vec::new(),
       fail_lock_unspent,
       hashes,
+      legacy_addresses,
       loaded_wallets: BTreeSet::new(),
       locked: BTreeSet::new(),
       mempool: Vec::new(),
@@ -47,7 +54,7 @@ impl State {
       txid_to_block_height: BTreeMap::new(),
       utxos: BTreeMap::new(),
       version,
-      wallet: Wallet::new(network),
+      wallet: Wallet::new(network, legacy_addresses),
       wallets: BTreeSet::new(),
     }
   }
@@ -85,7 +92,12 @@ impl State {
   }
 
   pub(crate) fn clear(&mut self) {
-    *self = Self::new(self.network, self.version, self.fail_lock_unspent);
+    *self = Self::new(
+      self.network,
+      self.version,
+      self.fail_lock_unspent,
+      self.legacy_addresses,
+    );
   }
 
   #[track_caller]
@@ -208,7 +220,7 @@ impl State {
   pub(crate) fn broadcast_tx(&mut self, template: TransactionTemplate) -> Txid {
     let mut total_value = 0;
     let mut input = Vec::new();
-    for (height, tx, vout, witness) in template.inputs.iter() {
+    for (i, (height, tx, vout, witness)) in template.inputs.iter().enumerate() {
       let block_hash = self
         .hashes
         .get(*height)
@@ -233,7 +245,11 @@ impl State {
 
       input.push(TxIn {
         previous_output: OutPoint::new(tx.compute_txid(), *vout as u32),
-        script_sig: ScriptBuf::new(),
+        script_sig: if i == 0 {
+          template.script_sig.clone()
+        } else {
+          ScriptBuf::new()
+        },
         sequence: Sequence::MAX,
         witness: witness.clone(),
       });
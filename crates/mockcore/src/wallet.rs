@@ -3,6 +3,7 @@ use super::*;
 #[derive(Debug)]
 pub struct Wallet {
   address_indices: HashMap<Address, u32>,
+  legacy: bool,
   master_key: Xpriv,
   network: Network,
   next_index: u32,
@@ -11,7 +12,7 @@ pub struct Wallet {
 }
 
 impl Wallet {
-  pub fn new(network: Network) -> Self {
+  pub fn new(network: Network, legacy: bool) -> Self {
     let derivation_path = DerivationPath::master()
       .child(ChildNumber::Hardened { index: 86 })
       .child(ChildNumber::Hardened { index: 0 })
@@ -20,6 +21,7 @@ impl Wallet {
 
     Self {
       address_indices: HashMap::new(),
+      legacy,
       master_key: Xpriv::new_master(network, &[]).unwrap(),
       network,
       next_index: 0,
@@ -41,9 +43,13 @@ impl Wallet {
         .unwrap();
 
       let keypair = derived_key.to_keypair(&self.secp);
-      let (internal_key, _parity) = XOnlyPublicKey::from_keypair(&keypair);
 
-      let script = ScriptBuf::new_p2tr(&self.secp, internal_key, None);
+      let script = if self.legacy {
+        ScriptBuf::new_p2pkh(&PublicKey::new(keypair.public_key()).pubkey_hash())
+      } else {
+        let (internal_key, _parity) = XOnlyPublicKey::from_keypair(&keypair);
+        ScriptBuf::new_p2tr(&self.secp, internal_key, None)
+      };
 
       Address::from_script(&script, self.network).unwrap()
     };
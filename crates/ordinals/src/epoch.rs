@@ -10,14 +10,14 @@ use super::*;
 /// Cumulative shiboshi totals at each block boundary during the wonky era.
 /// `STARTING_KOINU[n]` is the total number of shiboshis minted before block n.
 static STARTING_KOINU: LazyLock<Vec<u64>> = LazyLock::new(|| {
-  serde_json::from_str(include_str!("../../../starting_koinu.json"))
+  serde_json::from_str(include_str!("../../doginals/starting_koinu.json"))
     .expect("starting_koinu.json must be valid JSON")
 });
 
 /// Per-block subsidy (in shiboshis) for every block in the wonky era.
 /// Keyed by block height as a string (matches the JSON format).
 static SUBSIDIES: LazyLock<std::collections::HashMap<u32, u64>> = LazyLock::new(|| {
-  let raw: serde_json::Value = serde_json::from_str(include_str!("../../../subsidies.json"))
+  let raw: serde_json::Value = serde_json::from_str(include_str!("../../doginals/subsidies.json"))
     .expect("subsidies.json must be valid JSON");
   raw["epochs"]
     .as_object()
@@ -106,6 +106,8 @@ pub struct Inscription {
   pub content_type: Option<String>,
   pub effective_content_type: Option<String>,
   pub fee: u64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub first_of_content: Option<bool>,
   pub height: u32,
   pub id: InscriptionId,
   pub metaprotocol: Option<String>,
@@ -159,6 +161,13 @@ pub struct Inscriptions {
   pub page_index: u32,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TextSearch {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: usize,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct UtxoRecursive {
   pub inscriptions: Option<Vec<InscriptionId>>,
@@ -238,6 +247,65 @@ pub struct SatInscription {
   pub id: Option<InscriptionId>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct KoinuStats {
+  pub height: Option<u32>,
+  pub supply: u64,
+  pub rarity: BTreeMap<Rarity, u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct CollectionHolder {
+  pub address: String,
+  pub count: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CollectionHolders {
+  pub holders: Vec<CollectionHolder>,
+  pub more: bool,
+  pub page: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct CollectionStats {
+  pub parent: InscriptionId,
+  pub height: Option<u32>,
+  pub child_count: u64,
+  pub unique_holders: u64,
+  pub holder_distribution: BTreeMap<u64, u64>,
+  pub first_mint_height: Option<u32>,
+  pub last_mint_height: Option<u32>,
+  pub content_types: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct CollectionTraitValue {
+  pub count: u64,
+  pub rarity_score: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct CollectionTraits {
+  pub parent: InscriptionId,
+  pub members: u64,
+  pub traits: BTreeMap<String, BTreeMap<String, CollectionTraitValue>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CollectionTraitInscriptions {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentHashInscriptions {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: u64,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct SatInscriptions {
   pub ids: Vec<InscriptionId>,
@@ -251,10 +319,110 @@ pub struct AddressInfo {
   pub inscriptions: Option<Vec<InscriptionId>>,
   pub sat_balance: u64,
   pub dunes_balances: Option<Vec<(SpacedDune, Decimal, Option<char>)>>,
+  #[serde(default)]
+  pub drc20_balances: BTreeMap<String, Drc20Balance>,
+  #[serde(default)]
+  pub names: Vec<String>,
+  #[serde(default)]
+  pub dogemaps: Vec<u32>,
+  /// `Some(true)` if outputs and balances came from Dogecoin Core's address
+  /// index, `Some(false)` if that lookup failed and they are unknown.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub lazy_lookup: Option<bool>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddressInscriptions {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddressPortfolio {
+  pub address: String,
+  pub sat_balance: u64,
+  pub inscriptions: Vec<InscriptionId>,
+  pub dunes_balances: Vec<(SpacedDune, Decimal, Option<char>)>,
+  pub drc20_balances: BTreeMap<String, Drc20Balance>,
+  pub names: Vec<String>,
+  pub dogemaps: Vec<u32>,
+  /// Whether a later page holds more inscriptions, DRC-20 balances or dogemaps.
+  pub more: bool,
+  pub page: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum DuneActivityKind {
+  Etch,
+  Mint,
+  Transfer,
+  Burn,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct DuneActivity {
+  pub kind: DuneActivityKind,
+  pub amount: u128,
+  pub address: Option<String>,
+  pub txid: Txid,
+  pub height: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuneActivities {
+  pub activity: Vec<DuneActivity>,
+  pub more: bool,
+  pub page: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuneMints {
+  pub id: DuneId,
+  pub start: u32,
+  pub end: u32,
+  pub mints: u64,
+  pub blocks: BTreeMap<u32, u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct MintableDune {
+  pub id: DuneId,
+  pub spaced_dune: SpacedDune,
+  pub amount: u128,
+  pub cap: u128,
+  pub mints: u128,
+  pub remaining: u128,
+  pub end: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct DuneHolder {
+  pub address: String,
+  pub amount: u128,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuneHolders {
+  pub holders: Vec<DuneHolder>,
+  pub more: bool,
+  pub page: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Drc20Balance {
+  pub available: u128,
+  pub transferable: u128,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Drc20BalanceEntry {
+  pub tick: String,
+  pub available: u128,
+  pub transferable: u128,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Offers {
   pub offers: Vec<String>,
@@ -49,6 +49,15 @@ impl Chain {
     self.into()
   }
 
+  /// Magic bytes prefixing every block record in this chain's `.blk` files.
+  pub(crate) fn magic(self) -> [u8; 4] {
+    match self {
+      Self::Dogecoin => chainparams::MAGIC_MAINNET,
+      Self::DogecoinTestnet => chainparams::MAGIC_TESTNET,
+      Self::DogecoinRegtest => chainparams::MAGIC_REGTEST,
+    }
+  }
+
   pub(crate) fn is_dogecoin(self) -> bool {
     true // all chains are Dogecoin
   }
@@ -69,9 +78,9 @@ impl Chain {
   }
 
   /// Block height at which AuxPoW (Auxiliary Proof of Work) activates.
-  /// Dogecoin blocks at or above this height have extended headers that the
-  /// bitcoin crate cannot deserialize from raw RPC. Use getblockheader verbose=1
-  /// instead of the raw format for these blocks.
+  /// Dogecoin blocks at or above this height may have extended headers that the
+  /// bitcoin crate cannot deserialize, so raw blocks are parsed with
+  /// `DogecoinBlock::parse` instead.
   pub(crate) fn auxpow_activation_height(self) -> u32 {
     match self {
       Self::Dogecoin => 371_337,         // Dogecoin Core 1.8, Sept 2014
@@ -101,6 +110,33 @@ impl Chain {
     }
   }
 
+  /// Heights of the blocks that chain-specific charms are awarded for. The
+  /// wonky-era jackpot and pittance blocks only exist on mainnet, and regtest
+  /// never activates AuxPoW.
+  pub(crate) fn charm_heights(self) -> CharmHeights {
+    match self {
+      Self::Dogecoin => CharmHeights {
+        auxpow: Some(self.auxpow_activation_height()),
+        jackpot: Some(doginals::WONKY_JACKPOT_HEIGHT),
+        jubilee: Some(self.jubilee_height()),
+        pittance: Some(doginals::WONKY_PITTANCE_HEIGHT),
+      },
+      // `nAuxpowStartHeight` in `dogecoin/src/chainparams.cpp`
+      Self::DogecoinTestnet => CharmHeights {
+        auxpow: Some(158_100),
+        jackpot: None,
+        jubilee: Some(self.jubilee_height()),
+        pittance: None,
+      },
+      Self::DogecoinRegtest => CharmHeights {
+        auxpow: None,
+        jackpot: None,
+        jubilee: Some(self.jubilee_height()),
+        pittance: None,
+      },
+    }
+  }
+
   pub(crate) fn genesis_block(self) -> Block {
     let genesis_hex: &str = match self {
       Self::Dogecoin => {
@@ -134,7 +170,7 @@ impl Chain {
 
   /// Returns the correct Dogecoin address string from a script, using
   /// Dogecoin's base58check version bytes (P2PKH=0x1e → "D...", P2SH=0x16 → "A...").
-  pub(crate) fn address_string_from_script(self, script: &Script) -> Option<String> {
+  pub fn address_string_from_script(self, script: &Script) -> Option<String> {
     dogecoin_address_string(script)
   }
 
@@ -246,6 +282,35 @@ mod tests {
     assert_eq!(Chain::DogecoinRegtest.default_rpc_port(), 18444);
   }
 
+  #[test]
+  fn mainnet_charm_heights() {
+    let heights = Chain::Dogecoin.charm_heights();
+
+    let subsidies = &doginals::WONKY_SUBSIDIES;
+    let max = subsidies.iter().max().unwrap();
+    let min = subsidies.iter().min().unwrap();
+
+    assert_eq!(subsidies[heights.jackpot.unwrap() as usize], *max);
+    assert_eq!(subsidies[heights.pittance.unwrap() as usize], *min);
+    assert_eq!(heights.jackpot, Some(13_329));
+    assert_eq!(heights.pittance, Some(129_756));
+    assert_eq!(heights.auxpow, Some(371_337));
+    assert_eq!(heights.jubilee, Some(4_609_720));
+  }
+
+  #[test]
+  fn non_mainnet_charm_heights() {
+    for chain in [Chain::DogecoinTestnet, Chain::DogecoinRegtest] {
+      let heights = chain.charm_heights();
+      assert_eq!(heights.jackpot, None);
+      assert_eq!(heights.pittance, None);
+      assert_eq!(heights.jubilee, Some(chain.jubilee_height()));
+    }
+
+    assert_eq!(Chain::DogecoinTestnet.charm_heights().auxpow, Some(158_100));
+    assert_eq!(Chain::DogecoinRegtest.charm_heights().auxpow, None);
+  }
+
   #[test]
   fn genesis_blocks_parse() {
     let _ = Chain::Dogecoin.genesis_block();
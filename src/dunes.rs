@@ -4003,6 +4003,89 @@ mod tests {
     );
   }
 
+  #[test]
+  fn mintable_dunes_and_block_mints() {
+    let context = Context::builder()
+      .args(["--index-dunes", "--integration-test"])
+      .build();
+
+    let (_txid, id) = context.etch(
+      Dunestone {
+        etching: Some(Etching {
+          dune: Some(Dune(DUNE)),
+          terms: Some(Terms {
+            cap: Some(2),
+            amount: Some(1000),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    let mintable = |mints, remaining| api::MintableDune {
+      id,
+      spaced_dune: SpacedDune {
+        dune: Dune(DUNE),
+        spacers: 0,
+      },
+      amount: 1000,
+      cap: 2,
+      mints,
+      remaining,
+      end: None,
+    };
+
+    assert_eq!(
+      context.index.get_mintable_dunes().unwrap(),
+      [mintable(0, 2)]
+    );
+
+    let mut heights = Vec::new();
+
+    for block in [3, 4] {
+      context.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(block, 0, 0, Witness::new())],
+        op_return: Some(
+          Dunestone {
+            mint: Some(id),
+            ..default()
+          }
+          .encipher(),
+        ),
+        ..default()
+      });
+
+      context.mine_blocks(1);
+
+      heights.push(context.index.block_height().unwrap().unwrap().n());
+
+      if block == 3 {
+        assert_eq!(
+          context.index.get_mintable_dunes().unwrap(),
+          [mintable(1, 1)]
+        );
+      }
+    }
+
+    assert!(context.index.get_mintable_dunes().unwrap().is_empty());
+
+    assert_eq!(
+      context.index.get_dune_block_mints(id, 0, u32::MAX).unwrap(),
+      [(heights[0], 1), (heights[1], 1)].into_iter().collect(),
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_dune_block_mints(id, heights[1], heights[1] + 1)
+        .unwrap(),
+      [(heights[1], 1)].into_iter().collect(),
+    );
+  }
+
   #[test]
   fn etching_with_amount_can_be_minted() {
     let context = Context::builder().arg("--index-dunes").build();
@@ -5951,6 +6034,77 @@ mod tests {
     context.assert_dunes([], []);
   }
 
+  #[test]
+  fn commit_heights_are_read_from_the_index() {
+    let context = Context::builder()
+      .args(["--index-dunes", "--integration-test"])
+      .build();
+
+    let block_count = context.index.block_count().unwrap().into_usize();
+
+    context.mine_blocks(1);
+
+    let commit = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count, 0, 0, Witness::new())],
+      p2tr: true,
+      ..default()
+    });
+
+    context.mine_blocks(Dunestone::COMMIT_CONFIRMATIONS.into());
+
+    let mut witness = Witness::new();
+
+    let tapscript = script::Builder::new()
+      .push_slice::<&PushBytes>(Dune(DUNE).commitment().as_slice().try_into().unwrap())
+      .into_script();
+
+    witness.push(tapscript);
+
+    witness.push([]);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count + 1, 1, 0, witness)],
+      op_return: Some(
+        Dunestone {
+          etching: Some(Etching {
+            dune: Some(Dune(DUNE)),
+            ..default()
+          }),
+          ..default()
+        }
+        .encipher(),
+      ),
+      outputs: 1,
+      ..default()
+    });
+
+    context.core.state().txid_to_block_height.remove(&commit);
+
+    context.mine_blocks(1);
+
+    let id = DuneId {
+      block: u64::try_from(block_count + usize::from(Dunestone::COMMIT_CONFIRMATIONS) + 1).unwrap(),
+      tx: 1,
+    };
+
+    context.assert_dunes(
+      [(
+        id,
+        DuneEntry {
+          block: id.block,
+          etching: txid,
+          spaced_dune: SpacedDune {
+            dune: Dune(DUNE),
+            spacers: 0,
+          },
+          timestamp: id.block,
+          ..default()
+        },
+      )],
+      [],
+    );
+  }
+
   #[test]
   fn tx_commits_to_dune_ignores_invalid_script() {
     let context = Context::builder().arg("--index-dunes").build();
@@ -5986,6 +6140,121 @@ mod tests {
     context.assert_dunes([], []);
   }
 
+  /// Commits to `DUNE` in an output that is P2SH if `p2sh` is set and P2PKH
+  /// otherwise, and reveals it in an etching with `confirmations`
+  /// confirmations, spending the commit output with the commitment in its
+  /// redeem script. Returns the etching and the ID the dune would get.
+  fn etch_with_redeem_script_commitment(
+    context: &Context,
+    confirmations: u16,
+    p2sh: bool,
+  ) -> (Txid, DuneId) {
+    let block_count = context.index.block_count().unwrap().into_usize();
+
+    context.mine_blocks(1);
+
+    let redeem_script = script::Builder::new()
+      .push_slice::<&PushBytes>(Dune(DUNE).commitment().as_slice().try_into().unwrap())
+      .push_opcode(opcodes::all::OP_DROP)
+      .push_opcode(opcodes::OP_TRUE)
+      .into_script();
+
+    let recipient = if p2sh {
+      Address::p2sh(&redeem_script, Network::Regtest).unwrap()
+    } else {
+      Address::p2pkh(
+        bitcoin::PubkeyHash::from_byte_array([1; 20]),
+        Network::Regtest,
+      )
+    };
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count, 0, 0, Witness::new())],
+      recipient: Some(recipient),
+      ..default()
+    });
+
+    context.mine_blocks((confirmations - 1).into());
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count + 1, 1, 0, Witness::new())],
+      op_return: Some(
+        Dunestone {
+          etching: Some(Etching {
+            dune: Some(Dune(DUNE)),
+            ..default()
+          }),
+          ..default()
+        }
+        .encipher(),
+      ),
+      outputs: 1,
+      script_sig: script::Builder::new()
+        .push_slice::<&PushBytes>(redeem_script.as_bytes().try_into().unwrap())
+        .into_script(),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    (
+      txid,
+      DuneId {
+        block: u64::try_from(block_count + usize::from(confirmations)).unwrap(),
+        tx: 1,
+      },
+    )
+  }
+
+  #[test]
+  fn mature_p2sh_commits_are_valid() {
+    let context = Context::builder()
+      .args(["--index-dunes", "--integration-test"])
+      .build();
+
+    let (txid, id) =
+      etch_with_redeem_script_commitment(&context, Dunestone::COMMIT_CONFIRMATIONS, true);
+
+    context.assert_dunes(
+      [(
+        id,
+        DuneEntry {
+          block: id.block,
+          etching: txid,
+          spaced_dune: SpacedDune {
+            dune: Dune(DUNE),
+            spacers: 0,
+          },
+          timestamp: id.block,
+          ..default()
+        },
+      )],
+      [],
+    );
+  }
+
+  #[test]
+  fn immature_p2sh_commits_are_not_valid() {
+    let context = Context::builder()
+      .args(["--index-dunes", "--integration-test"])
+      .build();
+
+    etch_with_redeem_script_commitment(&context, Dunestone::COMMIT_CONFIRMATIONS - 1, true);
+
+    context.assert_dunes([], []);
+  }
+
+  #[test]
+  fn redeem_script_commits_are_not_valid_for_non_p2sh_outputs() {
+    let context = Context::builder()
+      .args(["--index-dunes", "--integration-test"])
+      .build();
+
+    etch_with_redeem_script_commitment(&context, Dunestone::COMMIT_CONFIRMATIONS, false);
+
+    context.assert_dunes([], []);
+  }
+
   #[test]
   fn edict_with_amount_zero_and_no_destinations_is_ignored() {
     let context = Context::builder().arg("--index-dunes").build();
@@ -6108,4 +6377,140 @@ mod tests {
         [],
       );
   }
+
+  #[test]
+  fn holders_and_activity_follow_etch_transfer_and_burn() {
+    let context = Context::builder()
+      .arg("--index-dunes")
+      .arg("--index-addresses")
+      .arg("--integration-test")
+      .build();
+
+    context.mine_blocks(1);
+
+    let alice = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+    let bob = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([2; 20]),
+      Network::Regtest,
+    );
+
+    let chain = Chain::DogecoinRegtest;
+    let alice_address = chain
+      .address_string_from_script(&alice.script_pubkey())
+      .unwrap();
+    let bob_address = chain
+      .address_string_from_script(&bob.script_pubkey())
+      .unwrap();
+
+    // unnamed etchings need no commitment
+    let etch = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      op_return: Some(
+        Dunestone {
+          etching: Some(Etching {
+            premine: Some(1000),
+            ..default()
+          }),
+          ..default()
+        }
+        .encipher(),
+      ),
+      recipient: Some(alice.clone()),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let id = DuneId { block: 2, tx: 1 };
+
+    assert_eq!(
+      context.index.get_dune_holders(id).unwrap(),
+      [api::DuneHolder {
+        address: alice_address.clone(),
+        amount: 1000,
+      }],
+    );
+
+    let transfer = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Witness::new())],
+      recipient: Some(bob.clone()),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.get_dune_holders(id).unwrap(),
+      [api::DuneHolder {
+        address: bob_address.clone(),
+        amount: 1000,
+      }],
+    );
+
+    let burn = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 1, 0, Witness::new())],
+      op_return: Some(
+        Dunestone {
+          edicts: vec![Edict {
+            id,
+            amount: 300,
+            output: 1,
+          }],
+          ..default()
+        }
+        .encipher(),
+      ),
+      recipient: Some(bob),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.get_dune_holders(id).unwrap(),
+      [api::DuneHolder {
+        address: bob_address.clone(),
+        amount: 700,
+      }],
+    );
+
+    let activity = |kind, amount, address: Option<&str>, txid, height| api::DuneActivity {
+      kind,
+      amount,
+      address: address.map(str::to_string),
+      txid,
+      height,
+    };
+
+    pretty_assert_eq!(
+      context
+        .index
+        .get_dune_activity_paginated(id, 100, 0)
+        .unwrap(),
+      (
+        vec![
+          activity(api::DuneActivityKind::Burn, 300, None, burn, 4),
+          activity(
+            api::DuneActivityKind::Transfer,
+            700,
+            Some(&bob_address),
+            burn,
+            4
+          ),
+          activity(
+            api::DuneActivityKind::Transfer,
+            1000,
+            Some(&bob_address),
+            transfer,
+            3
+          ),
+          activity(api::DuneActivityKind::Etch, 1000, None, etch, 2),
+        ],
+        false,
+      ),
+    );
+  }
 }
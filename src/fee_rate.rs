@@ -9,6 +9,11 @@ impl FromStr for FeeRate {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Some(per_kb) = s.to_lowercase().strip_suffix("doge/kb") {
+      let per_kb = Amount::from_str_in(per_kb.trim(), bitcoin::Denomination::Bitcoin)?;
+      return Ok(Self::from_per_kb(per_kb));
+    }
+
     Self::try_from(f64::from_str(s)?)
   }
 }
@@ -25,6 +30,12 @@ impl TryFrom<f64> for FeeRate {
 }
 
 impl FeeRate {
+  /// Fee rate from a fee per kilobyte, the unit used by Dogecoin Core.
+  pub fn from_per_kb(per_kb: Amount) -> Self {
+    #[allow(clippy::cast_precision_loss)]
+    Self(per_kb.to_sat() as f64 / 1000.0)
+  }
+
   pub fn fee(&self, vsize: usize) -> Amount {
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
@@ -69,4 +80,16 @@ mod tests {
       Amount::from_sat(123456789)
     );
   }
+
+  #[test]
+  fn parse_per_kb() {
+    assert_eq!("0.01 doge/kB".parse::<FeeRate>().unwrap().0, 1000.0);
+    assert_eq!("1DOGE/kb".parse::<FeeRate>().unwrap().0, 100_000.0);
+    assert_eq!(
+      "0.01 doge/kB".parse::<FeeRate>().unwrap(),
+      FeeRate::from_per_kb(policy::MIN_FEE_PER_KB),
+    );
+    assert!("-1 doge/kB".parse::<FeeRate>().is_err());
+    assert!("doge/kB".parse::<FeeRate>().is_err());
+  }
 }
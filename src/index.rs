@@ -8,7 +8,7 @@ use {
     event::Event,
     lot::Lot,
     reorg::Reorg,
-    updater::Updater,
+    updater::{Updater, auxpow::DogecoinBlock},
     utxo_entry::{ParsedUtxoEntry, UtxoEntry, UtxoEntryBuf},
   },
   super::*,
@@ -37,7 +37,7 @@ use {
   std::{
     collections::HashMap,
     io::{BufWriter, Write},
-    sync::Once,
+    sync::{Once, OnceLock},
   },
 };
 
@@ -49,14 +49,24 @@ mod fetcher;
 mod lot;
 mod reorg;
 mod rtx;
+pub(crate) mod text;
 pub(crate) mod updater;
 mod utxo_entry;
+pub(crate) mod zmq;
 
 #[cfg(test)]
 pub(crate) mod testing;
 
-const SCHEMA_VERSION: u64 = 34;
+const SCHEMA_VERSION: u64 = 54;
 
+const MAX_CACHED_COLLECTIONS: usize = 256;
+
+/// Bodies that decode to more than this many bytes are not content hashed.
+const MAX_CONTENT_HASH_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+define_multimap_table! { ADDRESS_TO_INSCRIPTION_SEQUENCE_NUMBERS, &str, u32 }
+define_multimap_table! { COLLECTION_TRAIT_TO_SEQUENCE_NUMBERS, (u32, &str, &str), u32 }
+define_multimap_table! { CONTENT_HASH_TO_SEQUENCE_NUMBER, &[u8; 32], u32 }
 define_multimap_table! { LATEST_CHILD_SEQUENCE_NUMBER_TO_COLLECTION_SEQUENCE_NUMBER, u32, u32 }
 define_multimap_table! { SAT_TO_SEQUENCE_NUMBER, u64, u32 }
 define_multimap_table! { SCRIPT_PUBKEY_TO_OUTPOINT, &[u8], OutPointValue }
@@ -67,19 +77,32 @@ define_table! { HEIGHT_TO_BLOCK_HEADER, u32, &HeaderValue }
 define_table! { HEIGHT_TO_BLOCK_HASH, u32, &[u8; 32] }
 define_table! { HEIGHT_TO_BLK_FILE_POS, u32, &[u8; 44] }
 define_table! { HEIGHT_TO_LAST_SEQUENCE_NUMBER, u32, u32 }
+define_table! { HEIGHT_TO_TRANSACTION_COUNT, u32, u32 }
 define_table! { HOME_INSCRIPTIONS, u32, InscriptionIdValue }
 define_table! { INSCRIPTION_ID_TO_SEQUENCE_NUMBER, InscriptionIdValue, u32 }
 define_table! { INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER, i32, u32 }
 define_table! { NUMBER_TO_OFFER, u64, &[u8] }
+// key: unspent P2SH or P2TR output, value: (height, whether the output is P2TR)
+define_table! { OUTPOINT_TO_COMMIT_HEIGHT, &OutPointValue, (u32, bool) }
 define_table! { OUTPOINT_TO_DUNE_BALANCES, &OutPointValue, &[u8] }
+define_table! { OUTPOINT_TO_DUNE_HOLDER, &OutPointValue, &str }
 define_table! { OUTPOINT_TO_UTXO_ENTRY, &OutPointValue, &UtxoEntry }
+// key: (dune id, activity number), value: JSON api::DuneActivity
+define_table! { DUNE_ID_TO_ACTIVITY, (DuneIdValue, u64), &[u8] }
+// key: (dune id, height), value: mints of the dune in that block
+define_table! { DUNE_ID_TO_BLOCK_MINTS, (DuneIdValue, u32), u64 }
 define_table! { DUNE_ID_TO_DUNE_ENTRY, DuneIdValue, DuneEntryValue }
+define_table! { DUNE_ID_TO_HOLDER_BALANCE, (DuneIdValue, &str), u128 }
 define_table! { DUNE_TO_DUNE_ID, u128, DuneIdValue }
 define_table! { SAT_TO_SATPOINT, u64, &KoinuPointValue }
 define_table! { SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY, u32, InscriptionEntryValue }
+define_table! { SEQUENCE_NUMBER_TO_ADDRESS, u32, &str }
+define_table! { SEQUENCE_NUMBER_TO_CONTENT_TYPE, u32, &str }
 define_table! { SEQUENCE_NUMBER_TO_DUNE_ID, u32, DuneIdValue }
 define_table! { SEQUENCE_NUMBER_TO_SATPOINT, u32, &KoinuPointValue }
 define_table! { STATISTIC_TO_COUNT, u64, u64 }
+define_table! { TEXT_TERM_TO_INSCRIPTION_COUNT, &str, u32 }
+define_table! { TEXT_TERM_TO_SEQUENCE_NUMBER, (&str, u32), u32 }
 define_table! { TRANSACTION_ID_TO_DUNE, &TxidValue, u128 }
 define_table! { TRANSACTION_ID_TO_TRANSACTION, &TxidValue, &[u8] }
 define_table! { WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP, u32, u128 }
@@ -95,10 +118,23 @@ define_table! { DRC20_TICK_TO_TOKEN, &str, &[u8] }
 define_table! { DRC20_BALANCE, &str, &[u8] }
 define_table! { DRC20_TRANSFERABLE, &str, &[u8] }
 define_table! { DRC20_OUTPOINT_TO_TRANSFER, &OutPointValue, &[u8] }
+// every address that has been credited with tick_lower
+define_multimap_table! { DRC20_TICK_TO_HOLDERS, &str, &str }
+// key: (tick_lower, activity number), value: JSON Drc20Activity
+define_table! { DRC20_ACTIVITY, (&str, u64), &[u8] }
 
 // Dogemaps table — first-to-inscribe owns a block number forever
 // key: block_number (u32), value: DogemapEntryValue
 define_table! { DOGEMAP_BLOCK_TO_CLAIM, u32, DogemapEntryValue }
+// Dogemap ownership follows the claim inscription as it moves
+// DOGEMAP_BLOCK_TO_TRANSFERS key: (block_number, transfer number), value: JSON
+// DogemapTransfer
+define_table! { DOGEMAP_SEQUENCE_NUMBER_TO_BLOCK, u32, u32 }
+define_table! { DOGEMAP_BLOCK_TO_OWNER, u32, &str }
+define_multimap_table! { DOGEMAP_OWNER_TO_BLOCKS, &str, u32 }
+define_table! { DOGEMAP_BLOCK_TO_TRANSFERS, (u32, u32), &[u8] }
+// Dogemap parcels — key: (block_number, parcel)
+define_table! { DOGEMAP_PARCEL_TO_INSCRIPTION_ID, (u32, u32), InscriptionIdValue }
 
 #[derive(Copy, Clone)]
 pub(crate) enum Statistic {
@@ -122,6 +158,10 @@ pub(crate) enum Statistic {
   DnsNames = 18,
   Drc20Tokens = 19,
   DogemapClaims = 20,
+  Drc20Activity = 21,
+  IndexContentHashes = 22,
+  IndexText = 23,
+  DuneActivity = 24,
 }
 
 impl Statistic {
@@ -221,8 +261,16 @@ impl<T> DogecoinCoreRpcResultExt<T> for Result<T, bitcoincore_rpc::Error> {
   }
 }
 
+/// Collection statistics and holders, computed together because both need a
+/// full pass over the parent's children.
+pub(crate) struct CollectionSummary {
+  pub(crate) stats: api::CollectionStats,
+  pub(crate) holders: Vec<api::CollectionHolder>,
+}
+
 pub struct Index {
   pub(crate) client: Client,
+  collection_summaries: Mutex<HashMap<u32, (Option<BlockHash>, Arc<CollectionSummary>)>>,
   database: Database,
   durability: redb::Durability,
   event_sender: Option<tokio::sync::mpsc::Sender<Event>>,
@@ -230,9 +278,11 @@ pub struct Index {
   genesis_block_coinbase_txid: Txid,
   height_limit: Option<u32>,
   index_addresses: bool,
+  index_content_hashes: bool,
   index_inscriptions: bool,
   index_dunes: bool,
   index_koinu: bool,
+  index_text: bool,
   index_transactions: bool,
   only_protocols: Option<Vec<String>>,
   path: PathBuf,
@@ -241,6 +291,8 @@ pub struct Index {
   first_index_height: u32,
   reorg_count: AtomicU64,
   unrecoverably_reorged: AtomicBool,
+  zmq: Option<zmq::ZmqSubscriber>,
+  zmq_events: OnceLock<Arc<Mutex<std::sync::mpsc::Receiver<zmq::ZmqEvent>>>>,
 }
 
 impl Index {
@@ -332,7 +384,14 @@ impl Index {
         tx.open_table(DRC20_BALANCE)?;
         tx.open_table(DRC20_TRANSFERABLE)?;
         tx.open_table(DRC20_OUTPOINT_TO_TRANSFER)?;
+        tx.open_multimap_table(DRC20_TICK_TO_HOLDERS)?;
+        tx.open_table(DRC20_ACTIVITY)?;
         tx.open_table(DOGEMAP_BLOCK_TO_CLAIM)?;
+        tx.open_table(DOGEMAP_SEQUENCE_NUMBER_TO_BLOCK)?;
+        tx.open_table(DOGEMAP_BLOCK_TO_OWNER)?;
+        tx.open_multimap_table(DOGEMAP_OWNER_TO_BLOCKS)?;
+        tx.open_table(DOGEMAP_BLOCK_TO_TRANSFERS)?;
+        tx.open_table(DOGEMAP_PARCEL_TO_INSCRIPTION_ID)?;
 
         tx.commit()?;
 
@@ -352,6 +411,9 @@ impl Index {
         tx.set_durability(durability)?;
         tx.set_quick_repair(true);
 
+        tx.open_multimap_table(ADDRESS_TO_INSCRIPTION_SEQUENCE_NUMBERS)?;
+        tx.open_multimap_table(COLLECTION_TRAIT_TO_SEQUENCE_NUMBERS)?;
+        tx.open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(LATEST_CHILD_SEQUENCE_NUMBER_TO_COLLECTION_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SCRIPT_PUBKEY_TO_OUTPOINT)?;
@@ -361,18 +423,28 @@ impl Index {
         tx.open_table(HEIGHT_TO_BLOCK_HEADER)?;
         tx.open_table(HEIGHT_TO_BLOCK_HASH)?;
         tx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
+        tx.open_table(HEIGHT_TO_TRANSACTION_COUNT)?;
         tx.open_table(HOME_INSCRIPTIONS)?;
         tx.open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
         tx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
         tx.open_table(NUMBER_TO_OFFER)?;
+        tx.open_table(OUTPOINT_TO_COMMIT_HEIGHT)?;
         tx.open_table(OUTPOINT_TO_DUNE_BALANCES)?;
+        tx.open_table(OUTPOINT_TO_DUNE_HOLDER)?;
         tx.open_table(OUTPOINT_TO_UTXO_ENTRY)?;
+        tx.open_table(DUNE_ID_TO_ACTIVITY)?;
+        tx.open_table(DUNE_ID_TO_BLOCK_MINTS)?;
         tx.open_table(DUNE_ID_TO_DUNE_ENTRY)?;
+        tx.open_table(DUNE_ID_TO_HOLDER_BALANCE)?;
         tx.open_table(DUNE_TO_DUNE_ID)?;
         tx.open_table(SAT_TO_SATPOINT)?;
         tx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+        tx.open_table(SEQUENCE_NUMBER_TO_ADDRESS)?;
+        tx.open_table(SEQUENCE_NUMBER_TO_CONTENT_TYPE)?;
         tx.open_table(SEQUENCE_NUMBER_TO_DUNE_ID)?;
         tx.open_table(SEQUENCE_NUMBER_TO_SATPOINT)?;
+        tx.open_table(TEXT_TERM_TO_INSCRIPTION_COUNT)?;
+        tx.open_table(TEXT_TERM_TO_SEQUENCE_NUMBER)?;
         tx.open_table(TRANSACTION_ID_TO_DUNE)?;
         tx.open_table(WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP)?;
 
@@ -384,7 +456,14 @@ impl Index {
         tx.open_table(DRC20_BALANCE)?;
         tx.open_table(DRC20_TRANSFERABLE)?;
         tx.open_table(DRC20_OUTPOINT_TO_TRANSFER)?;
+        tx.open_multimap_table(DRC20_TICK_TO_HOLDERS)?;
+        tx.open_table(DRC20_ACTIVITY)?;
         tx.open_table(DOGEMAP_BLOCK_TO_CLAIM)?;
+        tx.open_table(DOGEMAP_SEQUENCE_NUMBER_TO_BLOCK)?;
+        tx.open_table(DOGEMAP_BLOCK_TO_OWNER)?;
+        tx.open_multimap_table(DOGEMAP_OWNER_TO_BLOCKS)?;
+        tx.open_table(DOGEMAP_BLOCK_TO_TRANSFERS)?;
+        tx.open_table(DOGEMAP_PARCEL_TO_INSCRIPTION_ID)?;
 
         {
           let mut statistics = tx.open_table(STATISTIC_TO_COUNT)?;
@@ -395,6 +474,12 @@ impl Index {
             u64::from(settings.index_addresses_raw()),
           )?;
 
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::IndexContentHashes,
+            u64::from(settings.index_content_hashes_raw()),
+          )?;
+
           Self::set_statistic(
             &mut statistics,
             Statistic::IndexInscriptions,
@@ -413,6 +498,12 @@ impl Index {
             u64::from(settings.index_koinu_raw()),
           )?;
 
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::IndexText,
+            u64::from(settings.index_text_raw()),
+          )?;
+
           Self::set_statistic(
             &mut statistics,
             Statistic::IndexTransactions,
@@ -474,8 +565,10 @@ impl Index {
     };
 
     let index_addresses;
+    let index_content_hashes;
     let index_dunes;
     let index_koinu;
+    let index_text;
     let index_transactions;
     let index_inscriptions;
 
@@ -483,9 +576,11 @@ impl Index {
       let tx = database.begin_read()?;
       let statistics = tx.open_table(STATISTIC_TO_COUNT)?;
       index_addresses = Self::is_statistic_set(&statistics, Statistic::IndexAddresses)?;
+      index_content_hashes = Self::is_statistic_set(&statistics, Statistic::IndexContentHashes)?;
       index_inscriptions = Self::is_statistic_set(&statistics, Statistic::IndexInscriptions)?;
       index_dunes = Self::is_statistic_set(&statistics, Statistic::IndexDunes)?;
       index_koinu = Self::is_statistic_set(&statistics, Statistic::IndexKoinu)?;
+      index_text = Self::is_statistic_set(&statistics, Statistic::IndexText)?;
       index_transactions = Self::is_statistic_set(&statistics, Statistic::IndexTransactions)?;
     }
 
@@ -494,17 +589,26 @@ impl Index {
 
     let first_index_height = if index_koinu || index_addresses {
       0
-    } else if index_inscriptions {
-      settings.first_inscription_height()
-    } else if index_dunes {
-      settings.first_dune_height()
     } else {
-      u32::MAX
+      let inscriptions = if index_inscriptions {
+        settings.first_inscription_height()
+      } else {
+        u32::MAX
+      };
+
+      let dunes = if index_dunes {
+        settings.first_dune_commit_height()
+      } else {
+        u32::MAX
+      };
+
+      inscriptions.min(dunes)
     };
 
     Ok(Self {
       genesis_block_coinbase_txid: genesis_block_coinbase_transaction.compute_txid(),
       client,
+      collection_summaries: Mutex::new(HashMap::new()),
       database,
       durability,
       event_sender,
@@ -512,8 +616,10 @@ impl Index {
       genesis_block_coinbase_transaction,
       height_limit: settings.height_limit(),
       index_addresses,
+      index_content_hashes,
       index_dunes,
       index_koinu,
+      index_text,
       index_transactions,
       only_protocols: settings.only_protocols().map(|s| s.to_vec()),
       index_inscriptions,
@@ -522,14 +628,30 @@ impl Index {
       started: Utc::now(),
       reorg_count: AtomicU64::new(0),
       unrecoverably_reorged: AtomicBool::new(false),
+      zmq: settings
+        .dogecoin_zmq_address()
+        .map(zmq::ZmqSubscriber::new)
+        .transpose()?,
+      zmq_events: OnceLock::new(),
     })
   }
 
-  #[cfg(test)]
   pub(crate) fn chain(&self) -> Chain {
     self.settings.chain()
   }
 
+  /// New block notifications, if a ZMQ address is configured. Every update
+  /// shares the same receiver, so the feed is only subscribed to once.
+  pub(crate) fn zmq_events(&self) -> Option<Arc<Mutex<std::sync::mpsc::Receiver<zmq::ZmqEvent>>>> {
+    let zmq = self.zmq.as_ref()?;
+    Some(
+      self
+        .zmq_events
+        .get_or_init(|| Arc::new(Mutex::new(zmq.subscribe())))
+        .clone(),
+    )
+  }
+
   pub fn have_full_utxo_index(&self) -> bool {
     self.first_index_height == 0
   }
@@ -567,6 +689,10 @@ impl Index {
     self.index_addresses
   }
 
+  pub fn has_content_hash_index(&self) -> bool {
+    self.index_content_hashes
+  }
+
   pub fn has_inscription_index(&self) -> bool {
     self.index_inscriptions
   }
@@ -579,6 +705,10 @@ impl Index {
     self.index_koinu
   }
 
+  pub fn has_text_index(&self) -> bool {
+    self.index_text
+  }
+
   pub fn should_index_protocol(&self, protocol: &str) -> bool {
     match &self.only_protocols {
       None => true,
@@ -972,6 +1102,25 @@ impl Index {
     }
   }
 
+  pub fn get_dns_names_for_inscriptions(
+    &self,
+    inscription_ids: &[InscriptionId],
+  ) -> Result<Vec<String>> {
+    let rtx = self.database.begin_read()?;
+    let table = rtx.open_table(DNS_INSCRIPTION_ID_TO_NAME)?;
+
+    let mut names = Vec::new();
+    for inscription_id in inscription_ids {
+      if let Some(guard) = table.get(&inscription_id.store())? {
+        names.push(guard.value().to_string());
+      }
+    }
+
+    names.sort();
+
+    Ok(names)
+  }
+
   pub fn get_dns_names_by_namespace(&self, namespace: &str) -> Result<Option<Vec<String>>> {
     let tx = self.database.begin_read()?;
     let table = tx.open_multimap_table(DNS_NAMESPACE_TO_NAMES)?;
@@ -988,6 +1137,40 @@ impl Index {
     }
   }
 
+  pub fn count_dns_names(&self) -> Result<u64> {
+    let tx = self.database.begin_read()?;
+    let stats = tx.open_table(STATISTIC_TO_COUNT)?;
+    Ok(
+      stats
+        .get(&Statistic::DnsNames.key())?
+        .map(|g| g.value())
+        .unwrap_or(0),
+    )
+  }
+
+  /// Registered names as `(namespace, name)`, ordered by namespace and then
+  /// by name.
+  pub fn get_dns_names(&self, offset: usize, limit: usize) -> Result<Vec<(String, String)>> {
+    let tx = self.database.begin_read()?;
+    let mut names = Vec::new();
+    let mut skipped = 0;
+    for entry in tx.open_multimap_table(DNS_NAMESPACE_TO_NAMES)?.iter()? {
+      let (namespace, values) = entry?;
+      for name in values {
+        let name = name?;
+        if skipped < offset {
+          skipped += 1;
+          continue;
+        }
+        if names.len() == limit {
+          return Ok(names);
+        }
+        names.push((namespace.value().to_string(), name.value().to_string()));
+      }
+    }
+    Ok(names)
+  }
+
   pub fn get_dns_stats(&self) -> Result<(u64, std::collections::HashMap<String, u64>)> {
     let tx = self.database.begin_read()?;
     let table = tx.open_table(DNS_NAME_TO_ENTRY)?;
@@ -1014,14 +1197,28 @@ impl Index {
     if satpoint.outpoint == unbound_outpoint() {
       return Ok(None);
     }
-    let tx = self.database.begin_read()?;
-    let outpoint_to_utxo = tx.open_table(OUTPOINT_TO_UTXO_ENTRY)?;
-    let Some(utxo_guard) = outpoint_to_utxo.get(&satpoint.outpoint.store())? else {
-      return Ok(None);
+    let script_pubkey = if self.index_addresses {
+      let tx = self.database.begin_read()?;
+      let outpoint_to_utxo = tx.open_table(OUTPOINT_TO_UTXO_ENTRY)?;
+      let Some(utxo_guard) = outpoint_to_utxo.get(&satpoint.outpoint.store())? else {
+        return Ok(None);
+      };
+      ScriptBuf::from_bytes(utxo_guard.value().parse(self).script_pubkey().to_vec())
+    } else {
+      let Some(output) = self
+        .get_transaction(satpoint.outpoint.txid)?
+        .and_then(|tx| tx.output.into_iter().nth(satpoint.outpoint.vout as usize))
+      else {
+        return Ok(None);
+      };
+      output.script_pubkey
     };
-    let parsed = utxo_guard.value().parse(self);
-    let script = Script::from_bytes(parsed.script_pubkey());
-    Ok(self.settings.chain().address_string_from_script(script))
+    Ok(
+      self
+        .settings
+        .chain()
+        .address_string_from_script(&script_pubkey),
+    )
   }
 
   // ---------------------------------------------------------------------------
@@ -1074,29 +1271,121 @@ impl Index {
   }
 
   pub fn get_drc20_balances(&self, address: &str) -> Result<Vec<(String, u128, u128)>> {
+    Ok(self.get_drc20_balances_paginated(address, u64::MAX, 0)?.0)
+  }
+
+  /// Nonzero balances of `address` as `(tick, available, transferable)`,
+  /// ordered by tick.
+  pub fn get_drc20_balances_paginated(
+    &self,
+    address: &str,
+    page_size: u64,
+    page_index: u64,
+  ) -> Result<(Vec<(String, u128, u128)>, bool)> {
     let prefix = format!("{}\t", address);
     let tx = self.database.begin_read()?;
     let balance_table = tx.open_table(DRC20_BALANCE)?;
     let transferable_table = tx.open_table(DRC20_TRANSFERABLE)?;
+
+    let mut skip = page_index.saturating_mul(page_size);
     let mut results: Vec<(String, u128, u128)> = Vec::new();
-    for entry in balance_table.iter()? {
+    for entry in balance_table.range(prefix.as_str()..)? {
       let (k, v) = entry?;
       let key = k.value();
-      if key.starts_with(&prefix) {
-        let tick = key[prefix.len()..].to_string();
-        let available = serde_json::from_slice::<u128>(v.value()).unwrap_or(0);
-        let tkey = format!("{}\t{}", address, tick);
-        let transferable = transferable_table
-          .get(tkey.as_str())?
-          .map(|g| serde_json::from_slice::<u128>(g.value()).unwrap_or(0))
-          .unwrap_or(0);
-        if available > 0 || transferable > 0 {
-          results.push((tick, available, transferable));
-        }
+      let Some(tick) = key.strip_prefix(&prefix) else {
+        break;
+      };
+      let available = serde_json::from_slice::<u128>(v.value()).unwrap_or(0);
+      let transferable = transferable_table
+        .get(key)?
+        .map(|g| serde_json::from_slice::<u128>(g.value()).unwrap_or(0))
+        .unwrap_or(0);
+      if available == 0 && transferable == 0 {
+        continue;
+      }
+      if skip > 0 {
+        skip -= 1;
+        continue;
+      }
+      if results.len().into_u64() > page_size {
+        break;
       }
+      results.push((tick.to_string(), available, transferable));
+    }
+
+    let more = results.len().into_u64() > page_size;
+
+    if more {
+      results.pop();
+    }
+
+    Ok((results, more))
+  }
+
+  /// Addresses holding `tick` as `(address, available, transferable)`,
+  /// largest total balance first.
+  pub fn get_drc20_holders(&self, tick: &str) -> Result<Vec<(String, u128, u128)>> {
+    let tick = tick.to_lowercase();
+    let tx = self.database.begin_read()?;
+    let balance_table = tx.open_table(DRC20_BALANCE)?;
+    let transferable_table = tx.open_table(DRC20_TRANSFERABLE)?;
+    let mut holders = Vec::new();
+    for address in tx
+      .open_multimap_table(DRC20_TICK_TO_HOLDERS)?
+      .get(tick.as_str())?
+    {
+      let address = address?.value().to_string();
+      let key = format!("{}\t{}", address, tick);
+      let available = balance_table
+        .get(key.as_str())?
+        .map(|g| serde_json::from_slice::<u128>(g.value()).unwrap_or(0))
+        .unwrap_or(0);
+      let transferable = transferable_table
+        .get(key.as_str())?
+        .map(|g| serde_json::from_slice::<u128>(g.value()).unwrap_or(0))
+        .unwrap_or(0);
+      if available > 0 || transferable > 0 {
+        holders.push((address, available, transferable));
+      }
+    }
+    holders.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)).then_with(|| a.0.cmp(&b.0)));
+    Ok(holders)
+  }
+
+  /// Most recent activity for `tick`, newest first.
+  pub fn get_drc20_activity(
+    &self,
+    tick: &str,
+    limit: usize,
+  ) -> Result<Vec<crate::subcommand::drc20::Drc20Activity>> {
+    let tick = tick.to_lowercase();
+    let tx = self.database.begin_read()?;
+    let table = match tx.open_table(DRC20_ACTIVITY) {
+      Ok(table) => table,
+      Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+      Err(err) => return Err(err.into()),
+    };
+    table
+      .range((tick.as_str(), 0)..=(tick.as_str(), u64::MAX))?
+      .rev()
+      .take(limit)
+      .map(|result| Ok(serde_json::from_slice(result?.1.value())?))
+      .collect()
+  }
+
+  /// Returns the pending transfer inscribed at `outpoint`, if it has not yet
+  /// been spent.
+  pub fn get_drc20_transfer(
+    &self,
+    outpoint: OutPoint,
+  ) -> Result<Option<crate::subcommand::drc20::Drc20Transfer>> {
+    let tx = self.database.begin_read()?;
+    let table = tx.open_table(DRC20_OUTPOINT_TO_TRANSFER)?;
+    if let Some(guard) = table.get(&outpoint.store())? {
+      Ok(Some(serde_json::from_slice(guard.value())?))
+    } else {
+      Ok(None)
     }
-    results.sort_by(|a, b| a.0.cmp(&b.0));
-    Ok(results)
   }
 
   // ---------------------------------------------------------------------------
@@ -1189,6 +1478,101 @@ impl Index {
     Ok(results)
   }
 
+  /// Address currently holding the claim inscription for `block_number`, or
+  /// `None` if it is unclaimed or held by a script without an address.
+  pub fn get_dogemap_owner(&self, block_number: u32) -> Result<Option<String>> {
+    let tx = self.database.begin_read()?;
+    let table = match tx.open_table(DOGEMAP_BLOCK_TO_OWNER) {
+      Ok(table) => table,
+      Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+      Err(err) => return Err(err.into()),
+    };
+    Ok(
+      table
+        .get(&block_number)?
+        .map(|guard| guard.value().to_string()),
+    )
+  }
+
+  pub fn get_dogemaps_by_owner(&self, address: &str) -> Result<Vec<u32>> {
+    Ok(
+      self
+        .get_dogemaps_by_owner_paginated(address, u64::MAX, 0)?
+        .0,
+    )
+  }
+
+  pub fn get_dogemaps_by_owner_paginated(
+    &self,
+    address: &str,
+    page_size: u64,
+    page_index: u64,
+  ) -> Result<(Vec<u32>, bool)> {
+    let tx = self.database.begin_read()?;
+    let table = match tx.open_multimap_table(DOGEMAP_OWNER_TO_BLOCKS) {
+      Ok(table) => table,
+      Err(redb::TableError::TableDoesNotExist(_)) => return Ok((Vec::new(), false)),
+      Err(err) => return Err(err.into()),
+    };
+
+    let mut blocks = table
+      .get(address)?
+      .skip(page_index.saturating_mul(page_size).try_into().unwrap())
+      .take(page_size.saturating_add(1).try_into().unwrap())
+      .map(|result| Ok(result?.value()))
+      .collect::<Result<Vec<u32>>>()?;
+
+    let more = blocks.len().into_u64() > page_size;
+
+    if more {
+      blocks.pop();
+    }
+
+    Ok((blocks, more))
+  }
+
+  /// Every move of the claim inscription for `block_number`, oldest first.
+  /// The first entry is the claim itself.
+  pub fn get_dogemap_transfers(
+    &self,
+    block_number: u32,
+  ) -> Result<Vec<crate::subcommand::dogemap::DogemapTransfer>> {
+    let tx = self.database.begin_read()?;
+    let table = match tx.open_table(DOGEMAP_BLOCK_TO_TRANSFERS) {
+      Ok(table) => table,
+      Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+      Err(err) => return Err(err.into()),
+    };
+    table
+      .range((block_number, 0)..=(block_number, u32::MAX))?
+      .map(|result| Ok(serde_json::from_slice(result?.1.value())?))
+      .collect()
+  }
+
+  pub fn get_dogemap_parcels(
+    &self,
+    block_number: u32,
+  ) -> Result<Vec<crate::subcommand::dogemap::DogemapParcel>> {
+    let tx = self.database.begin_read()?;
+    let table = match tx.open_table(DOGEMAP_PARCEL_TO_INSCRIPTION_ID) {
+      Ok(table) => table,
+      Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+      Err(err) => return Err(err.into()),
+    };
+    table
+      .range((block_number, 0)..=(block_number, u32::MAX))?
+      .map(|result| {
+        let (key, value) = result?;
+        let (block_number, parcel) = key.value();
+        Ok(crate::subcommand::dogemap::DogemapParcel {
+          block_number,
+          parcel,
+          inscription_id: InscriptionId::load(value.value()),
+        })
+      })
+      .collect()
+  }
+
   pub fn count_dogemaps(&self) -> Result<u64> {
     let tx = self.database.begin_read()?;
     let stats = tx.open_table(STATISTIC_TO_COUNT)?;
@@ -1363,6 +1747,116 @@ impl Index {
     Ok(Some((DuneId::load(id), entry, parent)))
   }
 
+  /// Addresses holding `id`, largest balance first. Empty unless the index was
+  /// created with `--index-addresses`.
+  pub fn get_dune_holders(&self, id: DuneId) -> Result<Vec<api::DuneHolder>> {
+    let mut holders = Vec::new();
+
+    for result in self
+      .database
+      .begin_read()?
+      .open_table(DUNE_ID_TO_HOLDER_BALANCE)?
+      .range((id.store(), "")..)?
+    {
+      let (key, amount) = result?;
+
+      let (holder_id, address) = key.value();
+
+      if holder_id != id.store() {
+        break;
+      }
+
+      holders.push(api::DuneHolder {
+        address: address.into(),
+        amount: amount.value(),
+      });
+    }
+
+    holders.sort_by(|a, b| {
+      b.amount
+        .cmp(&a.amount)
+        .then_with(|| a.address.cmp(&b.address))
+    });
+
+    Ok(holders)
+  }
+
+  /// Dunes whose terms allow minting in the next block, in etching order.
+  pub fn get_mintable_dunes(&self) -> Result<Vec<api::MintableDune>> {
+    let height = self.block_height()?.unwrap_or(Height(0)).n() + 1;
+
+    Ok(
+      self
+        .dunes()?
+        .into_iter()
+        .filter_map(|(id, entry)| {
+          let amount = entry.mintable(height.into()).ok()?;
+          let cap = entry.terms?.cap.unwrap_or_default();
+
+          Some(api::MintableDune {
+            id,
+            spaced_dune: entry.spaced_dune,
+            amount,
+            cap,
+            mints: entry.mints,
+            remaining: cap - entry.mints,
+            end: entry.end(),
+          })
+        })
+        .collect(),
+    )
+  }
+
+  /// Mints of `id` in each block in `start..end` that had any.
+  pub fn get_dune_block_mints(
+    &self,
+    id: DuneId,
+    start: u32,
+    end: u32,
+  ) -> Result<BTreeMap<u32, u64>> {
+    if start >= end {
+      return Ok(BTreeMap::new());
+    }
+
+    self
+      .database
+      .begin_read()?
+      .open_table(DUNE_ID_TO_BLOCK_MINTS)?
+      .range((id.store(), start)..(id.store(), end))?
+      .map(|result| {
+        let (key, mints) = result?;
+        Ok((key.value().1, mints.value()))
+      })
+      .collect()
+  }
+
+  /// Page of `id`'s etch, mint, transfer and burn history, newest first.
+  pub fn get_dune_activity_paginated(
+    &self,
+    id: DuneId,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<api::DuneActivity>, bool)> {
+    let mut activity = self
+      .database
+      .begin_read()?
+      .open_table(DUNE_ID_TO_ACTIVITY)?
+      .range((id.store(), 0)..=(id.store(), u64::MAX))?
+      .rev()
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+      .map(|result| Ok(serde_json::from_slice(result?.1.value())?))
+      .collect::<Result<Vec<api::DuneActivity>>>()?;
+
+    let more = activity.len() > page_size;
+
+    if more {
+      activity.pop();
+    }
+
+    Ok((activity, more))
+  }
+
   pub fn dunes(&self) -> Result<Vec<(DuneId, DuneEntry)>> {
     let mut entries = Vec::new();
 
@@ -1548,8 +2042,43 @@ impl Index {
     Ok(result)
   }
 
+  /// Fetch a block header. AuxPoW headers carry the merge-mining proof after
+  /// the 80-byte header, which is ignored.
   pub fn block_header(&self, hash: BlockHash) -> Result<Option<Header>> {
-    self.client.get_block_header(&hash).into_option()
+    let Some(hex) = self
+      .client
+      .call::<String>(
+        "getblockheader",
+        &[serde_json::to_value(hash)?, false.into()],
+      )
+      .into_option()?
+    else {
+      return Ok(None);
+    };
+
+    Ok(Some(Header::consensus_decode(&mut io::Cursor::new(
+      hex::decode(hex.trim())?,
+    ))?))
+  }
+
+  /// Fetch a block with `getblock <hash> 0`, parsing the raw bytes with
+  /// `DogecoinBlock::parse`, since the bitcoin crate can't deserialize blocks
+  /// with AuxPoW headers.
+  fn get_block_raw(&self, hash: BlockHash) -> Result<Option<Block>> {
+    let Some(hex) = self
+      .client
+      .call::<String>("getblock", &[serde_json::to_value(hash)?, 0.into()])
+      .into_option()?
+    else {
+      return Ok(None);
+    };
+
+    let block = DogecoinBlock::parse(&hex::decode(hex.trim())?)?;
+
+    Ok(Some(Block {
+      header: block.header,
+      txdata: block.txdata,
+    }))
   }
 
   pub fn block_header_info(&self, hash: BlockHash) -> Result<Option<GetBlockHeaderResult>> {
@@ -1569,14 +2098,10 @@ impl Index {
     if !indexed {
       return Ok(None);
     }
-    Ok(
-      self
-        .client
-        .get_block_hash(height.into())
-        .into_option()?
-        .map(|hash| self.client.get_block(&hash))
-        .transpose()?,
-    )
+    match self.client.get_block_hash(height.into()).into_option()? {
+      Some(hash) => self.get_block_raw(hash),
+      None => Ok(None),
+    }
   }
 
   pub fn get_block_by_hash(&self, hash: BlockHash) -> Result<Option<Block>> {
@@ -1591,7 +2116,7 @@ impl Index {
     if !indexed {
       return Ok(None);
     }
-    self.client.get_block(&hash).into_option()
+    self.get_block_raw(hash)
   }
 
   pub fn get_collections_paginated(
@@ -1886,11 +2411,439 @@ impl Index {
     Ok((ids, more))
   }
 
-  pub fn get_inscription_id_by_koinu_indexed(
+  /// Inscriptions currently held by `address`, in inscription order.
+  pub fn get_inscription_ids_by_address(&self, address: &str) -> Result<Vec<InscriptionId>> {
+    Ok(
+      self
+        .get_inscription_ids_by_address_paginated(address, u64::MAX, 0)?
+        .0,
+    )
+  }
+
+  pub fn get_inscription_ids_by_address_paginated(
     &self,
-    sat: Koinu,
-    inscription_index: isize,
-  ) -> Result<Option<InscriptionId>> {
+    address: &str,
+    page_size: u64,
+    page_index: u64,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let rtx = self.database.begin_read()?;
+
+    let sequence_number_to_inscription_entry =
+      rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+
+    let mut ids = rtx
+      .open_multimap_table(ADDRESS_TO_INSCRIPTION_SEQUENCE_NUMBERS)?
+      .get(address)?
+      .skip(page_index.saturating_mul(page_size).try_into().unwrap())
+      .take(page_size.saturating_add(1).try_into().unwrap())
+      .map(|result| {
+        result
+          .and_then(|sequence_number| {
+            let sequence_number = sequence_number.value();
+            sequence_number_to_inscription_entry
+              .get(sequence_number)
+              .map(|entry| InscriptionEntry::load(entry.unwrap().value()).id)
+          })
+          .map_err(|err| err.into())
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    let more = ids.len().into_u64() > page_size;
+
+    if more {
+      ids.pop();
+    }
+
+    Ok((ids, more))
+  }
+
+  /// Statistics and holders for the children of `parent`, recomputed at
+  /// most once per indexed block.
+  pub(crate) fn collection_summary(
+    &self,
+    parent: InscriptionId,
+  ) -> Result<Option<Arc<CollectionSummary>>> {
+    let rtx = self.begin_read()?;
+
+    let Some(parent_sequence_number) = rtx
+      .0
+      .open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?
+      .get(&parent.store())?
+      .map(|sequence_number| sequence_number.value())
+    else {
+      return Ok(None);
+    };
+
+    let block_count = rtx.block_count()?;
+
+    let tip = rtx.block_hash(None)?;
+
+    if let Some((hash, summary)) = self
+      .collection_summaries
+      .lock()
+      .unwrap()
+      .get(&parent_sequence_number)
+      && *hash == tip
+    {
+      return Ok(Some(summary.clone()));
+    }
+
+    let sequence_number_to_inscription_entry =
+      rtx.0.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+
+    let sequence_number_to_address = rtx.0.open_table(SEQUENCE_NUMBER_TO_ADDRESS)?;
+
+    let sequence_number_to_content_type = rtx.0.open_table(SEQUENCE_NUMBER_TO_CONTENT_TYPE)?;
+
+    let mut child_count = 0;
+    let mut first_mint_height: Option<u32> = None;
+    let mut last_mint_height: Option<u32> = None;
+    let mut holders = HashMap::<String, u64>::new();
+    let mut content_types = BTreeMap::<String, u64>::new();
+
+    for child in rtx
+      .0
+      .open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?
+      .get(parent_sequence_number)?
+    {
+      let child = child?.value();
+
+      let entry = InscriptionEntry::load(
+        sequence_number_to_inscription_entry
+          .get(child)?
+          .ok_or_else(|| anyhow!("missing inscription entry for sequence number {child}"))?
+          .value(),
+      );
+
+      child_count += 1;
+
+      first_mint_height = Some(first_mint_height.map_or(entry.height, |h| h.min(entry.height)));
+      last_mint_height = Some(last_mint_height.map_or(entry.height, |h| h.max(entry.height)));
+
+      if let Some(address) = sequence_number_to_address.get(child)? {
+        *holders.entry(address.value().to_string()).or_default() += 1;
+      }
+
+      let content_type = sequence_number_to_content_type
+        .get(child)?
+        .map(|content_type| content_type.value().to_string())
+        .unwrap_or_else(|| "unknown".into());
+
+      *content_types.entry(content_type).or_default() += 1;
+    }
+
+    let mut holder_distribution = BTreeMap::<u64, u64>::new();
+
+    for count in holders.values() {
+      *holder_distribution.entry(*count).or_default() += 1;
+    }
+
+    let mut holders = holders
+      .into_iter()
+      .map(|(address, count)| api::CollectionHolder { address, count })
+      .collect::<Vec<api::CollectionHolder>>();
+
+    holders.sort_by(|a, b| {
+      b.count
+        .cmp(&a.count)
+        .then_with(|| a.address.cmp(&b.address))
+    });
+
+    let summary = Arc::new(CollectionSummary {
+      stats: api::CollectionStats {
+        parent,
+        height: block_count.checked_sub(1),
+        child_count,
+        unique_holders: holders.len().into_u64(),
+        holder_distribution,
+        first_mint_height,
+        last_mint_height,
+        content_types,
+      },
+      holders,
+    });
+
+    let mut summaries = self.collection_summaries.lock().unwrap();
+
+    if summaries.len() >= MAX_CACHED_COLLECTIONS {
+      summaries.clear();
+    }
+
+    summaries.insert(parent_sequence_number, (tip, summary.clone()));
+
+    Ok(Some(summary))
+  }
+
+  /// Trait value counts among the members of `parent`, its children or, if it
+  /// is a gallery, its items. A value's rarity score is the number of members
+  /// with any trait divided by the number with that value.
+  pub(crate) fn get_collection_traits(
+    &self,
+    parent: InscriptionId,
+  ) -> Result<Option<api::CollectionTraits>> {
+    let rtx = self.database.begin_read()?;
+
+    let Some(parent_sequence_number) = rtx
+      .open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?
+      .get(&parent.store())?
+      .map(|sequence_number| sequence_number.value())
+    else {
+      return Ok(None);
+    };
+
+    let mut members = HashSet::new();
+    let mut counts = BTreeMap::<String, BTreeMap<String, u64>>::new();
+
+    for result in rtx
+      .open_multimap_table(COLLECTION_TRAIT_TO_SEQUENCE_NUMBERS)?
+      .range((parent_sequence_number, "", "")..)?
+    {
+      let (key, sequence_numbers) = result?;
+
+      let (collection, name, value) = key.value();
+
+      if collection != parent_sequence_number {
+        break;
+      }
+
+      let mut count = 0;
+
+      for sequence_number in sequence_numbers {
+        members.insert(sequence_number?.value());
+        count += 1;
+      }
+
+      counts
+        .entry(name.into())
+        .or_default()
+        .insert(value.into(), count);
+    }
+
+    let members = members.len().into_u64();
+
+    Ok(Some(api::CollectionTraits {
+      parent,
+      members,
+      traits: counts
+        .into_iter()
+        .map(|(name, values)| {
+          (
+            name,
+            values
+              .into_iter()
+              .map(|(value, count)| {
+                (
+                  value,
+                  api::CollectionTraitValue {
+                    count,
+                    rarity_score: members as f64 / count as f64,
+                  },
+                )
+              })
+              .collect(),
+          )
+        })
+        .collect(),
+    }))
+  }
+
+  pub(crate) fn get_collection_trait_inscriptions_paginated(
+    &self,
+    parent: InscriptionId,
+    name: &str,
+    value: &str,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<Option<(Vec<InscriptionId>, bool)>> {
+    let rtx = self.database.begin_read()?;
+
+    let Some(parent_sequence_number) = rtx
+      .open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?
+      .get(&parent.store())?
+      .map(|sequence_number| sequence_number.value())
+    else {
+      return Ok(None);
+    };
+
+    let sequence_number_to_inscription_entry =
+      rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+
+    let mut ids = rtx
+      .open_multimap_table(COLLECTION_TRAIT_TO_SEQUENCE_NUMBERS)?
+      .get((parent_sequence_number, name, value))?
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+      .map(|result| {
+        result
+          .and_then(|sequence_number| {
+            let sequence_number = sequence_number.value();
+            sequence_number_to_inscription_entry
+              .get(sequence_number)
+              .map(|entry| InscriptionEntry::load(entry.unwrap().value()).id)
+          })
+          .map_err(|err| err.into())
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    let more = ids.len() > page_size;
+
+    if more {
+      ids.pop();
+    }
+
+    Ok(Some((ids, more)))
+  }
+
+  pub fn get_inscription_ids_by_content_hash_paginated(
+    &self,
+    content_hash: sha256::Hash,
+    page_size: u64,
+    page_index: u64,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let rtx = self.database.begin_read()?;
+
+    let sequence_number_to_inscription_entry =
+      rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+
+    let mut ids = rtx
+      .open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?
+      .get(content_hash.as_byte_array())?
+      .skip(page_index.saturating_mul(page_size).try_into().unwrap())
+      .take(page_size.saturating_add(1).try_into().unwrap())
+      .map(|result| {
+        result
+          .and_then(|sequence_number| {
+            let sequence_number = sequence_number.value();
+            sequence_number_to_inscription_entry
+              .get(sequence_number)
+              .map(|entry| InscriptionEntry::load(entry.unwrap().value()).id)
+          })
+          .map_err(|err| err.into())
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    let more = ids.len().into_u64() > page_size;
+
+    if more {
+      ids.pop();
+    }
+
+    Ok((ids, more))
+  }
+
+  /// Inscriptions containing every term of `query`, highest scoring first.
+  /// Each matching term adds `(1 + ln count) * ln(1 + inscriptions / matches)`.
+  pub fn search_text(
+    &self,
+    query: &str,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let terms = text::query_terms(query);
+
+    if terms.is_empty() {
+      return Ok((Vec::new(), false));
+    }
+
+    let rtx = self.database.begin_read()?;
+
+    let sequence_number_to_inscription_entry =
+      rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+
+    let text_term_to_inscription_count = rtx.open_table(TEXT_TERM_TO_INSCRIPTION_COUNT)?;
+
+    let text_term_to_sequence_number = rtx.open_table(TEXT_TERM_TO_SEQUENCE_NUMBER)?;
+
+    let inscriptions =
+      f64::from(u32::try_from(sequence_number_to_inscription_entry.len()?).unwrap_or(u32::MAX));
+
+    let mut terms = terms
+      .into_iter()
+      .map(|term| {
+        Ok((
+          text_term_to_inscription_count
+            .get(term.as_str())?
+            .map(|count| count.value())
+            .unwrap_or_default(),
+          term,
+        ))
+      })
+      .collect::<Result<Vec<(u32, String)>>>()?;
+
+    if terms
+      .iter()
+      .any(|(inscription_count, _)| *inscription_count == 0)
+    {
+      return Ok((Vec::new(), false));
+    }
+
+    terms.sort();
+
+    let score = |inscription_count: u32, count: u32| {
+      (1.0 + f64::from(count).ln()) * (1.0 + inscriptions / f64::from(inscription_count)).ln()
+    };
+
+    let (rarest_count, rarest) = &terms[0];
+
+    let mut ranked = Vec::new();
+
+    // walk the rarest term's postings newest first, so that when there are too
+    // many matches, the newest inscriptions containing every term are ranked
+    'postings: for result in text_term_to_sequence_number
+      .range((rarest.as_str(), 0)..=(rarest.as_str(), u32::MAX))?
+      .rev()
+    {
+      let (key, count) = result?;
+
+      let sequence_number = key.value().1;
+
+      let mut total = score(*rarest_count, count.value());
+
+      for (inscription_count, term) in &terms[1..] {
+        let Some(count) = text_term_to_sequence_number.get((term.as_str(), sequence_number))?
+        else {
+          continue 'postings;
+        };
+
+        total += score(*inscription_count, count.value());
+      }
+
+      ranked.push((sequence_number, total));
+
+      if ranked.len() == text::MAX_SEARCH_CANDIDATES {
+        break;
+      }
+    }
+
+    ranked.sort_by(|(a, a_score), (b, b_score)| b_score.total_cmp(a_score).then(a.cmp(b)));
+
+    let more = ranked.len() > page_index.saturating_add(1).saturating_mul(page_size);
+
+    let ids = ranked
+      .into_iter()
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size)
+      .map(|(sequence_number, _)| {
+        Ok(
+          InscriptionEntry::load(
+            sequence_number_to_inscription_entry
+              .get(sequence_number)?
+              .unwrap()
+              .value(),
+          )
+          .id,
+        )
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    Ok((ids, more))
+  }
+
+  pub fn get_inscription_id_by_koinu_indexed(
+    &self,
+    sat: Koinu,
+    inscription_index: isize,
+  ) -> Result<Option<InscriptionId>> {
     let rtx = self.database.begin_read()?;
 
     let sequence_number_to_inscription_entry =
@@ -1974,16 +2927,26 @@ impl Index {
     }
 
     Ok(self.get_transaction(inscription_id.txid)?.and_then(|tx| {
-      ParsedEnvelope::from_transaction(&tx)
+      self
+        .parse_envelopes(&tx)
         .into_iter()
         .nth(inscription_id.index as usize)
         .map(|envelope| envelope.payload)
     }))
   }
 
-  pub fn inscription_count(&self, txid: Txid) -> Result<u32> {
-    let start = InscriptionId { index: 0, txid };
-
+  /// Envelopes in `transaction`, parsed the same way the updater parses them.
+  pub(crate) fn parse_envelopes(&self, transaction: &Transaction) -> Vec<ParsedEnvelope> {
+    if self.settings.chain().is_dogecoin() {
+      ParsedEnvelope::from_transactions_dogecoin(std::slice::from_ref(transaction))
+    } else {
+      ParsedEnvelope::from_transaction(transaction)
+    }
+  }
+
+  pub fn inscription_count(&self, txid: Txid) -> Result<u32> {
+    let start = InscriptionId { index: 0, txid };
+
     let end = InscriptionId {
       index: u32::MAX,
       txid,
@@ -2198,7 +3161,7 @@ impl Index {
       None => return Ok(None), // unconfirmed
     };
 
-    let block = match self.client.get_block(&blockhash).into_option()? {
+    let block = match self.get_block_raw(blockhash)? {
       Some(b) => b,
       None => return Ok(None),
     };
@@ -2614,7 +3577,8 @@ impl Index {
             "transaction {} not available via RPC — node may not be fully synced to that block yet ({})",
             id.txid, e
           ))?;
-        let Some(inscription) = ParsedEnvelope::from_transaction(&transaction)
+        let Some(inscription) = self
+          .parse_envelopes(&transaction)
           .into_iter()
           .nth(id.index as usize)
           .map(|envelope| envelope.payload)
@@ -2651,6 +3615,7 @@ impl Index {
             content_type: inscription.content_type().map(|s| s.to_string()),
             effective_content_type: effective_mime_type,
             fee: 0,
+            first_of_content: None,
             height: 0,
             id,
             next: None,
@@ -2702,7 +3667,8 @@ impl Index {
       return Ok(None);
     };
 
-    let Some(inscription) = ParsedEnvelope::from_transaction(&transaction)
+    let Some(inscription) = self
+      .parse_envelopes(&transaction)
       .into_iter()
       .nth(entry.id.index as usize)
       .map(|envelope| envelope.payload)
@@ -2803,6 +3769,23 @@ impl Index {
       Charm::Lost.set(&mut charms);
     }
 
+    let first_of_content = if self.index_content_hashes {
+      match inscription.content_hash(MAX_CONTENT_HASH_BODY_SIZE) {
+        Some(content_hash) => Some(
+          rtx
+            .open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?
+            .get(content_hash.as_byte_array())?
+            .next()
+            .transpose()?
+            .map(|first| first.value())
+            == Some(sequence_number),
+        ),
+        None => None,
+      }
+    } else {
+      None
+    };
+
     let effective_mime_type = if let Some(delegate_id) = inscription.delegate() {
       let delegate_result = self.get_inscription_by_id(delegate_id);
       if let Ok(Some(delegate)) = delegate_result {
@@ -2833,6 +3816,7 @@ impl Index {
         content_type: inscription.content_type().map(|s| s.to_string()),
         effective_content_type: effective_mime_type,
         fee: entry.fee,
+        first_of_content,
         height: entry.height,
         id: entry.id,
         next,
@@ -3054,6 +4038,9 @@ impl Index {
           inscriptions,
           sat_balance: total_balance,
           dunes_balances,
+          drc20_balances: BTreeMap::new(),
+          names: Vec::new(),
+          dogemaps: Vec::new(),
           lazy_lookup: Some(true),
         });
       }
@@ -3290,6 +4277,28 @@ mod tests {
     }
   }
 
+  #[test]
+  fn blocks_and_headers_are_fetched_raw() {
+    let context = Context::builder().build();
+
+    let block = context.mine_blocks(1)[0].clone();
+    let hash = block.block_hash();
+
+    assert_eq!(
+      context.index.get_block_by_height(1).unwrap(),
+      Some(block.clone())
+    );
+    assert_eq!(
+      context.index.get_block_by_hash(hash).unwrap(),
+      Some(block.clone())
+    );
+    assert_eq!(
+      context.index.block_header(hash).unwrap(),
+      Some(block.header)
+    );
+    assert_eq!(context.index.get_block_by_height(2).unwrap(), None);
+  }
+
   #[test]
   fn update_starts_from_configured_first_inscription_height() {
     let context = Context::builder()
@@ -7605,4 +8614,657 @@ mod tests {
     // zero
     assert_eq!(Statistic::Schema.key(), 0);
   }
+
+  #[test]
+  fn dogemap_owner_follows_claim_inscription() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    let alice = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+    let bob = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([2; 20]),
+      Network::Regtest,
+    );
+
+    let chain = context.index.settings.chain();
+    let alice_address = chain
+      .address_string_from_script(&alice.script_pubkey())
+      .unwrap();
+    let bob_address = chain
+      .address_string_from_script(&bob.script_pubkey())
+      .unwrap();
+
+    let claim = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      script_sig: inscription("text/plain;charset=utf-8", "1.dogemap").to_script_sig(),
+      recipient: Some(alice.clone()),
+      ..default()
+    });
+
+    let claim_time = context.mine_blocks(1)[0].header.time;
+
+    let inscription_id = InscriptionId {
+      txid: claim,
+      index: 0,
+    };
+
+    assert_eq!(
+      context
+        .index
+        .get_dogemap_claim(1)
+        .unwrap()
+        .unwrap()
+        .owner_inscription_id,
+      inscription_id,
+    );
+
+    assert_eq!(
+      context.index.get_dogemap_owner(1).unwrap(),
+      Some(alice_address.clone()),
+    );
+
+    assert_eq!(
+      context.index.get_dogemaps_by_owner(&alice_address).unwrap(),
+      [1],
+    );
+
+    let transfer = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Witness::new())],
+      recipient: Some(bob),
+      ..default()
+    });
+
+    let transfer_time = context.mine_blocks(1)[0].header.time;
+
+    assert_eq!(
+      context.index.get_dogemap_owner(1).unwrap(),
+      Some(bob_address.clone()),
+    );
+
+    assert!(
+      context
+        .index
+        .get_dogemaps_by_owner(&alice_address)
+        .unwrap()
+        .is_empty()
+    );
+
+    assert_eq!(
+      context.index.get_dogemaps_by_owner(&bob_address).unwrap(),
+      [1]
+    );
+
+    pretty_assert_eq!(
+      context.index.get_dogemap_transfers(1).unwrap(),
+      [
+        crate::subcommand::dogemap::DogemapTransfer {
+          height: 2,
+          timestamp: claim_time,
+          location: KoinuPoint {
+            outpoint: OutPoint {
+              txid: claim,
+              vout: 0,
+            },
+            offset: 0,
+          },
+          from: None,
+          to: Some(alice_address.clone()),
+        },
+        crate::subcommand::dogemap::DogemapTransfer {
+          height: 3,
+          timestamp: transfer_time,
+          location: KoinuPoint {
+            outpoint: OutPoint {
+              txid: transfer,
+              vout: 0,
+            },
+            offset: 0,
+          },
+          from: Some(alice_address),
+          to: Some(bob_address),
+        },
+      ],
+    );
+  }
+
+  #[test]
+  fn dogemap_parcels_are_limited_by_transaction_count() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    let claim = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      script_sig: inscription("text/plain;charset=utf-8", "1.dogemap").to_script_sig(),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let claim = InscriptionId {
+      txid: claim,
+      index: 0,
+    };
+
+    let parcel = |n: u32| Inscription {
+      parents: vec![claim.value()],
+      ..inscription("text/plain;charset=utf-8", format!("{n}.1.dogemap"))
+    };
+
+    let first = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Witness::new())],
+      script_sig: parcel(0).to_script_sig(),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    // block 1 only contains its coinbase, so parcel 1 is out of range
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 1, 0, Witness::new())],
+      script_sig: parcel(1).to_script_sig(),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context
+        .index
+        .database
+        .begin_read()
+        .unwrap()
+        .open_table(HEIGHT_TO_TRANSACTION_COUNT)
+        .unwrap()
+        .get(1)
+        .unwrap()
+        .unwrap()
+        .value(),
+      1,
+    );
+
+    assert_eq!(
+      context.index.get_dogemap_parcels(1).unwrap(),
+      [crate::subcommand::dogemap::DogemapParcel {
+        block_number: 1,
+        parcel: 0,
+        inscription_id: InscriptionId {
+          txid: first,
+          index: 0,
+        },
+      }],
+    );
+  }
+
+  #[test]
+  fn drc20_holders_are_ordered_by_balance() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    let alice = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+    let bob = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([2; 20]),
+      Network::Regtest,
+    );
+
+    let operations = [
+      (
+        r#"{"p":"drc-20","op":"deploy","tick":"dogi","max":"1000","lim":"100","dec":0}"#,
+        &alice,
+      ),
+      (
+        r#"{"p":"drc-20","op":"mint","tick":"dogi","amt":"60"}"#,
+        &alice,
+      ),
+      (
+        r#"{"p":"drc-20","op":"mint","tick":"dogi","amt":"100"}"#,
+        &bob,
+      ),
+      (
+        r#"{"p":"drc-20","op":"mint","tick":"dogi","amt":"30"}"#,
+        &alice,
+      ),
+    ];
+
+    for (height, (operation, recipient)) in operations.into_iter().enumerate() {
+      context.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          height + 1,
+          0,
+          0,
+          inscription("text/plain", operation).to_witness(),
+        )],
+        recipient: Some(recipient.clone()),
+        ..default()
+      });
+
+      context.mine_blocks(1);
+    }
+
+    let chain = context.index.settings.chain();
+    let address = |address: &Address| {
+      chain
+        .address_string_from_script(&address.script_pubkey())
+        .unwrap()
+    };
+
+    assert_eq!(
+      context.index.get_drc20_holders("DOGI").unwrap(),
+      [(address(&bob), 100, 0), (address(&alice), 90, 0)],
+    );
+
+    assert!(context.index.get_drc20_holders("wow").unwrap().is_empty());
+  }
+
+  #[test]
+  fn search_text_ranks_and_paginates_matches() {
+    let context = Context::builder().arg("--index-text").build();
+
+    context.mine_blocks(3);
+
+    let mut ids = Vec::new();
+
+    for (block, body) in [(1, "doge doge doge wow"), (2, "such doge"), (3, "wow doge")] {
+      ids.push(InscriptionId {
+        txid: context.core.broadcast_tx(TransactionTemplate {
+          inputs: &[(block, 0, 0, Witness::new())],
+          script_sig: inscription("text/plain;charset=utf-8", body).to_script_sig(),
+          ..default()
+        }),
+        index: 0,
+      });
+
+      context.mine_blocks(1);
+    }
+
+    assert_eq!(
+      context.index.search_text("wow doge", 10, 0).unwrap(),
+      (vec![ids[0], ids[2]], false),
+    );
+
+    assert_eq!(
+      context.index.search_text("doge", 10, 0).unwrap(),
+      (vec![ids[0], ids[1], ids[2]], false),
+    );
+
+    assert_eq!(
+      context.index.search_text("WOW doge", 1, 0).unwrap(),
+      (vec![ids[0]], true),
+    );
+
+    assert_eq!(
+      context.index.search_text("wow doge", 1, 1).unwrap(),
+      (vec![ids[2]], false),
+    );
+
+    assert_eq!(
+      context.index.search_text("wow cat", 10, 0).unwrap(),
+      (Vec::new(), false),
+    );
+  }
+
+  #[test]
+  fn inscription_address_follows_create_transfer_and_burn() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(2);
+
+    let alice = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+    let bob = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([2; 20]),
+      Network::Regtest,
+    );
+
+    let chain = context.index.settings.chain();
+    let alice_address = chain
+      .address_string_from_script(&alice.script_pubkey())
+      .unwrap();
+    let bob_address = chain
+      .address_string_from_script(&bob.script_pubkey())
+      .unwrap();
+
+    let address_sequence_numbers = |address: &str| {
+      context
+        .index
+        .database
+        .begin_read()
+        .unwrap()
+        .open_multimap_table(ADDRESS_TO_INSCRIPTION_SEQUENCE_NUMBERS)
+        .unwrap()
+        .get(address)
+        .unwrap()
+        .map(|sequence_number| sequence_number.unwrap().value())
+        .collect::<Vec<u32>>()
+    };
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      script_sig: inscription("text/plain;charset=utf-8", "wow").to_script_sig(),
+      recipient: Some(alice),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    assert_eq!(address_sequence_numbers(&alice_address), [0]);
+    assert!(address_sequence_numbers(&bob_address).is_empty());
+    assert_eq!(
+      context
+        .index
+        .get_inscription_ids_by_address(&alice_address)
+        .unwrap(),
+      [inscription_id],
+    );
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 1, 0, Witness::new())],
+      recipient: Some(bob),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert!(address_sequence_numbers(&alice_address).is_empty());
+    assert_eq!(address_sequence_numbers(&bob_address), [0]);
+    assert_eq!(
+      context
+        .index
+        .get_inscription_ids_by_address(&bob_address)
+        .unwrap(),
+      [inscription_id],
+    );
+
+    context.core.broadcast_tx(TransactionTemplate {
+      fee: 1,
+      inputs: &[(4, 1, 0, Witness::new())],
+      op_return: Some(ScriptBuf::new_op_return([])),
+      op_return_index: Some(0),
+      op_return_value: Some(1),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert!(address_sequence_numbers(&alice_address).is_empty());
+    assert!(address_sequence_numbers(&bob_address).is_empty());
+    assert!(
+      context
+        .index
+        .get_inscription_ids_by_address(&bob_address)
+        .unwrap()
+        .is_empty()
+    );
+  }
+
+  #[test]
+  fn collection_traits_are_indexed_for_children_and_gallery_items() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(6);
+
+    let traits = |items: &[(&str, Trait)]| {
+      Properties {
+        attributes: Attributes {
+          title: None,
+          traits: Traits {
+            items: items
+              .iter()
+              .map(|(name, value)| (name.to_string(), value.clone()))
+              .collect(),
+          },
+        },
+        ..default()
+      }
+      .to_cbor()
+    };
+
+    let parent = InscriptionId {
+      txid: context.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, Witness::new())],
+        script_sig: inscription("text/plain;charset=utf-8", "parent").to_script_sig(),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    context.mine_blocks(1);
+
+    let mut children = Vec::new();
+    let mut parent_output = (7, 1, 0);
+
+    for (height, (block, properties)) in (8..).zip([
+      (2, traits(&[("background", Trait::String("red".into()))])),
+      (3, traits(&[("background", Trait::String("blue".into()))])),
+      (
+        4,
+        traits(&[
+          ("background", Trait::String("red".into())),
+          ("eyes", Trait::String("laser".into())),
+        ]),
+      ),
+      (5, None),
+    ]) {
+      children.push(InscriptionId {
+        txid: context.core.broadcast_tx(TransactionTemplate {
+          inputs: &[
+            (block, 0, 0, Witness::new()),
+            (
+              parent_output.0,
+              parent_output.1,
+              parent_output.2,
+              Witness::new(),
+            ),
+          ],
+          script_sig: Inscription {
+            content_type: Some("text/plain;charset=utf-8".into()),
+            body: Some("child".into()),
+            parents: vec![parent.value()],
+            properties,
+            ..default()
+          }
+          .to_script_sig(),
+          outputs: 2,
+          ..default()
+        }),
+        index: 0,
+      });
+
+      context.mine_blocks(1);
+
+      parent_output = (height, 1, 1);
+    }
+
+    // the child without traits is not a member
+    pretty_assert_eq!(
+      context
+        .index
+        .get_collection_traits(parent)
+        .unwrap()
+        .unwrap(),
+      api::CollectionTraits {
+        parent,
+        members: 3,
+        traits: [
+          (
+            "background".into(),
+            [
+              (
+                "blue".into(),
+                api::CollectionTraitValue {
+                  count: 1,
+                  rarity_score: 3.0,
+                },
+              ),
+              (
+                "red".into(),
+                api::CollectionTraitValue {
+                  count: 2,
+                  rarity_score: 1.5,
+                },
+              ),
+            ]
+            .into(),
+          ),
+          (
+            "eyes".into(),
+            [(
+              "laser".into(),
+              api::CollectionTraitValue {
+                count: 1,
+                rarity_score: 3.0,
+              },
+            )]
+            .into(),
+          ),
+        ]
+        .into(),
+      }
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_collection_trait_inscriptions_paginated(parent, "background", "red", 1, 0)
+        .unwrap()
+        .unwrap(),
+      (vec![children[0]], true),
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_collection_trait_inscriptions_paginated(parent, "background", "red", 1, 1)
+        .unwrap()
+        .unwrap(),
+      (vec![children[2]], false),
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_collection_trait_inscriptions_paginated(parent, "background", "green", 1, 0)
+        .unwrap()
+        .unwrap(),
+      (Vec::new(), false),
+    );
+
+    let item = |id: InscriptionId, items: &[(&str, Trait)]| Item {
+      id: Some(id),
+      attributes: Attributes {
+        title: None,
+        traits: Traits {
+          items: items
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect(),
+        },
+      },
+    };
+
+    // items that were not inscribed before the gallery are ignored
+    let gallery = InscriptionId {
+      txid: context.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(6, 0, 0, Witness::new())],
+        script_sig: Inscription {
+          content_type: Some("text/plain;charset=utf-8".into()),
+          body: Some("gallery".into()),
+          properties: Properties {
+            gallery: vec![
+              item(children[0], &[("rank", Trait::Integer(1))]),
+              item(
+                children[1],
+                &[("rank", Trait::Integer(2)), ("shiny", Trait::Bool(true))],
+              ),
+              item(children[3], &[]),
+              item(inscription_id(9), &[("rank", Trait::Integer(3))]),
+            ],
+            ..default()
+          }
+          .to_cbor(),
+          ..default()
+        }
+        .to_script_sig(),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    context.mine_blocks(1);
+
+    pretty_assert_eq!(
+      context
+        .index
+        .get_collection_traits(gallery)
+        .unwrap()
+        .unwrap(),
+      api::CollectionTraits {
+        parent: gallery,
+        members: 2,
+        traits: [
+          (
+            "rank".into(),
+            [
+              (
+                "1".into(),
+                api::CollectionTraitValue {
+                  count: 1,
+                  rarity_score: 2.0,
+                },
+              ),
+              (
+                "2".into(),
+                api::CollectionTraitValue {
+                  count: 1,
+                  rarity_score: 2.0,
+                },
+              ),
+            ]
+            .into(),
+          ),
+          (
+            "shiny".into(),
+            [(
+              "true".into(),
+              api::CollectionTraitValue {
+                count: 1,
+                rarity_score: 2.0,
+              },
+            )]
+            .into(),
+          ),
+        ]
+        .into(),
+      }
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_collection_trait_inscriptions_paginated(gallery, "shiny", "true", 100, 0)
+        .unwrap()
+        .unwrap(),
+      (vec![children[1]], false),
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_collection_traits(inscription_id(9))
+        .unwrap(),
+      None
+    );
+  }
 }
@@ -289,7 +289,7 @@ impl Entry for DuneId {
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct InscriptionEntry {
-  pub charms: u16,
+  pub charms: u32,
   pub fee: u64,
   pub height: u32,
   pub id: InscriptionId,
@@ -301,7 +301,7 @@ pub struct InscriptionEntry {
 }
 
 pub(crate) type InscriptionEntryValue = (
-  u16,                // charms
+  u32,                // charms
   u64,                // fee
   u32,                // height
   InscriptionIdValue, // inscription id
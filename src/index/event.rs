@@ -4,7 +4,7 @@ use super::*;
 pub enum Event {
   InscriptionCreated {
     block_height: u32,
-    charms: u16,
+    charms: u32,
     inscription_id: InscriptionId,
     location: Option<KoinuPoint>,
     parent_inscription_ids: Vec<InscriptionId>,
@@ -85,6 +85,11 @@ impl Reorg {
       return Ok(false);
     }
 
+    // A block dump can't reorg, and there is no node to ask for the tip.
+    if index.settings.offline() {
+      return Ok(false);
+    }
+
     let height = u64::from(height);
 
     let last_savepoint_height = index
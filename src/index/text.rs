@@ -0,0 +1,188 @@
+//! Tokenizer for the optional full-text index built with `--index-text`.
+//!
+//! Text, markdown, JSON and HTML bodies are lowercased and split on
+//! non-alphanumeric characters. Each distinct term is stored with its count in
+//! `TEXT_TERM_TO_SEQUENCE_NUMBER`, keyed by `(term, sequence number)`, and the
+//! number of inscriptions containing each term is kept in
+//! `TEXT_TERM_TO_INSCRIPTION_COUNT`.
+
+use super::*;
+
+/// Only the first `MAX_BODY_SIZE` decoded bytes of a body are tokenized.
+pub(crate) const MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// At most `MAX_TERMS` distinct terms are indexed per inscription.
+const MAX_TERMS: usize = 512;
+
+/// Queries with more terms than this only use the first `MAX_QUERY_TERMS`.
+const MAX_QUERY_TERMS: usize = 8;
+
+/// Only the newest `MAX_SEARCH_CANDIDATES` inscriptions containing every term
+/// of a query are ranked.
+pub(crate) const MAX_SEARCH_CANDIDATES: usize = 10_000;
+
+const MIN_TERM_LENGTH: usize = 2;
+const MAX_TERM_LENGTH: usize = 32;
+
+static HTML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+
+pub(crate) fn is_searchable(media: Media) -> bool {
+  matches!(
+    media,
+    Media::Text | Media::Markdown | Media::Code(media::Language::Json) | Media::Iframe
+  )
+}
+
+/// Terms and their counts for an inscription, or `None` if its media type is
+/// not searchable or its body cannot be decoded.
+pub(crate) fn inscription_terms(inscription: &Inscription) -> Option<BTreeMap<String, u32>> {
+  let media = inscription.media();
+
+  if !is_searchable(media) {
+    return None;
+  }
+
+  let body = inscription.decoded_body(MAX_BODY_SIZE)?;
+
+  let text = String::from_utf8_lossy(&body);
+
+  let terms = if media == Media::Iframe {
+    terms(&HTML_TAG.replace_all(&text, " "))
+  } else {
+    terms(&text)
+  };
+
+  (!terms.is_empty()).then_some(terms)
+}
+
+/// Distinct terms of a search query, in order of appearance.
+pub(crate) fn query_terms(query: &str) -> Vec<String> {
+  let mut terms = Vec::new();
+
+  for term in split(query) {
+    if !terms.contains(&term) {
+      terms.push(term);
+    }
+
+    if terms.len() == MAX_QUERY_TERMS {
+      break;
+    }
+  }
+
+  terms
+}
+
+fn terms(text: &str) -> BTreeMap<String, u32> {
+  let mut terms = BTreeMap::<String, u32>::new();
+
+  for term in split(text) {
+    if let Some(count) = terms.get_mut(&term) {
+      *count += 1;
+    } else if terms.len() < MAX_TERMS {
+      terms.insert(term, 1);
+    }
+  }
+
+  terms
+}
+
+fn split(text: &str) -> impl Iterator<Item = String> + '_ {
+  text
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|term| (MIN_TERM_LENGTH..=MAX_TERM_LENGTH).contains(&term.chars().count()))
+    .map(str::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn terms(content_type: &str, body: &str) -> Option<String> {
+    inscription_terms(&inscription(content_type, body)).map(|terms| {
+      terms
+        .into_iter()
+        .map(|(term, count)| format!("{term}:{count}"))
+        .collect::<Vec<String>>()
+        .join(" ")
+    })
+  }
+
+  #[test]
+  fn plain_text() {
+    assert_eq!(
+      terms("text/plain;charset=utf-8", "Such wow. Much WOW, very doge!"),
+      Some("doge:1 much:1 such:1 very:1 wow:2".into()),
+    );
+  }
+
+  #[test]
+  fn json() {
+    assert_eq!(
+      terms(
+        "application/json",
+        r#"{"p":"drc-20","op":"deploy","tick":"dogi","max":"21000000"}"#,
+      ),
+      Some("20:1 21000000:1 deploy:1 dogi:1 drc:1 max:1 op:1 tick:1".into()),
+    );
+  }
+
+  #[test]
+  fn html_tags_are_stripped() {
+    assert_eq!(
+      terms(
+        "text/html;charset=utf-8",
+        "<html><body style=\"color:red\"><h1>Doge art</h1></body></html>",
+      ),
+      Some("art:1 doge:1".into()),
+    );
+  }
+
+  #[test]
+  fn unsearchable_media_is_skipped() {
+    assert_eq!(terms("image/png", "doge"), None);
+    assert_eq!(terms("text/javascript", "doge"), None);
+  }
+
+  #[test]
+  fn short_and_long_terms_are_skipped() {
+    assert_eq!(
+      terms(
+        "text/plain;charset=utf-8",
+        &format!("a {} ok", "x".repeat(33))
+      ),
+      Some("ok:1".into()),
+    );
+
+    assert_eq!(terms("text/plain;charset=utf-8", "a b c"), None);
+  }
+
+  #[test]
+  fn distinct_terms_are_capped() {
+    let body = (0..MAX_TERMS + 10)
+      .map(|i| format!("t{i}"))
+      .collect::<Vec<String>>()
+      .join(" ");
+
+    assert_eq!(
+      inscription_terms(&inscription("text/plain;charset=utf-8", body))
+        .unwrap()
+        .len(),
+      MAX_TERMS,
+    );
+  }
+
+  #[test]
+  fn query_terms_are_deduplicated() {
+    assert_eq!(
+      query_terms("Much wow much WOW doge"),
+      vec!["much", "wow", "doge"],
+    );
+
+    assert_eq!(query_terms(&"ab ".repeat(20)).len(), 1);
+
+    assert_eq!(
+      query_terms("aa bb cc dd ee ff gg hh ii jj").len(),
+      MAX_QUERY_TERMS,
+    );
+  }
+}
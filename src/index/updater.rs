@@ -1,7 +1,6 @@
 use {
   self::{dune_updater::DuneUpdater, inscription_updater::InscriptionUpdater},
   super::{fetcher::Fetcher, *},
-  bitcoin::consensus::deserialize,
   futures::future::try_join_all,
   serde::Deserialize,
   tokio::sync::{
@@ -10,17 +9,16 @@ use {
   },
 };
 
+pub(crate) mod auxpow;
 pub(crate) mod blk_reader;
 mod dune_updater;
 mod inscription_updater;
 
 /// Dogecoin Core's getblockheader verbose response. Uses optional nTx because
 /// some Dogecoin Core versions omit it (unlike Bitcoin Core).
-/// The hash field is the canonical block hash from the node (used for reorg detection).
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct DogecoinBlockHeaderInfo {
-  hash: BlockHash,
   version: bitcoin::block::Version,
   #[serde(rename = "merkleroot")]
   merkle_root: TxMerkleNode,
@@ -36,13 +34,20 @@ struct DogecoinBlockHeaderInfo {
 pub(crate) struct BlockData {
   pub(crate) header: Header,
   pub(crate) txdata: Vec<(Transaction, Txid)>,
-  /// Canonical block hash from RPC for AuxPoW blocks (bitcoin crate hash may differ).
-  pub(crate) auxpow_block_hash: Option<BlockHash>,
+  /// Merge-mining proof, when the block was parsed from raw bytes and carries one.
+  #[allow(dead_code)]
+  pub(crate) auxpow: Option<auxpow::AuxPow>,
   pub(crate) blk_file_pos: Option<(u32, u64, [u8; 32])>,
 }
 
-impl BlockData {
-  fn from_block(block: Block, auxpow_block_hash: Option<BlockHash>) -> Self {
+impl From<Block> for BlockData {
+  fn from(block: Block) -> Self {
+    auxpow::DogecoinBlock::from(block).into()
+  }
+}
+
+impl From<auxpow::DogecoinBlock> for BlockData {
+  fn from(block: auxpow::DogecoinBlock) -> Self {
     BlockData {
       header: block.header,
       txdata: block
@@ -53,18 +58,12 @@ impl BlockData {
           (transaction, txid)
         })
         .collect(),
-      auxpow_block_hash,
+      auxpow: block.auxpow,
       blk_file_pos: None,
     }
   }
 }
 
-impl From<Block> for BlockData {
-  fn from(block: Block) -> Self {
-    Self::from_block(block, None)
-  }
-}
-
 pub(crate) struct Updater<'index> {
   pub(super) height: u32,
   pub(super) started_height: u32,
@@ -104,7 +103,15 @@ impl Updater<'_> {
 
   pub(crate) fn update_index(&mut self, mut wtx: WriteTransaction) -> Result {
     let start = Instant::now();
-    let starting_height = u32::try_from(self.index.client.get_block_count()?).unwrap() + 1;
+    let starting_height = if self.index.settings.offline() {
+      ensure!(
+        self.index.have_full_utxo_index(),
+        "--offline can't look up spent outputs without Dogecoin Core, index from genesis with --index-addresses, --index-koinu or --first-inscription-height 0"
+      );
+      0
+    } else {
+      u32::try_from(self.index.client.get_block_count()?).unwrap() + 1
+    };
     let starting_index_height = self.started_height;
     let first_inscription_height = self.index.settings.first_inscription_height();
 
@@ -150,6 +157,8 @@ impl Updater<'_> {
     let mut uncommitted = 0;
     let mut utxo_cache = HashMap::new();
     while let Ok(block) = rx.recv() {
+      let block = block?;
+
       self.index_block(
         &mut output_sender,
         &mut txout_receiver,
@@ -229,7 +238,7 @@ impl Updater<'_> {
   fn fetch_blocks_from(
     index: &Index,
     mut height: u32,
-  ) -> Result<std::sync::mpsc::Receiver<BlockData>> {
+  ) -> Result<std::sync::mpsc::Receiver<Result<BlockData>>> {
     let (tx, rx) = std::sync::mpsc::sync_channel(32);
 
     let first_index_height = index.first_index_height;
@@ -246,7 +255,7 @@ impl Updater<'_> {
 
     let fast_reader: Option<blk_reader::BlkReader> = blocks_dir.clone().and_then(|dir| {
       let index_copy_dir = index.settings.dogecoin_blk_index_copy_dir()?;
-      match blk_reader::BlkReader::open(&dir, &index_copy_dir) {
+      match blk_reader::BlkReader::open(&dir, &index_copy_dir, chain) {
         Ok(reader) => reader,
         Err(e) => {
           log::warn!("BlkReader: unexpected error: {e}");
@@ -255,7 +264,18 @@ impl Updater<'_> {
       }
     });
 
-    let use_zmq_feed = index.settings.dogecoin_zmq_address().is_some();
+    let offline = index.settings.offline();
+
+    if offline && fast_reader.is_none() {
+      bail!(
+        "--offline requires `.blk` files in {}",
+        blocks_dir
+          .map(|dir| dir.display().to_string())
+          .unwrap_or_else(|| "the Dogecoin data directory".into())
+      );
+    }
+
+    let zmq_events = index.zmq_events();
 
     thread::spawn(move || {
       loop {
@@ -273,7 +293,7 @@ impl Updater<'_> {
             Ok(block) if block.block_hash().to_byte_array() == expected_hash => {
               let mut block_data: BlockData = block.into();
               block_data.blk_file_pos = Some((file_idx, offset, expected_hash));
-              if let Err(err) = tx.send(block_data) {
+              if let Err(err) = tx.send(Ok(block_data)) {
                 log::info!("Block receiver disconnected: {err}");
                 break;
               }
@@ -297,7 +317,7 @@ impl Updater<'_> {
             Ok(Some(block)) => {
               let mut block_data: BlockData = block.into();
               block_data.blk_file_pos = reader.location(height);
-              if let Err(err) = tx.send(block_data) {
+              if let Err(err) = tx.send(Ok(block_data)) {
                 log::info!("Block receiver disconnected: {err}");
                 break;
               }
@@ -306,8 +326,18 @@ impl Updater<'_> {
             }
             Ok(None) => {
               // Block not in .blk index yet (tip) — fall through to RPC
+              if offline {
+                break;
+              }
             }
             Err(e) => {
+              if offline {
+                tx.send(Err(e.context(format!(
+                  "failed to read block {height} from .blk files"
+                ))))
+                .ok();
+                break;
+              }
               log::warn!("BlkReader error at height {height}, retrying via RPC: {e}");
             }
           }
@@ -316,15 +346,15 @@ impl Updater<'_> {
         // RPC path: used for tip blocks and when BlkReader is unavailable
         match Self::get_block_with_retries(&client, height, first_index_height, chain) {
           Ok(Some(block_data)) => {
-            if let Err(err) = tx.send(block_data) {
+            if let Err(err) = tx.send(Ok(block_data)) {
               log::info!("Block receiver disconnected: {err}");
               break;
             }
             height += 1;
           }
           Ok(None) => {
-            if use_zmq_feed {
-              let _ = client.call::<serde_json::Value>("waitfornewblock", &[1u64.into()]);
+            if let Some(events) = &zmq_events {
+              Self::wait_for_zmq_block(&events.lock().unwrap());
               continue;
             }
             break;
@@ -340,45 +370,39 @@ impl Updater<'_> {
     Ok(rx)
   }
 
-  /// Fetch full block via getblock(1) + getrawtransaction for each tx.
-  /// Used for Dogecoin AuxPoW blocks when height >= first_index_height (raw getblock fails).
-  fn get_block_auxpow_full(client: &Client, hash: &BlockHash) -> Result<BlockData> {
-    #[derive(Deserialize)]
-    struct BlockTxids {
-      tx: Vec<Txid>,
+  /// Block until ZMQ announces a new block. Returns early on a sequence gap,
+  /// a lost subscriber, or after a timeout, so the caller polls RPC instead
+  /// of trusting a feed that may have dropped notifications.
+  fn wait_for_zmq_block(events: &std::sync::mpsc::Receiver<zmq::ZmqEvent>) {
+    const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+    match events.recv_timeout(POLL_INTERVAL) {
+      Ok(zmq::ZmqEvent::Block(hash)) => log::debug!("ZMQ announced block {hash}"),
+      Ok(zmq::ZmqEvent::Gap) => log::debug!("ZMQ feed interrupted, polling RPC"),
+      Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+      Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => thread::sleep(POLL_INTERVAL),
     }
-    let info: DogecoinBlockHeaderInfo = client.call(
-      "getblockheader",
-      &[serde_json::to_value(hash)?, true.into()],
-    )?;
-    let header = Self::header_from_block_header_info(&info)?;
+  }
 
-    let txids: BlockTxids = client.call(
+  /// Fetch a full block via `getblock <hash> 0` and parse the raw bytes,
+  /// including any AuxPoW header data.
+  fn get_block_raw(client: &Client, hash: &BlockHash) -> Result<BlockData> {
+    let hex: String = client.call(
       "getblock",
-      &[serde_json::to_value(hash)?, serde_json::Value::from(1u8)],
+      &[serde_json::to_value(hash)?, serde_json::Value::from(0u8)],
     )?;
 
-    let txdata: Vec<(Transaction, Txid)> = txids
-      .tx
-      .iter()
-      .map(|txid| {
-        let hex: String = client.call(
-          "getrawtransaction",
-          &[serde_json::to_value(txid)?, serde_json::Value::from(false)],
-        )?;
-        let bytes = hex::decode(hex.trim())?;
-        let tx = deserialize::<Transaction>(&bytes).map_err(anyhow::Error::msg)?;
-        let computed = tx.compute_txid();
-        Ok((tx, computed))
-      })
-      .collect::<Result<Vec<_>>>()?;
+    let block = auxpow::DogecoinBlock::parse(&hex::decode(hex.trim())?)
+      .with_context(|| format!("parsing block {hash}"))?;
 
-    Ok(BlockData {
-      header,
-      txdata,
-      auxpow_block_hash: Some(info.hash),
-      blk_file_pos: None,
-    })
+    if block.block_hash() != *hash {
+      bail!(
+        "block hash mismatch: expected {hash}, got {}",
+        block.block_hash()
+      );
+    }
+
+    Ok(block.into())
   }
 
   fn header_from_block_header_info(info: &DogecoinBlockHeaderInfo) -> Result<Header> {
@@ -414,12 +438,7 @@ impl Updater<'_> {
           option
             .map(|hash| {
               if height >= first_index_height {
-                if use_header_info {
-                  Self::get_block_auxpow_full(client, &hash)
-                } else {
-                  let block = client.get_block(&hash)?;
-                  Ok(BlockData::from_block(block, None))
-                }
+                Self::get_block_raw(client, &hash)
               } else if use_header_info {
                 let info: DogecoinBlockHeaderInfo = client.call(
                   "getblockheader",
@@ -429,13 +448,13 @@ impl Updater<'_> {
                   header: Self::header_from_block_header_info(&info)?,
                   txdata: Vec::new(),
                 };
-                Ok(BlockData::from_block(block, Some(info.hash)))
+                Ok(block.into())
               } else {
                 let block = Block {
                   header: client.get_block_header(&hash)?,
                   txdata: Vec::new(),
                 };
-                Ok(BlockData::from_block(block, None))
+                Ok(block.into())
               }
             })
             .transpose()
@@ -575,8 +594,13 @@ impl Updater<'_> {
     }
 
     if self.index.index_dunes && self.height >= self.index.settings.first_dune_height() {
+      let mut outpoint_to_commit_height = wtx.open_table(OUTPOINT_TO_COMMIT_HEIGHT)?;
       let mut outpoint_to_dune_balances = wtx.open_table(OUTPOINT_TO_DUNE_BALANCES)?;
+      let mut outpoint_to_dune_holder = wtx.open_table(OUTPOINT_TO_DUNE_HOLDER)?;
+      let mut dune_id_to_activity = wtx.open_table(DUNE_ID_TO_ACTIVITY)?;
+      let mut dune_id_to_block_mints = wtx.open_table(DUNE_ID_TO_BLOCK_MINTS)?;
       let mut dune_id_to_dune_entry = wtx.open_table(DUNE_ID_TO_DUNE_ENTRY)?;
+      let mut dune_id_to_holder_balance = wtx.open_table(DUNE_ID_TO_HOLDER_BALANCE)?;
       let mut dune_to_dune_id = wtx.open_table(DUNE_TO_DUNE_ID)?;
       let mut sequence_number_to_dune_id = wtx.open_table(SEQUENCE_NUMBER_TO_DUNE_ID)?;
       let mut transaction_id_to_dune = wtx.open_table(TRANSACTION_ID_TO_DUNE)?;
@@ -587,18 +611,24 @@ impl Updater<'_> {
         .unwrap_or(0);
 
       let mut dune_updater = DuneUpdater {
+        activity: &mut dune_id_to_activity,
+        block_mints: &mut dune_id_to_block_mints,
         event_sender: self.index.event_sender.as_ref(),
         block_time: block.header.time,
         burned: HashMap::new(),
-        client: &self.index.client,
+        chain: self.index.settings.chain(),
         height: self.height,
+        holder_balances: &mut dune_id_to_holder_balance,
         id_to_entry: &mut dune_id_to_dune_entry,
+        index_addresses: self.index.index_addresses,
         inscription_id_to_sequence_number: &mut inscription_id_to_sequence_number,
         minimum: Dune::minimum_at_height(
           self.index.settings.chain().network(),
           Height(self.height),
         ),
         outpoint_to_balances: &mut outpoint_to_dune_balances,
+        outpoint_to_commit_height: &mut outpoint_to_commit_height,
+        outpoint_to_holder: &mut outpoint_to_dune_holder,
         dune_to_id: &mut dune_to_dune_id,
         dunes,
         sequence_number_to_dune_id: &mut sequence_number_to_dune_id,
@@ -611,13 +641,28 @@ impl Updater<'_> {
       }
 
       dune_updater.update()?;
+    } else if self.index.index_dunes
+      && self.height >= self.index.settings.first_dune_commit_height()
+    {
+      let mut outpoint_to_commit_height = wtx.open_table(OUTPOINT_TO_COMMIT_HEIGHT)?;
+
+      for (tx, txid) in &block.txdata {
+        dune_updater::update_commit_outputs(
+          &mut outpoint_to_commit_height,
+          self.height,
+          tx,
+          *txid,
+        )?;
+      }
     }
 
     height_to_block_header.insert(&self.height, &block.header.store())?;
-    if let Some(hash) = block.auxpow_block_hash {
+
+    // blocks below the first index height are fetched without transactions
+    if self.height >= self.index.first_index_height {
       wtx
-        .open_table(HEIGHT_TO_BLOCK_HASH)?
-        .insert(&self.height, &hash.to_byte_array())?;
+        .open_table(HEIGHT_TO_TRANSACTION_COUNT)?
+        .insert(&self.height, &u32::try_from(block.txdata.len()).unwrap())?;
     }
 
     if let Some((file_idx, offset, hash)) = block.blk_file_pos {
@@ -660,15 +705,25 @@ impl Updater<'_> {
     let mut home_inscriptions = wtx.open_table(HOME_INSCRIPTIONS)?;
     let mut inscription_number_to_sequence_number =
       wtx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
+    let mut address_to_sequence_numbers =
+      wtx.open_multimap_table(ADDRESS_TO_INSCRIPTION_SEQUENCE_NUMBERS)?;
+    let mut collection_trait_to_sequence_numbers =
+      wtx.open_multimap_table(COLLECTION_TRAIT_TO_SEQUENCE_NUMBERS)?;
+    let mut content_hash_to_sequence_number =
+      wtx.open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?;
     let mut latest_child_to_collection =
       wtx.open_multimap_table(LATEST_CHILD_SEQUENCE_NUMBER_TO_COLLECTION_SEQUENCE_NUMBER)?;
     let mut outpoint_to_utxo_entry = wtx.open_table(OUTPOINT_TO_UTXO_ENTRY)?;
     let mut sat_to_satpoint = wtx.open_table(SAT_TO_SATPOINT)?;
     let mut koinu_to_sequence_number = wtx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
     let mut script_pubkey_to_outpoint = wtx.open_multimap_table(SCRIPT_PUBKEY_TO_OUTPOINT)?;
+    let mut sequence_number_to_address = wtx.open_table(SEQUENCE_NUMBER_TO_ADDRESS)?;
     let mut sequence_number_to_children = wtx.open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?;
+    let mut sequence_number_to_content_type = wtx.open_table(SEQUENCE_NUMBER_TO_CONTENT_TYPE)?;
     let mut sequence_number_to_inscription_entry =
       wtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+    let mut text_term_to_inscription_count = wtx.open_table(TEXT_TERM_TO_INSCRIPTION_COUNT)?;
+    let mut text_term_to_sequence_number = wtx.open_table(TEXT_TERM_TO_SEQUENCE_NUMBER)?;
     let mut transaction_id_to_transaction = wtx.open_table(TRANSACTION_ID_TO_TRANSACTION)?;
 
     let index_inscriptions = self.height >= self.index.settings.first_inscription_height()
@@ -747,8 +802,11 @@ impl Updater<'_> {
     let home_inscription_count = home_inscriptions.len()?;
 
     let mut inscription_updater = InscriptionUpdater {
+      address_to_sequence_numbers: &mut address_to_sequence_numbers,
       blessed_inscription_count,
       collection_to_latest_child: &mut collection_to_latest_child,
+      collection_trait_to_sequence_numbers: &mut collection_trait_to_sequence_numbers,
+      content_hash_to_sequence_number: &mut content_hash_to_sequence_number,
       cursed_inscription_count,
       flotsam: Vec::new(),
       gallery_sequence_numbers: &mut gallery_sequence_numbers,
@@ -762,8 +820,12 @@ impl Updater<'_> {
       next_sequence_number,
       reward: Height(self.height).subsidy(),
       koinu_to_sequence_number: &mut koinu_to_sequence_number,
+      sequence_number_to_address: &mut sequence_number_to_address,
       sequence_number_to_children: &mut sequence_number_to_children,
+      sequence_number_to_content_type: &mut sequence_number_to_content_type,
       sequence_number_to_entry: &mut sequence_number_to_inscription_entry,
+      text_term_to_inscription_count: &mut text_term_to_inscription_count,
+      text_term_to_sequence_number: &mut text_term_to_sequence_number,
       timestamp: block.header.time,
       transaction_buffer: Vec::new(),
       transaction_id_to_transaction: &mut transaction_id_to_transaction,
@@ -896,13 +958,30 @@ impl Updater<'_> {
         )?;
 
         if self.index.should_index_protocol("dns") {
-          self.index_dns_transaction(tx, *txid, block.header.time, wtx, statistic_to_count)?;
+          self.index_dns_transaction(
+            tx,
+            *txid,
+            block.header.time,
+            wtx,
+            statistic_to_count,
+            inscription_updater.id_to_sequence_number,
+            inscription_updater.sequence_number_to_entry,
+          )?;
         }
         if self.index.should_index_protocol("drc20") {
           self.index_drc20_transaction(tx, *txid, block.header.time, wtx, statistic_to_count)?;
         }
         if self.index.should_index_protocol("dogemap") {
-          self.index_dogemap_transaction(tx, *txid, block.header.time, wtx, statistic_to_count)?;
+          self.index_dogemap_transaction(
+            tx,
+            *txid,
+            block.header.time,
+            wtx,
+            statistic_to_count,
+            inscription_updater.id_to_sequence_number,
+            &output_utxo_entries,
+            block.txdata.len(),
+          )?;
         }
       }
 
@@ -1145,6 +1224,8 @@ impl Updater<'_> {
     block_time: u32,
     wtx: &WriteTransaction,
     statistic_to_count: &mut Table<'_, u64, u64>,
+    id_to_sequence_number: &Table<'_, InscriptionIdValue, u32>,
+    sequence_number_to_entry: &Table<'_, u32, InscriptionEntryValue>,
   ) -> Result<()> {
     use crate::subcommand::dns::is_valid_dns_namespace;
 
@@ -1152,7 +1233,9 @@ impl Updater<'_> {
     let mut dns_inscription_id_to_name = wtx.open_table(DNS_INSCRIPTION_ID_TO_NAME)?;
     let mut dns_namespace_to_names = wtx.open_multimap_table(DNS_NAMESPACE_TO_NAMES)?;
 
-    let envelopes = ParsedEnvelope::from_transaction(tx);
+    // Parse envelopes the same way as the inscription updater, so that names
+    // resolve to the inscriptions it assigned sequence numbers to
+    let envelopes = self.index.parse_envelopes(tx);
 
     for (envelope_index, envelope) in envelopes.into_iter().enumerate() {
       let Some(body) = envelope.payload.body() else {
@@ -1184,22 +1267,12 @@ impl Updater<'_> {
         index: envelope_index as u32,
       };
 
-      // Look up the sequence number for this inscription, then find its inscription number.
-      // We copy the u32 out immediately so the table guard is released before the next lookup.
-      let maybe_seq: Option<u32> = {
-        let id_to_seq = wtx.open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
-        id_to_seq.get(&inscription_id.store())?.map(|g| g.value())
-      };
-      let inscription_number: i32 = if let Some(seq) = maybe_seq {
-        let num_to_seq = wtx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
-        num_to_seq
-          .iter()?
-          .filter_map(|r| r.ok())
-          .find(|(_, s)| s.value() == seq)
-          .map(|(n, _)| n.value())
-          .unwrap_or(0)
-      } else {
-        0
+      let inscription_number = match id_to_sequence_number.get(&inscription_id.store())? {
+        Some(sequence_number) => sequence_number_to_entry
+          .get(sequence_number.value())?
+          .map(|entry| InscriptionEntry::load(entry.value()).inscription_number)
+          .unwrap_or(0),
+        None => 0,
       };
 
       let entry = DnsEntry {
@@ -1244,7 +1317,7 @@ impl Updater<'_> {
     wtx: &WriteTransaction,
     statistic_to_count: &mut Table<'_, u64, u64>,
   ) -> Result<()> {
-    use crate::subcommand::drc20::Drc20Transfer;
+    use crate::subcommand::drc20::{Drc20Activity, Drc20Transfer};
 
     // --- Step 1: Complete any pending transfers whose UTXOs are spent here ---
     {
@@ -1275,6 +1348,9 @@ impl Updater<'_> {
                 .unwrap_or(0);
               let new_bal = serde_json::to_vec(&(prev_bal + transfer.amount))?;
               balance_table.insert(recv_key.as_str(), new_bal.as_slice())?;
+              wtx
+                .open_multimap_table(DRC20_TICK_TO_HOLDERS)?
+                .insert(transfer.tick.as_str(), recv_addr.as_str())?;
 
               // Deduct from sender's transferable
               let send_key = format!("{}\t{}", transfer.from_address, transfer.tick);
@@ -1284,6 +1360,21 @@ impl Updater<'_> {
                 .unwrap_or(0);
               let new_trf = serde_json::to_vec(&prev_trf.saturating_sub(transfer.amount))?;
               transferable_table.insert(send_key.as_str(), new_trf.as_slice())?;
+
+              self.drc20_activity(
+                &transfer.tick,
+                Drc20Activity {
+                  op: "transfer".into(),
+                  amount: transfer.amount,
+                  from: Some(transfer.from_address.clone()),
+                  to: Some(recv_addr),
+                  txid,
+                  height: self.height,
+                  timestamp: block_time,
+                },
+                wtx,
+                statistic_to_count,
+              )?;
             }
           }
           outpoint_to_transfer.remove(&prev)?;
@@ -1352,14 +1443,21 @@ impl Updater<'_> {
           )?;
         }
         "mint" => {
-          self.drc20_mint(&json, &addr, wtx, statistic_to_count)?;
+          self.drc20_mint(&json, &addr, txid, block_time, wtx, statistic_to_count)?;
         }
         "transfer" => {
           let outpoint = OutPoint {
             txid,
             vout: out_idx as u32,
           };
-          self.drc20_transfer_create(&json, &addr, outpoint, wtx, statistic_to_count)?;
+          self.drc20_transfer_create(
+            &json,
+            &addr,
+            outpoint,
+            block_time,
+            wtx,
+            statistic_to_count,
+          )?;
         }
         _ => {}
       }
@@ -1373,7 +1471,16 @@ impl Updater<'_> {
   //
   // Protocol: first inscription with plain-text body exactly matching
   // "{block_number}.dogemap" claims that block number. No re-claiming allowed.
+  //
+  // Parcels: "{parcel}.{block_number}.dogemap" claims a parcel of a claimed
+  // block. It must be a child of the claim inscription, and parcel numbers
+  // are capped by the block's transaction count. First wins, as for blocks.
+  //
+  // Ownership: the owner of a Dogemap is the address holding its claim
+  // inscription, which is found in the output UTXO entries after inscriptions
+  // have been moved for this transaction.
   // ---------------------------------------------------------------------------
+  #[allow(clippy::too_many_arguments)]
   fn index_dogemap_transaction(
     &mut self,
     tx: &Transaction,
@@ -1381,10 +1488,17 @@ impl Updater<'_> {
     block_time: u32,
     wtx: &WriteTransaction,
     statistic_to_count: &mut Table<'_, u64, u64>,
+    id_to_sequence_number: &Table<'_, InscriptionIdValue, u32>,
+    output_utxo_entries: &[UtxoEntryBuf],
+    block_tx_count: usize,
   ) -> Result<()> {
-    use crate::index::entry::DogemapEntry;
+    use crate::{index::entry::DogemapEntry, subcommand::dogemap::DogemapTransfer};
 
-    let envelopes = ParsedEnvelope::from_transaction(tx);
+    let mut sequence_number_to_block = wtx.open_table(DOGEMAP_SEQUENCE_NUMBER_TO_BLOCK)?;
+
+    // Parse envelopes the same way as the inscription updater, so that claim
+    // IDs match the inscriptions it assigned sequence numbers to
+    let envelopes = self.index.parse_envelopes(tx);
 
     for (envelope_index, envelope) in envelopes.into_iter().enumerate() {
       let Some(body) = envelope.payload.body() else {
@@ -1396,15 +1510,25 @@ impl Updater<'_> {
       };
       let trimmed = text.trim();
 
-      // Must be exactly "{digits}.dogemap"
+      // Must be exactly "{digits}.dogemap" or "{digits}.{digits}.dogemap"
       let Some(prefix) = trimmed.strip_suffix(".dogemap") else {
         continue;
       };
-      if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_digit()) {
-        continue;
-      }
-      let Ok(target_block) = prefix.parse::<u32>() else {
-        continue;
+      let (parcel, target_block) = match prefix.split_once('.') {
+        Some((parcel, block)) => {
+          let (Some(parcel), Some(block)) =
+            (Self::dogemap_number(parcel), Self::dogemap_number(block))
+          else {
+            continue;
+          };
+          (Some(parcel), block)
+        }
+        None => {
+          let Some(block) = Self::dogemap_number(prefix) else {
+            continue;
+          };
+          (None, block)
+        }
       };
 
       // Bitmap/Dogemap spec: the claimed block must already exist at inscription time.
@@ -1413,17 +1537,66 @@ impl Updater<'_> {
         continue;
       }
 
+      let inscription_id = InscriptionId {
+        txid,
+        index: envelope_index as u32,
+      };
+
+      if let Some(parcel) = parcel {
+        let Some(claim) = wtx
+          .open_table(DOGEMAP_BLOCK_TO_CLAIM)?
+          .get(&target_block)?
+          .map(|guard| DogemapEntry::load(guard.value()))
+        else {
+          continue;
+        };
+
+        if !envelope
+          .payload
+          .parents()
+          .contains(&claim.owner_inscription_id)
+        {
+          continue;
+        }
+
+        // A child must spend its parent, so the claim inscription has to be in
+        // one of this transaction's inputs
+        let claim_outpoint = wtx
+          .open_table(DOGEMAP_BLOCK_TO_TRANSFERS)?
+          .range((target_block, 0)..=(target_block, u32::MAX))?
+          .next_back()
+          .transpose()?
+          .and_then(|(_, guard)| serde_json::from_slice::<DogemapTransfer>(guard.value()).ok())
+          .map(|transfer| transfer.location.outpoint);
+
+        if !tx
+          .input
+          .iter()
+          .any(|input| Some(input.previous_output) == claim_outpoint)
+        {
+          continue;
+        }
+
+        let mut parcels = wtx.open_table(DOGEMAP_PARCEL_TO_INSCRIPTION_ID)?;
+        if parcels.get(&(target_block, parcel))?.is_some() {
+          continue;
+        }
+
+        if u64::from(parcel) >= self.dogemap_tx_count(wtx, target_block, block_tx_count)? {
+          continue;
+        }
+
+        parcels.insert(&(target_block, parcel), &inscription_id.store())?;
+
+        continue;
+      }
+
       // Open the claims table and skip if already claimed (first wins)
       let mut claims = wtx.open_table(DOGEMAP_BLOCK_TO_CLAIM)?;
       if claims.get(&target_block)?.is_some() {
         continue;
       }
 
-      let inscription_id = InscriptionId {
-        txid,
-        index: envelope_index as u32,
-      };
-
       let entry = DogemapEntry {
         block_number: target_block,
         owner_inscription_id: inscription_id,
@@ -1433,6 +1606,10 @@ impl Updater<'_> {
 
       claims.insert(&target_block, entry.store())?;
 
+      if let Some(sequence_number) = id_to_sequence_number.get(&inscription_id.store())? {
+        sequence_number_to_block.insert(&sequence_number.value(), &target_block)?;
+      }
+
       // Update total count statistic
       let prev = statistic_to_count
         .get(&Statistic::DogemapClaims.key())?
@@ -1441,9 +1618,117 @@ impl Updater<'_> {
       statistic_to_count.insert(&Statistic::DogemapClaims.key(), &(prev + 1))?;
     }
 
+    if sequence_number_to_block.is_empty()? {
+      return Ok(());
+    }
+
+    let mut block_to_owner = wtx.open_table(DOGEMAP_BLOCK_TO_OWNER)?;
+    let mut owner_to_blocks = wtx.open_multimap_table(DOGEMAP_OWNER_TO_BLOCKS)?;
+    let mut block_to_transfers = wtx.open_table(DOGEMAP_BLOCK_TO_TRANSFERS)?;
+
+    for (vout, output_utxo_entry) in output_utxo_entries.iter().enumerate() {
+      for (sequence_number, offset) in output_utxo_entry.parse(self.index).parse_inscriptions() {
+        let Some(block_number) = sequence_number_to_block
+          .get(&sequence_number)?
+          .map(|guard| guard.value())
+        else {
+          continue;
+        };
+
+        let from = block_to_owner
+          .remove(&block_number)?
+          .map(|guard| guard.value().to_string());
+
+        if let Some(from) = &from {
+          owner_to_blocks.remove(from.as_str(), &block_number)?;
+        }
+
+        let to = self
+          .index
+          .settings
+          .chain()
+          .address_string_from_script(tx.output[vout].script_pubkey.as_script());
+
+        if let Some(to) = &to {
+          block_to_owner.insert(&block_number, to.as_str())?;
+          owner_to_blocks.insert(to.as_str(), &block_number)?;
+        }
+
+        let n = block_to_transfers
+          .range((block_number, 0)..=(block_number, u32::MAX))?
+          .next_back()
+          .transpose()?
+          .map(|(key, _)| key.value().1 + 1)
+          .unwrap_or_default();
+
+        let transfer = DogemapTransfer {
+          height: self.height,
+          timestamp: block_time,
+          location: KoinuPoint {
+            outpoint: OutPoint {
+              txid,
+              vout: vout.try_into().unwrap(),
+            },
+            offset,
+          },
+          from,
+          to,
+        };
+
+        block_to_transfers.insert(
+          &(block_number, n),
+          serde_json::to_vec(&transfer)?.as_slice(),
+        )?;
+      }
+    }
+
     Ok(())
   }
 
+  fn dogemap_number(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+      return None;
+    }
+    digits.parse().ok()
+  }
+
+  /// Transaction count of `block_number`, the same count shown on
+  /// `/dogemap/{block}`. Blocks below the height indexing started at are
+  /// fetched from the node, which `--offline` never needs since it indexes
+  /// from genesis.
+  fn dogemap_tx_count(
+    &self,
+    wtx: &WriteTransaction,
+    block_number: u32,
+    current_block_tx_count: usize,
+  ) -> Result<u64> {
+    if block_number == self.height {
+      return Ok(current_block_tx_count.try_into().unwrap());
+    }
+
+    if let Some(tx_count) = wtx
+      .open_table(HEIGHT_TO_TRANSACTION_COUNT)?
+      .get(&block_number)?
+    {
+      return Ok(tx_count.value().into());
+    }
+
+    ensure!(
+      !self.index.settings.offline(),
+      "transaction count of block {block_number} is not indexed and --offline can't fetch it from Dogecoin Core"
+    );
+
+    let hash = self.index.client.get_block_hash(block_number.into())?;
+
+    Ok(
+      Self::get_block_raw(&self.index.client, &hash)?
+        .txdata
+        .len()
+        .try_into()
+        .unwrap(),
+    )
+  }
+
   fn drc20_deploy(
     &mut self,
     json: &serde_json::Value,
@@ -1453,7 +1738,7 @@ impl Updater<'_> {
     wtx: &WriteTransaction,
     statistic_to_count: &mut Table<'_, u64, u64>,
   ) -> Result<()> {
-    use crate::subcommand::drc20::{Drc20Token, json_to_amount_str, parse_amount};
+    use crate::subcommand::drc20::{Drc20Activity, Drc20Token, json_to_amount_str, parse_amount};
 
     let Some(tick_raw) = json.get("tick").and_then(|v| v.as_str()) else {
       return Ok(());
@@ -1520,6 +1805,21 @@ impl Updater<'_> {
       .unwrap_or(0);
     statistic_to_count.insert(&Statistic::Drc20Tokens.key(), &(prev + 1))?;
 
+    self.drc20_activity(
+      &tick_lower,
+      Drc20Activity {
+        op: "deploy".into(),
+        amount: max_supply,
+        from: None,
+        to: Some(deployer.to_string()),
+        txid: inscription_id.txid,
+        height: self.height,
+        timestamp: block_time,
+      },
+      wtx,
+      statistic_to_count,
+    )?;
+
     Ok(())
   }
 
@@ -1527,10 +1827,12 @@ impl Updater<'_> {
     &mut self,
     json: &serde_json::Value,
     recipient: &str,
+    txid: Txid,
+    block_time: u32,
     wtx: &WriteTransaction,
-    _statistic_to_count: &mut Table<'_, u64, u64>,
+    statistic_to_count: &mut Table<'_, u64, u64>,
   ) -> Result<()> {
-    use crate::subcommand::drc20::{Drc20Token, json_to_amount_str, parse_amount};
+    use crate::subcommand::drc20::{Drc20Activity, Drc20Token, json_to_amount_str, parse_amount};
 
     let Some(tick_raw) = json.get("tick").and_then(|v| v.as_str()) else {
       return Ok(());
@@ -1581,6 +1883,9 @@ impl Updater<'_> {
       .unwrap_or(0);
     let new_bal = serde_json::to_vec(&(prev + amt))?;
     balance_table.insert(bal_key.as_str(), new_bal.as_slice())?;
+    wtx
+      .open_multimap_table(DRC20_TICK_TO_HOLDERS)?
+      .insert(tick_lower.as_str(), recipient)?;
 
     // Update token metadata
     token.minted += amt;
@@ -1588,6 +1893,21 @@ impl Updater<'_> {
     let updated = serde_json::to_vec(&token)?;
     token_table.insert(tick_lower.as_str(), updated.as_slice())?;
 
+    self.drc20_activity(
+      &tick_lower,
+      Drc20Activity {
+        op: "mint".into(),
+        amount: amt,
+        from: None,
+        to: Some(recipient.to_string()),
+        txid,
+        height: self.height,
+        timestamp: block_time,
+      },
+      wtx,
+      statistic_to_count,
+    )?;
+
     Ok(())
   }
 
@@ -1596,10 +1916,13 @@ impl Updater<'_> {
     json: &serde_json::Value,
     sender: &str,
     outpoint: OutPoint,
+    block_time: u32,
     wtx: &WriteTransaction,
-    _statistic_to_count: &mut Table<'_, u64, u64>,
+    statistic_to_count: &mut Table<'_, u64, u64>,
   ) -> Result<()> {
-    use crate::subcommand::drc20::{Drc20Token, Drc20Transfer, json_to_amount_str, parse_amount};
+    use crate::subcommand::drc20::{
+      Drc20Activity, Drc20Token, Drc20Transfer, json_to_amount_str, parse_amount,
+    };
 
     let Some(tick_raw) = json.get("tick").and_then(|v| v.as_str()) else {
       return Ok(());
@@ -1653,7 +1976,7 @@ impl Updater<'_> {
 
     // Record this outpoint as a pending transfer
     let transfer = Drc20Transfer {
-      tick: tick_lower,
+      tick: tick_lower.clone(),
       amount: amt,
       from_address: sender.to_string(),
     };
@@ -1661,6 +1984,45 @@ impl Updater<'_> {
     let mut outpoint_to_transfer = wtx.open_table(DRC20_OUTPOINT_TO_TRANSFER)?;
     outpoint_to_transfer.insert(&outpoint.store(), transfer_bytes.as_slice())?;
 
+    self.drc20_activity(
+      &tick_lower,
+      Drc20Activity {
+        op: "inscribe-transfer".into(),
+        amount: amt,
+        from: Some(sender.to_string()),
+        to: None,
+        txid: outpoint.txid,
+        height: self.height,
+        timestamp: block_time,
+      },
+      wtx,
+      statistic_to_count,
+    )?;
+
+    Ok(())
+  }
+
+  /// Append to the tick's activity log, keyed by a global counter so entries
+  /// for a tick are in indexing order.
+  fn drc20_activity(
+    &self,
+    tick_lower: &str,
+    activity: crate::subcommand::drc20::Drc20Activity,
+    wtx: &WriteTransaction,
+    statistic_to_count: &mut Table<'_, u64, u64>,
+  ) -> Result<()> {
+    let number = statistic_to_count
+      .get(&Statistic::Drc20Activity.key())?
+      .map(|g| g.value())
+      .unwrap_or(0);
+
+    wtx.open_table(DRC20_ACTIVITY)?.insert(
+      &(tick_lower, number),
+      serde_json::to_vec(&activity)?.as_slice(),
+    )?;
+
+    statistic_to_count.insert(&Statistic::Drc20Activity.key(), &(number + 1))?;
+
     Ok(())
   }
 }
@@ -0,0 +1,225 @@
+//! Dogecoin block deserialization with AuxPoW (merged mining) support.
+//!
+//! Since height 371,337 on mainnet (and from genesis on testnet) Dogecoin
+//! blocks may be merge-mined. Such blocks set the AuxPoW bit in the header
+//! version and carry the parent chain's proof of work between the 80-byte
+//! header and the transaction list:
+//!
+//! ```text
+//!   [80 bytes]  block header            (nVersion & 0x100 != 0)
+//!   [tx]        parent coinbase transaction
+//!   [32 bytes]  parent block hash
+//!   [vec]       coinbase merkle branch
+//!   [i32]       coinbase branch index
+//!   [vec]       chain merkle branch
+//!   [i32]       chain branch index
+//!   [80 bytes]  parent block header
+//!   [vec<tx>]   transactions
+//! ```
+//!
+//! `bitcoin::Block` knows nothing about the middle section, so both `.blk`
+//! reads and raw `getblock <hash> 0` responses go through [`DogecoinBlock`].
+
+use {
+  crate::Result,
+  anyhow::{Context, ensure},
+  bitcoin::{
+    Block, BlockHash, Transaction, TxMerkleNode,
+    block::Header,
+    consensus::{Decodable, ReadExt},
+    io::{BufRead, Cursor},
+  },
+};
+
+/// Header version bit marking a merge-mined block.
+pub(crate) const VERSION_AUXPOW: i32 = 1 << 8;
+
+/// Proof that a Dogecoin block was merge-mined into a parent chain block.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct AuxPow {
+  pub(crate) coinbase_tx: Transaction,
+  pub(crate) parent_hash: BlockHash,
+  pub(crate) coinbase_branch: Vec<TxMerkleNode>,
+  pub(crate) coinbase_index: i32,
+  pub(crate) chain_branch: Vec<TxMerkleNode>,
+  pub(crate) chain_index: i32,
+  pub(crate) parent_header: Header,
+}
+
+impl AuxPow {
+  fn consensus_decode<R: BufRead + ?Sized>(reader: &mut R) -> Result<Self> {
+    Ok(Self {
+      coinbase_tx: Transaction::consensus_decode(reader).context("parent coinbase")?,
+      parent_hash: BlockHash::consensus_decode(reader).context("parent block hash")?,
+      coinbase_branch: Vec::consensus_decode(reader).context("coinbase merkle branch")?,
+      coinbase_index: reader.read_i32().context("coinbase branch index")?,
+      chain_branch: Vec::consensus_decode(reader).context("chain merkle branch")?,
+      chain_index: reader.read_i32().context("chain branch index")?,
+      parent_header: Header::consensus_decode(reader).context("parent block header")?,
+    })
+  }
+}
+
+/// A Dogecoin block, including AuxPoW data when present.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct DogecoinBlock {
+  pub(crate) header: Header,
+  pub(crate) auxpow: Option<AuxPow>,
+  pub(crate) txdata: Vec<Transaction>,
+}
+
+impl DogecoinBlock {
+  pub(crate) fn parse(bytes: &[u8]) -> Result<Self> {
+    let mut cursor = Cursor::new(bytes);
+
+    let header = Header::consensus_decode(&mut cursor).context("block header")?;
+
+    let auxpow = if header.version.to_consensus() & VERSION_AUXPOW != 0 {
+      Some(AuxPow::consensus_decode(&mut cursor)?)
+    } else {
+      None
+    };
+
+    let txdata = Vec::<Transaction>::consensus_decode(&mut cursor).context("transactions")?;
+
+    ensure!(
+      cursor.position() == bytes.len() as u64,
+      "{} trailing bytes after block",
+      bytes.len() as u64 - cursor.position(),
+    );
+
+    Ok(Self {
+      header,
+      auxpow,
+      txdata,
+    })
+  }
+
+  /// The block hash is the double-SHA256 of the 80-byte header alone, so
+  /// AuxPoW data does not affect it.
+  pub(crate) fn block_hash(&self) -> BlockHash {
+    self.header.block_hash()
+  }
+}
+
+impl From<Block> for DogecoinBlock {
+  fn from(block: Block) -> Self {
+    Self {
+      header: block.header,
+      auxpow: None,
+      txdata: block.txdata,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    bitcoin::{
+      Amount, OutPoint, ScriptBuf, Sequence, TxIn, TxOut, Witness,
+      absolute::LockTime,
+      block::Version,
+      consensus::{Encodable, serialize},
+      hashes::Hash,
+      pow::CompactTarget,
+      transaction,
+    },
+  };
+
+  fn coinbase(tag: u8) -> Transaction {
+    Transaction {
+      version: transaction::Version(1),
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: OutPoint::null(),
+        script_sig: ScriptBuf::from_bytes(vec![tag; 4]),
+        sequence: Sequence::MAX,
+        witness: Witness::new(),
+      }],
+      output: vec![TxOut {
+        value: Amount::from_sat(10_000),
+        script_pubkey: ScriptBuf::new(),
+      }],
+    }
+  }
+
+  fn header(version: i32) -> Header {
+    Header {
+      version: Version::from_consensus(version),
+      prev_blockhash: BlockHash::all_zeros(),
+      merkle_root: TxMerkleNode::all_zeros(),
+      time: 1_400_000_000,
+      bits: CompactTarget::from_consensus(0x1e0f_fff0),
+      nonce: 7,
+    }
+  }
+
+  #[test]
+  fn parse_plain_block() {
+    let block = Block {
+      header: header(1),
+      txdata: vec![coinbase(1)],
+    };
+
+    let parsed = DogecoinBlock::parse(&serialize(&block)).unwrap();
+
+    assert_eq!(parsed, DogecoinBlock::from(block.clone()));
+    assert_eq!(parsed.block_hash(), block.block_hash());
+  }
+
+  #[test]
+  fn parse_auxpow_block() {
+    let header = header(0x0062_0004 | VERSION_AUXPOW);
+
+    let auxpow = AuxPow {
+      coinbase_tx: coinbase(2),
+      parent_hash: BlockHash::from_byte_array([3; 32]),
+      coinbase_branch: vec![TxMerkleNode::from_byte_array([4; 32])],
+      coinbase_index: 0,
+      chain_branch: vec![
+        TxMerkleNode::from_byte_array([5; 32]),
+        TxMerkleNode::from_byte_array([6; 32]),
+      ],
+      chain_index: 2,
+      parent_header: self::header(2),
+    };
+
+    let mut bytes = Vec::new();
+    header.consensus_encode(&mut bytes).unwrap();
+    auxpow.coinbase_tx.consensus_encode(&mut bytes).unwrap();
+    auxpow.parent_hash.consensus_encode(&mut bytes).unwrap();
+    auxpow.coinbase_branch.consensus_encode(&mut bytes).unwrap();
+    auxpow.coinbase_index.consensus_encode(&mut bytes).unwrap();
+    auxpow.chain_branch.consensus_encode(&mut bytes).unwrap();
+    auxpow.chain_index.consensus_encode(&mut bytes).unwrap();
+    auxpow.parent_header.consensus_encode(&mut bytes).unwrap();
+    vec![coinbase(1)].consensus_encode(&mut bytes).unwrap();
+
+    assert!(bitcoin::consensus::deserialize::<Block>(&bytes).is_err());
+
+    let parsed = DogecoinBlock::parse(&bytes).unwrap();
+
+    assert_eq!(parsed.header, header);
+    assert_eq!(parsed.auxpow, Some(auxpow.clone()));
+    assert_eq!(parsed.txdata, vec![coinbase(1)]);
+    assert_eq!(parsed.block_hash(), header.block_hash());
+
+    assert_eq!(super::super::BlockData::from(parsed).auxpow, Some(auxpow));
+  }
+
+  #[test]
+  fn trailing_bytes_are_rejected() {
+    let mut bytes = serialize(&Block {
+      header: header(1),
+      txdata: vec![coinbase(1)],
+    });
+
+    bytes.push(0);
+
+    assert_eq!(
+      DogecoinBlock::parse(&bytes).unwrap_err().to_string(),
+      "1 trailing bytes after block",
+    );
+  }
+}
@@ -21,19 +21,33 @@
 //! ```text
 //! dog index refresh-blk-index
 //! ```
+//!
+//! ## Linear scan
+//!
+//! When there is no `blocks/index/` at all — e.g. an archived copy of the
+//! `blk*.dat` files on a build server — the block locations are recovered by
+//! scanning every `.blk` file for the chain's network magic, linking headers
+//! by their previous-block hash, and following the most-work chain from
+//! genesis. See [`BlkReader::scan`].
+//!
+//! The headers found in each file are saved to `scan.dat` in the index copy
+//! directory, so later runs only re-read `.blk` files whose length or
+//! modification time has changed.
 
 use {
-  crate::Result,
+  super::auxpow::DogecoinBlock,
+  crate::{Result, chain::Chain},
   anyhow::Context,
-  bitcoin::hashes::Hash,
-  bitcoin::{Block, consensus::deserialize},
-  byteorder::{LittleEndian, ReadBytesExt},
+  bitcoin::{
+    BlockHash, CompactTarget, Target, Work, block::Header, consensus::deserialize, hashes::Hash,
+  },
+  byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt},
   rusty_leveldb::{DB, LdbIterator, Options},
   std::{
     collections::HashMap,
     ffi::OsStr,
     fs,
-    io::{BufReader, Cursor, Read, Seek, SeekFrom},
+    io::{BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
     time::SystemTime,
   },
@@ -47,6 +61,9 @@ const _: () = assert!(crate::chain::chainparams::MAGIC_REGTEST.len() == 4);
 /// height → (blk_file_index, data_offset_within_file, block_hash_bytes)
 type BlkIndex = HashMap<u32, (u32, u64, [u8; 32])>;
 
+const SCAN_CACHE_FILE: &str = "scan.dat";
+const SCAN_CACHE_VERSION: u32 = 1;
+
 /// Reads blocks directly from Dogecoin Core's `.blk` files.
 pub(crate) struct BlkReader {
   blocks_dir: PathBuf,
@@ -61,14 +78,24 @@ impl BlkReader {
   /// refreshed here before opening so it is always current.
   ///
   /// Fall-through order:
+  /// 0. No `blocks/index/` at all → linear scan of the `.blk` files, cached
+  ///    in `index_copy_dir`
   /// 1. Refresh shadow copy (smart-copy — fast on subsequent runs)
   /// 2. Open shadow copy
   /// 3. Open live index (only succeeds when Core is not running)
   /// 4. Return `Ok(None)` → caller falls back to RPC
-  pub(crate) fn open(blocks_dir: &Path, index_copy_dir: &Path) -> Result<Option<Self>> {
+  pub(crate) fn open(
+    blocks_dir: &Path,
+    index_copy_dir: &Path,
+    chain: Chain,
+  ) -> Result<Option<Self>> {
     let live_index = blocks_dir.join("index");
     if !live_index.exists() {
-      return Ok(None);
+      if !blocks_dir.join("blk00000.dat").exists() {
+        return Ok(None);
+      }
+
+      return Self::scan(blocks_dir, index_copy_dir, chain).map(Some);
     }
 
     // Refresh the shadow copy.  Safe while Core runs — immutable SST files
@@ -129,6 +156,146 @@ impl BlkReader {
     }
   }
 
+  /// Build the height index by linearly scanning every `blkNNNNN.dat` file in
+  /// `blocks_dir`, without Core's LevelDB index.
+  ///
+  /// Only block headers are decoded. Blocks are linked through
+  /// `prev_blockhash`, and heights are assigned along the chain with the most
+  /// cumulative work starting from `chain`'s genesis block, so stale forks and
+  /// out-of-order blocks in the files are handled the same way Core would.
+  ///
+  /// Headers are read back from `cache_dir` for files that haven't changed
+  /// since the last scan, and the cache is rewritten afterwards.
+  pub(crate) fn scan(blocks_dir: &Path, cache_dir: &Path, chain: Chain) -> Result<Self> {
+    let magic = chain.magic();
+
+    let mut files = fs::read_dir(blocks_dir)
+      .with_context(|| format!("reading {}", blocks_dir.display()))?
+      .filter_map(|entry| {
+        let name = entry.ok()?.file_name();
+        name
+          .to_str()?
+          .strip_prefix("blk")?
+          .strip_suffix(".dat")?
+          .parse::<u32>()
+          .ok()
+      })
+      .collect::<Vec<u32>>();
+
+    files.sort_unstable();
+
+    let cache_path = cache_dir.join(SCAN_CACHE_FILE);
+
+    let mut cache = match read_scan_cache(&cache_path) {
+      Ok(cache) => cache,
+      Err(err) => {
+        if cache_path.exists() {
+          log::warn!(
+            "BlkReader: ignoring unreadable scan cache {}: {err}",
+            cache_path.display()
+          );
+        }
+        ScanCache::new()
+      }
+    };
+
+    let mut scanned = ScanCache::new();
+    let mut rescanned = 0;
+
+    for file_idx in files {
+      let stamp = file_stamp(&blocks_dir.join(format!("blk{:05}.dat", file_idx)))?;
+
+      let file_headers = match cache.remove(&file_idx) {
+        Some((cached, file_headers)) if cached == stamp => file_headers,
+        _ => {
+          rescanned += 1;
+          scan_file(blocks_dir, file_idx, magic)?
+        }
+      };
+
+      scanned.insert(file_idx, (stamp, file_headers));
+    }
+
+    if (rescanned > 0 || !cache.is_empty())
+      && let Err(err) = write_scan_cache(&cache_path, &scanned)
+    {
+      log::warn!(
+        "BlkReader: could not write scan cache {}: {err}",
+        cache_path.display()
+      );
+    }
+
+    let mut headers = HashMap::<BlockHash, ScannedHeader>::new();
+
+    let mut file_indices = scanned.keys().copied().collect::<Vec<u32>>();
+    file_indices.sort_unstable();
+
+    for file_idx in file_indices {
+      for (hash, header) in &scanned[&file_idx].1 {
+        headers.entry(*hash).or_insert(*header);
+      }
+    }
+
+    let genesis = chain.genesis_block().block_hash();
+
+    anyhow::ensure!(
+      headers.contains_key(&genesis),
+      "genesis block {genesis} not found in {}",
+      blocks_dir.display()
+    );
+
+    let mut children = HashMap::<BlockHash, Vec<BlockHash>>::new();
+    for (hash, header) in &headers {
+      children
+        .entry(header.prev_blockhash)
+        .or_default()
+        .push(*hash);
+    }
+
+    // Walk forward from genesis, tracking height and cumulative work, and
+    // remember the tip with the most work.
+    let mut best = (genesis, 0u32, headers[&genesis].work());
+    let mut stack = vec![(genesis, 0u32, headers[&genesis].work())];
+    while let Some((hash, height, work)) = stack.pop() {
+      if work > best.2 {
+        best = (hash, height, work);
+      }
+
+      for child in children.get(&hash).into_iter().flatten() {
+        stack.push((*child, height + 1, work + headers[child].work()));
+      }
+    }
+
+    let (mut hash, mut height, _) = best;
+    let mut index = BlkIndex::with_capacity(usize::try_from(height)? + 1);
+    loop {
+      let header = &headers[&hash];
+      index.insert(
+        height,
+        (header.file_idx, header.data_offset, hash.to_byte_array()),
+      );
+
+      if height == 0 {
+        break;
+      }
+
+      hash = header.prev_blockhash;
+      height -= 1;
+    }
+
+    log::info!(
+      "BlkReader: linear scan found {} blocks ({rescanned} files read, {} cached), best chain height {}",
+      headers.len(),
+      scanned.len() - rescanned,
+      index.len() - 1,
+    );
+
+    Ok(Self {
+      blocks_dir: blocks_dir.to_owned(),
+      index,
+    })
+  }
+
   /// Highest block height available in the on-disk index.
   #[allow(dead_code)]
   pub(crate) fn max_height(&self) -> u32 {
@@ -143,7 +310,7 @@ impl BlkReader {
   ///
   /// Returns `Ok(None)` when the height is not yet indexed (tip blocks that
   /// haven't been flushed to `.blk` files yet — caller falls back to RPC).
-  pub(crate) fn get(&self, height: u32) -> Result<Option<Block>> {
+  pub(crate) fn get(&self, height: u32) -> Result<Option<DogecoinBlock>> {
     let Some(&(file_idx, data_offset, expected_hash)) = self.index.get(&height) else {
       return Ok(None);
     };
@@ -301,6 +468,202 @@ fn read_varint(cur: &mut Cursor<&[u8]>) -> std::io::Result<u64> {
   Ok(n)
 }
 
+// ---------------------------------------------------------------------------
+// Linear .blk scanning
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ScannedHeader {
+  prev_blockhash: BlockHash,
+  bits: CompactTarget,
+  file_idx: u32,
+  data_offset: u64,
+}
+
+impl ScannedHeader {
+  fn work(&self) -> Work {
+    Target::from_compact(self.bits).to_work()
+  }
+}
+
+/// Length and modification time of a `.blk` file, in nanoseconds since the
+/// Unix epoch. Core appends into preallocated space, so the length alone
+/// doesn't change when blocks are written.
+type FileStamp = (u64, u128);
+
+/// file index → (stamp when scanned, headers found in the file)
+type ScanCache = HashMap<u32, (FileStamp, Vec<(BlockHash, ScannedHeader)>)>;
+
+fn file_stamp(path: &Path) -> Result<FileStamp> {
+  let metadata = fs::metadata(path).with_context(|| format!("reading {}", path.display()))?;
+
+  let modified = metadata
+    .modified()?
+    .duration_since(SystemTime::UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+
+  Ok((metadata.len(), modified))
+}
+
+/// Scan cache layout, all integers little-endian:
+/// ```text
+///   [4 bytes]   version
+///   [4 bytes]   file count
+///   per file:
+///     [4 bytes]   file index
+///     [8 bytes]   file length
+///     [16 bytes]  file modification time
+///     [4 bytes]   header count
+///     per header: hash (32), prev_blockhash (32), bits (4), data_offset (8)
+/// ```
+fn read_scan_cache(path: &Path) -> Result<ScanCache> {
+  let mut reader = BufReader::new(fs::File::open(path)?);
+
+  let version = reader.read_u32::<LittleEndian>()?;
+  anyhow::ensure!(
+    version == SCAN_CACHE_VERSION,
+    "unsupported version {version}"
+  );
+
+  let mut cache = ScanCache::new();
+
+  for _ in 0..reader.read_u32::<LittleEndian>()? {
+    let file_idx = reader.read_u32::<LittleEndian>()?;
+    let stamp = (
+      reader.read_u64::<LittleEndian>()?,
+      reader.read_u128::<LittleEndian>()?,
+    );
+
+    let count = reader.read_u32::<LittleEndian>()?;
+    let mut headers = Vec::with_capacity(usize::try_from(count)?);
+
+    for _ in 0..count {
+      let mut hash = [0; 32];
+      reader.read_exact(&mut hash)?;
+      let mut prev_blockhash = [0; 32];
+      reader.read_exact(&mut prev_blockhash)?;
+
+      headers.push((
+        BlockHash::from_byte_array(hash),
+        ScannedHeader {
+          prev_blockhash: BlockHash::from_byte_array(prev_blockhash),
+          bits: CompactTarget::from_consensus(reader.read_u32::<LittleEndian>()?),
+          file_idx,
+          data_offset: reader.read_u64::<LittleEndian>()?,
+        },
+      ));
+    }
+
+    cache.insert(file_idx, (stamp, headers));
+  }
+
+  Ok(cache)
+}
+
+/// Write the cache to a temporary file and rename it into place, so an
+/// interrupted write never leaves a truncated cache behind.
+fn write_scan_cache(path: &Path, cache: &ScanCache) -> Result {
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+
+  let partial = path.with_extension("partial");
+
+  let mut writer = BufWriter::new(fs::File::create(&partial)?);
+
+  writer.write_u32::<LittleEndian>(SCAN_CACHE_VERSION)?;
+  writer.write_u32::<LittleEndian>(u32::try_from(cache.len())?)?;
+
+  for (file_idx, ((len, modified), headers)) in cache {
+    writer.write_u32::<LittleEndian>(*file_idx)?;
+    writer.write_u64::<LittleEndian>(*len)?;
+    writer.write_u128::<LittleEndian>(*modified)?;
+    writer.write_u32::<LittleEndian>(u32::try_from(headers.len())?)?;
+
+    for (hash, header) in headers {
+      writer.write_all(hash.as_byte_array())?;
+      writer.write_all(header.prev_blockhash.as_byte_array())?;
+      writer.write_u32::<LittleEndian>(header.bits.to_consensus())?;
+      writer.write_u64::<LittleEndian>(header.data_offset)?;
+    }
+  }
+
+  writer
+    .into_inner()
+    .map_err(|err| err.into_error())?
+    .sync_all()?;
+
+  fs::rename(&partial, path)?;
+
+  Ok(())
+}
+
+/// Record the header and location of every block in one `.blk` file.
+///
+/// Core preallocates `.blk` files in chunks, so a run of zero bytes where the
+/// next magic should be marks the end of the written data.
+fn scan_file(
+  blocks_dir: &Path,
+  file_idx: u32,
+  magic: [u8; 4],
+) -> Result<Vec<(BlockHash, ScannedHeader)>> {
+  let path = blocks_dir.join(format!("blk{:05}.dat", file_idx));
+  let len = fs::metadata(&path)
+    .with_context(|| format!("reading {}", path.display()))?
+    .len();
+  let mut reader =
+    BufReader::new(fs::File::open(&path).with_context(|| format!("opening {}", path.display()))?);
+
+  let mut headers = Vec::new();
+
+  let mut position = 0;
+  while position + 8 + 80 <= len {
+    let mut record_magic = [0u8; 4];
+    reader.read_exact(&mut record_magic)?;
+
+    if record_magic != magic {
+      if record_magic != [0; 4] {
+        log::warn!(
+          "BlkReader: unexpected magic {} at {}:{position}, skipping rest of file",
+          hex::encode(record_magic),
+          path.display(),
+        );
+      }
+      break;
+    }
+
+    let block_size = u64::from(reader.read_u32::<LittleEndian>()?);
+    let data_offset = position + 8;
+
+    let mut header = [0u8; 80];
+    reader.read_exact(&mut header)?;
+    let header = deserialize::<Header>(&header)
+      .with_context(|| format!("decoding header at {}:{data_offset}", path.display()))?;
+
+    headers.push((
+      header.block_hash(),
+      ScannedHeader {
+        prev_blockhash: header.prev_blockhash,
+        bits: header.bits,
+        file_idx,
+        data_offset,
+      },
+    ));
+
+    anyhow::ensure!(
+      block_size >= 80,
+      "block at {}:{data_offset} is only {block_size} bytes",
+      path.display()
+    );
+
+    position = data_offset + block_size;
+    reader.seek_relative(i64::try_from(block_size - 80)?)?;
+  }
+
+  Ok(headers)
+}
+
 // ---------------------------------------------------------------------------
 // .blk file binary reading
 // ---------------------------------------------------------------------------
@@ -319,15 +682,16 @@ fn read_varint(cur: &mut Cursor<&[u8]>) -> std::io::Result<u64> {
 ///
 /// The LevelDB `data_offset` points to the start of the raw block bytes
 /// (i.e., 8 bytes into the record). So `data_offset - 4` is where
-/// `block_size` lives. Because we locate blocks via the LevelDB index
-/// rather than linear `.blk` scanning, we never need to read or match the
-/// magic bytes at runtime — the constants serve as documentation and for
-/// future validation tooling.
+/// `block_size` lives. Offsets recorded by [`BlkReader::scan`] follow the
+/// same convention, so the magic only needs matching while scanning.
+///
+/// Blocks are parsed with [`DogecoinBlock::parse`] so merge-mined blocks
+/// carrying AuxPoW data read the same way as plain ones.
 pub(crate) fn read_block_from_file(
   blk_dir: &Path,
   file_idx: u32,
   data_offset: u64,
-) -> Result<Block> {
+) -> Result<DogecoinBlock> {
   let path = blk_dir.join(format!("blk{:05}.dat", file_idx));
   let file = fs::File::open(&path).with_context(|| format!("opening {}", path.display()))?;
   let mut reader = BufReader::new(file);
@@ -345,5 +709,145 @@ pub(crate) fn read_block_from_file(
     .read_exact(&mut block_bytes)
     .context("reading block bytes")?;
 
-  deserialize(&block_bytes).context("deserializing block")
+  DogecoinBlock::parse(&block_bytes).context("deserializing block")
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    bitcoin::{Block, consensus::serialize},
+  };
+
+  fn child(parent: &Block, nonce: u32) -> Block {
+    let mut block = parent.clone();
+    block.header.prev_blockhash = parent.block_hash();
+    block.header.nonce = nonce;
+    block
+  }
+
+  fn write_blk(dir: &Path, file_idx: u32, blocks: &[&Block]) {
+    let mut bytes = Vec::new();
+    for block in blocks {
+      let block = serialize(*block);
+      bytes.extend_from_slice(&Chain::DogecoinRegtest.magic());
+      bytes.extend_from_slice(&u32::try_from(block.len()).unwrap().to_le_bytes());
+      bytes.extend_from_slice(&block);
+    }
+    // Core preallocates block files, leaving zeros after the last record.
+    bytes.extend_from_slice(&[0; 1024]);
+    fs::write(dir.join(format!("blk{file_idx:05}.dat")), bytes).unwrap();
+  }
+
+  #[test]
+  fn scan_follows_best_chain() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let genesis = Chain::DogecoinRegtest.genesis_block();
+    let a1 = child(&genesis, 1);
+    let a2 = child(&a1, 2);
+    let b1 = child(&genesis, 3);
+
+    write_blk(tempdir.path(), 0, &[&genesis, &a2, &b1]);
+    write_blk(tempdir.path(), 1, &[&a1]);
+
+    let reader = BlkReader::open(
+      tempdir.path(),
+      &tempdir.path().join("blk-index"),
+      Chain::DogecoinRegtest,
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(reader.max_height(), 2);
+
+    for (height, block) in [&genesis, &a1, &a2].into_iter().enumerate() {
+      let height = u32::try_from(height).unwrap();
+      assert_eq!(
+        reader.location(height).unwrap().2,
+        block.block_hash().to_byte_array()
+      );
+      assert_eq!(
+        reader.get(height).unwrap().unwrap(),
+        DogecoinBlock::from(block.clone())
+      );
+    }
+
+    assert!(reader.get(3).unwrap().is_none());
+  }
+
+  #[test]
+  fn scan_is_cached() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let index_copy_dir = tempdir.path().join("blk-index");
+
+    let genesis = Chain::DogecoinRegtest.genesis_block();
+    let a1 = child(&genesis, 1);
+    let a2 = child(&a1, 2);
+
+    write_blk(tempdir.path(), 0, &[&genesis, &a1]);
+
+    let reader = BlkReader::open(tempdir.path(), &index_copy_dir, Chain::DogecoinRegtest)
+      .unwrap()
+      .unwrap();
+    assert_eq!(reader.max_height(), 1);
+    assert!(index_copy_dir.join(SCAN_CACHE_FILE).is_file());
+
+    // Clobber the file but keep its length and mtime, so only the cache
+    // still knows where the blocks were.
+    let path = tempdir.path().join("blk00000.dat");
+    let modified = fs::metadata(&path).unwrap().modified().unwrap();
+    let len = fs::metadata(&path).unwrap().len();
+    fs::write(&path, vec![0xff; usize::try_from(len).unwrap()]).unwrap();
+    fs::File::options()
+      .write(true)
+      .open(&path)
+      .unwrap()
+      .set_modified(modified)
+      .unwrap();
+
+    let reader = BlkReader::open(tempdir.path(), &index_copy_dir, Chain::DogecoinRegtest)
+      .unwrap()
+      .unwrap();
+    assert_eq!(reader.max_height(), 1);
+    assert_eq!(
+      reader.location(1).unwrap().2,
+      a1.block_hash().to_byte_array()
+    );
+
+    write_blk(tempdir.path(), 1, &[&a2]);
+
+    let reader = BlkReader::open(tempdir.path(), &index_copy_dir, Chain::DogecoinRegtest)
+      .unwrap()
+      .unwrap();
+    assert_eq!(reader.max_height(), 2);
+    assert_eq!(
+      reader.location(2).unwrap(),
+      (1, 8, a2.block_hash().to_byte_array())
+    );
+  }
+
+  #[test]
+  fn scan_requires_genesis() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let genesis = Chain::DogecoinRegtest.genesis_block();
+    write_blk(tempdir.path(), 0, &[&child(&genesis, 1)]);
+
+    assert_eq!(
+      BlkReader::scan(
+        tempdir.path(),
+        &tempdir.path().join("blk-index"),
+        Chain::DogecoinRegtest
+      )
+      .err()
+      .unwrap()
+      .to_string(),
+      format!(
+        "genesis block {} not found in {}",
+        genesis.block_hash(),
+        tempdir.path().display()
+      ),
+    );
+  }
 }
@@ -1,15 +1,21 @@
 use super::*;
 
-pub(super) struct DuneUpdater<'a, 'tx, 'client> {
+pub(super) struct DuneUpdater<'a, 'tx> {
+  pub(super) activity: &'a mut Table<'tx, (DuneIdValue, u64), &'static [u8]>,
+  pub(super) block_mints: &'a mut Table<'tx, (DuneIdValue, u32), u64>,
   pub(super) block_time: u32,
   pub(super) burned: HashMap<DuneId, Lot>,
-  pub(super) client: &'client Client,
+  pub(super) chain: Chain,
   pub(super) event_sender: Option<&'a mpsc::Sender<Event>>,
   pub(super) height: u32,
+  pub(super) holder_balances: &'a mut Table<'tx, (DuneIdValue, &'static str), u128>,
   pub(super) id_to_entry: &'a mut Table<'tx, DuneIdValue, DuneEntryValue>,
   pub(super) inscription_id_to_sequence_number: &'a Table<'tx, InscriptionIdValue, u32>,
+  pub(super) index_addresses: bool,
   pub(super) minimum: Dune,
   pub(super) outpoint_to_balances: &'a mut Table<'tx, &'static OutPointValue, &'static [u8]>,
+  pub(super) outpoint_to_commit_height: &'a mut Table<'tx, &'static OutPointValue, (u32, bool)>,
+  pub(super) outpoint_to_holder: &'a mut Table<'tx, &'static OutPointValue, &'static str>,
   pub(super) dune_to_id: &'a mut Table<'tx, u128, DuneIdValue>,
   pub(super) dunes: u64,
   pub(super) sequence_number_to_dune_id: &'a mut Table<'tx, u32, DuneIdValue>,
@@ -17,12 +23,16 @@ pub(super) struct DuneUpdater<'a, 'tx, 'client> {
   pub(super) transaction_id_to_dune: &'a mut Table<'tx, &'static TxidValue, u128>,
 }
 
-impl DuneUpdater<'_, '_, '_> {
+impl DuneUpdater<'_, '_> {
   pub(super) fn index_dunes(&mut self, tx_index: u32, tx: &Transaction, txid: Txid) -> Result<()> {
     let artifact = Dunestone::decipher(tx);
 
     let mut unallocated = self.unallocated(tx)?;
 
+    // balances spent from inputs, as opposed to minted or premined, which are
+    // the only ones logged as transfers
+    let mut transferable = unallocated.clone();
+
     let mut allocated: Vec<HashMap<DuneId, Lot>> = vec![HashMap::new(); tx.output.len()];
 
     if let Some(artifact) = &artifact {
@@ -31,6 +41,8 @@ impl DuneUpdater<'_, '_, '_> {
       {
         *unallocated.entry(id).or_default() += amount;
 
+        self.record_activity(id, api::DuneActivityKind::Mint, amount.n(), None, txid)?;
+
         if let Some(sender) = self.event_sender {
           sender.blocking_send(Event::DuneMinted {
             block_height: self.height,
@@ -195,9 +207,43 @@ impl DuneUpdater<'_, '_, '_> {
         vout: vout.try_into().unwrap(),
       };
 
+      let address = self
+        .chain
+        .address_string_from_script(&tx.output[vout].script_pubkey);
+
+      if self.index_addresses
+        && let Some(address) = &address
+      {
+        self
+          .outpoint_to_holder
+          .insert(&outpoint.store(), address.as_str())?;
+      }
+
       for (id, balance) in balances {
         Index::encode_dune_balance(id, balance.n(), &mut buffer);
 
+        if let Some(remaining) = transferable.get_mut(&id) {
+          let transferred = balance.min(*remaining);
+
+          if transferred > 0 {
+            *remaining -= transferred;
+
+            self.record_activity(
+              id,
+              api::DuneActivityKind::Transfer,
+              transferred.n(),
+              address.clone(),
+              txid,
+            )?;
+          }
+        }
+
+        if self.index_addresses
+          && let Some(address) = &address
+        {
+          self.credit_holder(id, address, balance.n())?;
+        }
+
         if let Some(sender) = self.event_sender {
           sender.blocking_send(Event::DuneTransferred {
             outpoint,
@@ -218,6 +264,8 @@ impl DuneUpdater<'_, '_, '_> {
     for (id, amount) in burned {
       *self.burned.entry(id).or_default() += amount;
 
+      self.record_activity(id, api::DuneActivityKind::Burn, amount.n(), None, txid)?;
+
       if let Some(sender) = self.event_sender {
         sender.blocking_send(Event::DuneBurned {
           block_height: self.height,
@@ -228,6 +276,8 @@ impl DuneUpdater<'_, '_, '_> {
       }
     }
 
+    update_commit_outputs(self.outpoint_to_commit_height, self.height, tx, txid)?;
+
     Ok(())
   }
 
@@ -308,6 +358,8 @@ impl DuneUpdater<'_, '_, '_> {
 
     self.id_to_entry.insert(id.store(), entry.store())?;
 
+    self.record_activity(id, api::DuneActivityKind::Etch, entry.premine, None, txid)?;
+
     if let Some(sender) = self.event_sender {
       sender.blocking_send(Event::DuneEtched {
         block_height: self.height,
@@ -396,6 +448,16 @@ impl DuneUpdater<'_, '_, '_> {
 
     self.id_to_entry.insert(&id.store(), dune_entry.store())?;
 
+    let block_mints = self
+      .block_mints
+      .get(&(id.store(), self.height))?
+      .map(|mints| mints.value())
+      .unwrap_or_default();
+
+    self
+      .block_mints
+      .insert(&(id.store(), self.height), block_mints + 1)?;
+
     Ok(Some(Lot(amount)))
   }
 
@@ -403,63 +465,40 @@ impl DuneUpdater<'_, '_, '_> {
     let commitment = dune.commitment();
 
     for input in &tx.input {
-      // extracting a tapscript does not indicate that the input being spent
-      // was actually a taproot output. this is checked below, when we load the
-      // output's entry from the database
-      let Some(tapscript) = unversioned_leaf_script_from_witness(&input.witness) else {
+      // a commitment may be made in a taproot tapscript or, since dogecoin
+      // has no taproot, in a P2SH redeem script. extracting either script
+      // does not indicate that the input being spent was actually of that
+      // type. this is checked below, when we load the output's entry from the
+      // database
+      let taproot = unversioned_leaf_script_from_witness(&input.witness)
+        .is_some_and(|tapscript| script_contains_push(tapscript, &commitment));
+
+      let p2sh = redeem_script_from_script_sig(&input.script_sig)
+        .is_some_and(|redeem_script| script_contains_push(redeem_script, &commitment));
+
+      if !taproot && !p2sh {
         continue;
-      };
+      }
 
-      for instruction in tapscript.instructions() {
-        // ignore errors, since the extracted script may not be valid
-        let Ok(instruction) = instruction else {
-          break;
-        };
+      // commit outputs are recorded from `Settings::first_dune_commit_height`,
+      // so an output missing from the table can't hold a commitment
+      let Some(commit_output) = self
+        .outpoint_to_commit_height
+        .get(&input.previous_output.store())?
+      else {
+        continue;
+      };
 
-        let Some(pushbytes) = instruction.push_bytes() else {
-          continue;
-        };
+      let (commit_tx_height, p2tr) = commit_output.value();
 
-        if pushbytes.as_bytes() != commitment {
-          continue;
-        }
+      if !(taproot && p2tr || p2sh && !p2tr) {
+        continue;
+      }
 
-        let Some(tx_info) = self
-          .client
-          .get_raw_transaction_info(&input.previous_output.txid, None)
-          .into_option()?
-        else {
-          panic!(
-            "can't get input transaction: {}",
-            input.previous_output.txid
-          );
-        };
-
-        let taproot = tx_info.vout[input.previous_output.vout.into_usize()]
-          .script_pub_key
-          .script()?
-          .is_p2tr();
-
-        if !taproot {
-          continue;
-        }
+      let confirmations = self.height.saturating_sub(commit_tx_height) + 1;
 
-        let commit_tx_height = self
-          .client
-          .get_block_header_info(&tx_info.blockhash.unwrap())
-          .into_option()?
-          .unwrap()
-          .height;
-
-        let confirmations = self
-          .height
-          .checked_sub(commit_tx_height.try_into().unwrap())
-          .unwrap()
-          + 1;
-
-        if confirmations >= u32::from(Dunestone::COMMIT_CONFIRMATIONS) {
-          return Ok(true);
-        }
+      if confirmations >= u32::from(Dunestone::COMMIT_CONFIRMATIONS) {
+        return Ok(true);
       }
     }
 
@@ -472,6 +511,8 @@ impl DuneUpdater<'_, '_, '_> {
 
     // increment unallocated dunes with the dunes in tx inputs
     for input in &tx.input {
+      let mut balances = Vec::new();
+
       if let Some(guard) = self
         .outpoint_to_balances
         .remove(&input.previous_output.store())?
@@ -482,10 +523,138 @@ impl DuneUpdater<'_, '_, '_> {
           let ((id, balance), len) = Index::decode_dune_balance(&buffer[i..]).unwrap();
           i += len;
           *unallocated.entry(id).or_default() += balance;
+          balances.push((id, balance));
+        }
+      }
+
+      if !self.index_addresses || balances.is_empty() {
+        continue;
+      }
+
+      let address = self
+        .outpoint_to_holder
+        .remove(&input.previous_output.store())?
+        .map(|address| address.value().to_string());
+
+      if let Some(address) = address {
+        for (id, balance) in balances {
+          self.debit_holder(id, &address, balance)?;
         }
       }
     }
 
     Ok(unallocated)
   }
+
+  fn credit_holder(&mut self, id: DuneId, address: &str, amount: u128) -> Result {
+    let balance = self
+      .holder_balances
+      .get(&(id.store(), address))?
+      .map(|balance| balance.value())
+      .unwrap_or_default();
+
+    self
+      .holder_balances
+      .insert(&(id.store(), address), balance.checked_add(amount).unwrap())?;
+
+    Ok(())
+  }
+
+  fn debit_holder(&mut self, id: DuneId, address: &str, amount: u128) -> Result {
+    let held = self
+      .holder_balances
+      .get(&(id.store(), address))?
+      .map(|balance| balance.value())
+      .unwrap_or_default();
+
+    let balance = held.checked_sub(amount).ok_or_else(|| {
+      anyhow!("holder {address} of dune {id} spent {amount} but only holds {held}")
+    })?;
+
+    if balance == 0 {
+      self.holder_balances.remove(&(id.store(), address))?;
+    } else {
+      self
+        .holder_balances
+        .insert(&(id.store(), address), balance)?;
+    }
+
+    Ok(())
+  }
+
+  /// Append to the dune's activity log, keyed by a global counter so entries
+  /// for a dune are in indexing order.
+  fn record_activity(
+    &mut self,
+    id: DuneId,
+    kind: api::DuneActivityKind,
+    amount: u128,
+    address: Option<String>,
+    txid: Txid,
+  ) -> Result {
+    let number = self
+      .statistic_to_count
+      .get(&Statistic::DuneActivity.into())?
+      .map(|count| count.value())
+      .unwrap_or_default();
+
+    self.activity.insert(
+      &(id.store(), number),
+      serde_json::to_vec(&api::DuneActivity {
+        kind,
+        amount,
+        address,
+        txid,
+        height: self.height,
+      })?
+      .as_slice(),
+    )?;
+
+    self
+      .statistic_to_count
+      .insert(&Statistic::DuneActivity.into(), number + 1)?;
+
+    Ok(())
+  }
+}
+
+/// Whether `script` contains a data push of exactly `bytes`. Errors end the
+/// scan, since the extracted script may not be valid.
+fn script_contains_push(script: &Script, bytes: &[u8]) -> bool {
+  script
+    .instructions()
+    .map_while(Result::ok)
+    .any(|instruction| {
+      instruction
+        .push_bytes()
+        .is_some_and(|push| push.as_bytes() == bytes)
+    })
+}
+
+/// Forget outputs spent by `tx` and remember its P2SH and P2TR outputs, any
+/// of which may later be spent by an etching that commits to its dune.
+pub(super) fn update_commit_outputs(
+  outpoint_to_commit_height: &mut Table<&'static OutPointValue, (u32, bool)>,
+  height: u32,
+  tx: &Transaction,
+  txid: Txid,
+) -> Result {
+  for input in &tx.input {
+    outpoint_to_commit_height.remove(&input.previous_output.store())?;
+  }
+
+  for (vout, output) in tx.output.iter().enumerate() {
+    let p2tr = output.script_pubkey.is_p2tr();
+
+    if p2tr || output.script_pubkey.is_p2sh() {
+      let outpoint = OutPoint {
+        txid,
+        vout: vout.try_into().unwrap(),
+      };
+
+      outpoint_to_commit_height.insert(&outpoint.store(), (height, p2tr))?;
+    }
+  }
+
+  Ok(())
 }
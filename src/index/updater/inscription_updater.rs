@@ -23,12 +23,17 @@ pub(super) struct Flotsam {
 #[derive(Debug, Clone)]
 enum Origin {
   New {
+    content_hash: Option<[u8; 32]>,
+    content_type: Option<String>,
     cursed: bool,
     fee: u64,
     gallery: bool,
+    gallery_traits: Vec<(InscriptionId, Vec<(String, String)>)>,
     hidden: bool,
     parents: Vec<InscriptionId>,
     reinscription: bool,
+    text_terms: Option<BTreeMap<String, u32>>,
+    traits: Vec<(String, String)>,
     unbound: bool,
     vindicated: bool,
   },
@@ -39,8 +44,12 @@ enum Origin {
 }
 
 pub(super) struct InscriptionUpdater<'a, 'tx> {
+  pub(super) address_to_sequence_numbers: &'a mut MultimapTable<'tx, &'static str, u32>,
   pub(super) blessed_inscription_count: u64,
   pub(super) collection_to_latest_child: &'a mut Table<'tx, u32, u32>,
+  pub(super) collection_trait_to_sequence_numbers:
+    &'a mut MultimapTable<'tx, (u32, &'static str, &'static str), u32>,
+  pub(super) content_hash_to_sequence_number: &'a mut MultimapTable<'tx, &'static [u8; 32], u32>,
   pub(super) cursed_inscription_count: u64,
   pub(super) flotsam: Vec<Flotsam>,
   pub(super) gallery_sequence_numbers: &'a mut Table<'tx, u32, ()>,
@@ -54,8 +63,12 @@ pub(super) struct InscriptionUpdater<'a, 'tx> {
   pub(super) next_sequence_number: u32,
   pub(super) reward: u64,
   pub(super) koinu_to_sequence_number: &'a mut MultimapTable<'tx, u64, u32>,
+  pub(super) sequence_number_to_address: &'a mut Table<'tx, u32, &'static str>,
   pub(super) sequence_number_to_children: &'a mut MultimapTable<'tx, u32, u32>,
+  pub(super) sequence_number_to_content_type: &'a mut Table<'tx, u32, &'static str>,
   pub(super) sequence_number_to_entry: &'a mut Table<'tx, u32, InscriptionEntryValue>,
+  pub(super) text_term_to_inscription_count: &'a mut Table<'tx, &'static str, u32>,
+  pub(super) text_term_to_sequence_number: &'a mut Table<'tx, (&'static str, u32), u32>,
   pub(super) timestamp: u32,
   pub(super) transaction_buffer: Vec<u8>,
   pub(super) transaction_id_to_transaction: &'a mut Table<'tx, &'static TxidValue, &'static [u8]>,
@@ -84,11 +97,7 @@ impl InscriptionUpdater<'_, '_> {
       .map(|txout| txout.value.to_sat())
       .sum::<u64>();
 
-    let envelopes = if index.settings.chain().is_dogecoin() {
-      ParsedEnvelope::from_transactions_dogecoin(std::slice::from_ref(tx))
-    } else {
-      ParsedEnvelope::from_transaction(tx)
-    };
+    let envelopes = index.parse_envelopes(tx);
     let has_new_inscriptions = !envelopes.is_empty();
     let mut envelopes = envelopes.into_iter().peekable();
 
@@ -200,16 +209,35 @@ impl InscriptionUpdater<'_, '_> {
           .filter(|&pointer| pointer < total_output_value)
           .unwrap_or(offset);
 
+        let properties = inscription.payload.properties();
+
         floating_inscriptions.push(Flotsam {
           inscription_id,
           offset,
           origin: Origin::New {
+            content_hash: index
+              .index_content_hashes
+              .then(|| inscription.payload.content_hash(MAX_CONTENT_HASH_BODY_SIZE))
+              .flatten()
+              .map(|hash| hash.to_byte_array()),
+            content_type: inscription.payload.content_type().map(str::to_string),
             cursed: curse.is_some() && !jubilant,
             fee: 0,
-            gallery: !inscription.payload.properties().gallery.is_empty(),
+            gallery: !properties.gallery.is_empty(),
+            gallery_traits: properties
+              .gallery
+              .iter()
+              .map(|item| (item.id(), trait_values(&item.attributes)))
+              .filter(|(_, traits)| !traits.is_empty())
+              .collect(),
             hidden: inscription.payload.hidden(),
             parents: inscription.payload.parents(),
             reinscription: inscribed_offsets.contains_key(&offset),
+            text_terms: index
+              .index_text
+              .then(|| text::inscription_terms(&inscription.payload))
+              .flatten(),
+            traits: trait_values(&properties.attributes),
             unbound: input_value == 0
               || curse == Some(Curse::UnrecognizedEvenField)
               || inscription.payload.unrecognized_even_field,
@@ -303,14 +331,14 @@ impl InscriptionUpdater<'_, '_> {
         new_locations.push((
           new_satpoint,
           inscriptions.next().unwrap(),
-          txout.script_pubkey.is_op_return(),
+          txout.script_pubkey.as_script(),
         ));
       }
 
       output_value = end;
     }
 
-    for (new_satpoint, flotsam, op_return) in new_locations.into_iter() {
+    for (new_satpoint, flotsam, script_pubkey) in new_locations.into_iter() {
       let output_utxo_entry =
         &mut output_utxo_entries[usize::try_from(new_satpoint.outpoint.vout).unwrap()];
 
@@ -318,7 +346,7 @@ impl InscriptionUpdater<'_, '_> {
         input_sat_ranges,
         flotsam,
         new_satpoint,
-        op_return,
+        Some(script_pubkey),
         Some(output_utxo_entry),
         utxo_cache,
         index,
@@ -335,7 +363,7 @@ impl InscriptionUpdater<'_, '_> {
           input_sat_ranges,
           flotsam,
           new_satpoint,
-          false,
+          None,
           None,
           utxo_cache,
           index,
@@ -378,12 +406,13 @@ impl InscriptionUpdater<'_, '_> {
     input_sat_ranges: Option<&Vec<&[u8]>>,
     flotsam: Flotsam,
     new_satpoint: KoinuPoint,
-    op_return: bool,
+    script_pubkey: Option<&Script>,
     mut normal_output_utxo_entry: Option<&mut UtxoEntryBuf>,
     utxo_cache: &mut HashMap<OutPoint, UtxoEntryBuf>,
     index: &Index,
   ) -> Result {
     let inscription_id = flotsam.inscription_id;
+    let op_return = script_pubkey.is_some_and(Script::is_op_return);
     let (unbound, sequence_number) = match flotsam.origin {
       Origin::Old {
         sequence_number,
@@ -420,12 +449,17 @@ impl InscriptionUpdater<'_, '_> {
         (false, sequence_number)
       }
       Origin::New {
+        content_hash,
+        content_type,
         cursed,
         fee,
         gallery,
+        gallery_traits,
         hidden,
         parents,
         reinscription,
+        text_terms,
+        traits,
         unbound,
         vindicated,
       } => {
@@ -463,7 +497,7 @@ impl InscriptionUpdater<'_, '_> {
         }
 
         if let Some(sat) = sat {
-          charms |= sat.charms();
+          charms |= sat.charms(index.settings.chain().charm_heights());
         }
 
         if op_return {
@@ -521,10 +555,70 @@ impl InscriptionUpdater<'_, '_> {
           })
           .collect::<Result<Vec<u32>>>()?;
 
+        // content types are only needed for collection statistics, so they
+        // are only recorded for children
+        if !parent_sequence_numbers.is_empty()
+          && let Some(content_type) = &content_type
+        {
+          self
+            .sequence_number_to_content_type
+            .insert(sequence_number, content_type.as_str())?;
+        }
+
+        for parent_sequence_number in &parent_sequence_numbers {
+          for (name, value) in &traits {
+            self.collection_trait_to_sequence_numbers.insert(
+              (*parent_sequence_number, name.as_str(), value.as_str()),
+              sequence_number,
+            )?;
+          }
+        }
+
         if gallery {
           self.gallery_sequence_numbers.insert(sequence_number, ())?;
         }
 
+        // gallery items are indexed under the gallery, as if they were its
+        // children, but only if they were inscribed before it
+        for (item, traits) in &gallery_traits {
+          let Some(item_sequence_number) = self
+            .id_to_sequence_number
+            .get(&item.store())?
+            .map(|sequence_number| sequence_number.value())
+          else {
+            continue;
+          };
+
+          for (name, value) in traits {
+            self.collection_trait_to_sequence_numbers.insert(
+              (sequence_number, name.as_str(), value.as_str()),
+              item_sequence_number,
+            )?;
+          }
+        }
+
+        if let Some(content_hash) = content_hash {
+          self
+            .content_hash_to_sequence_number
+            .insert(&content_hash, sequence_number)?;
+        }
+
+        for (term, count) in text_terms.iter().flatten() {
+          self
+            .text_term_to_sequence_number
+            .insert((term.as_str(), sequence_number), count)?;
+
+          let inscription_count = self
+            .text_term_to_inscription_count
+            .get(term.as_str())?
+            .map(|inscription_count| inscription_count.value())
+            .unwrap_or_default();
+
+          self
+            .text_term_to_inscription_count
+            .insert(term.as_str(), inscription_count + 1)?;
+        }
+
         if let Some(ref sender) = index.event_sender {
           sender.blocking_send(Event::InscriptionCreated {
             block_height: self.height,
@@ -597,6 +691,56 @@ impl InscriptionUpdater<'_, '_> {
 
     output_utxo_entry.push_inscription(sequence_number, satpoint.offset, index);
 
+    let address = if unbound {
+      None
+    } else {
+      script_pubkey.and_then(|script| index.settings.chain().address_string_from_script(script))
+    };
+
+    self.update_inscription_address(sequence_number, address)
+  }
+
+  fn update_inscription_address(
+    &mut self,
+    sequence_number: u32,
+    address: Option<String>,
+  ) -> Result {
+    let old_address = self
+      .sequence_number_to_address
+      .get(sequence_number)?
+      .map(|address| address.value().to_string());
+
+    if old_address == address {
+      return Ok(());
+    }
+
+    if let Some(old_address) = old_address {
+      self
+        .address_to_sequence_numbers
+        .remove(old_address.as_str(), sequence_number)?;
+    }
+
+    if let Some(address) = address {
+      self
+        .address_to_sequence_numbers
+        .insert(address.as_str(), sequence_number)?;
+
+      self
+        .sequence_number_to_address
+        .insert(sequence_number, address.as_str())?;
+    } else {
+      self.sequence_number_to_address.remove(sequence_number)?;
+    }
+
     Ok(())
   }
 }
+
+fn trait_values(attributes: &Attributes) -> Vec<(String, String)> {
+  attributes
+    .traits
+    .items
+    .iter()
+    .map(|(name, value)| (name.clone(), value.to_string()))
+    .collect()
+}
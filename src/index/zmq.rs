@@ -0,0 +1,605 @@
+//! Minimal ZeroMQ subscriber for Dogecoin Core's `-zmqpub*` notifications.
+//!
+//! Speaks just enough ZMTP 3.0 (NULL security mechanism, SUB socket) to
+//! subscribe to `hashblock` on a single `tcp://` endpoint, so no native libzmq
+//! is required. Each notification Core publishes is a three-part message:
+//!
+//! ```text
+//!   [topic]     "hashblock"
+//!   [body]      32-byte block hash (display order)
+//!   [sequence]  u32 little-endian, incremented per topic
+//! ```
+//!
+//! `rawtx` is not subscribed to, since nothing consumes mempool transactions
+//! and they would crowd block notifications out of the event buffers.
+//!
+//! Nothing is connected until the first call to [`ZmqSubscriber::subscribe`].
+//! Events are then fanned out to every receiver it has returned, each through
+//! a bounded buffer: a receiver that falls behind has events dropped rather
+//! than queued without limit. When the connection drops or goes quiet for
+//! longer than [`READ_TIMEOUT`], a sequence number is skipped, a notification
+//! is malformed, or events were dropped for a slow receiver, a
+//! [`ZmqEvent::Gap`] is sent so consumers know they may have missed something
+//! and should fall back to RPC polling.
+
+use {
+  super::*,
+  std::{
+    io::{Read, Write},
+    net::TcpStream,
+    sync::{
+      Once,
+      mpsc::{self, TrySendError},
+    },
+  },
+};
+
+const TOPIC_HASHBLOCK: &[u8] = b"hashblock";
+
+const FLAG_MORE: u8 = 0x01;
+const FLAG_LONG: u8 = 0x02;
+const FLAG_COMMAND: u8 = 0x04;
+
+const MAX_FRAME_SIZE: u64 = 32 * 1024 * 1024;
+
+const EVENT_BUFFER: usize = 1024;
+
+const RECONNECT_DELAY_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(30);
+
+/// A connection that delivers nothing for this long is assumed dead, since a
+/// peer that vanished without closing it would otherwise block reads forever.
+const READ_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ZmqEvent {
+  Block(BlockHash),
+  Gap,
+}
+
+struct Subscription {
+  sender: mpsc::SyncSender<ZmqEvent>,
+  lagged: bool,
+}
+
+impl Subscription {
+  fn new(capacity: usize) -> (Self, mpsc::Receiver<ZmqEvent>) {
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+    (
+      Self {
+        sender,
+        lagged: false,
+      },
+      receiver,
+    )
+  }
+
+  /// Returns false once the receiver has been dropped.
+  fn send(&mut self, event: &ZmqEvent) -> bool {
+    if self.lagged {
+      match self.sender.try_send(ZmqEvent::Gap) {
+        Ok(()) => self.lagged = false,
+        Err(TrySendError::Full(_)) => return true,
+        Err(TrySendError::Disconnected(_)) => return false,
+      }
+    }
+
+    match self.sender.try_send(event.clone()) {
+      Ok(()) => true,
+      Err(TrySendError::Full(_)) => {
+        self.lagged = true;
+        true
+      }
+      Err(TrySendError::Disconnected(_)) => false,
+    }
+  }
+}
+
+pub(crate) struct ZmqSubscriber {
+  endpoint: String,
+  read_timeout: Duration,
+  started: Once,
+  subscriptions: Arc<Mutex<Vec<Subscription>>>,
+}
+
+impl ZmqSubscriber {
+  /// Validate `address` (e.g. `tcp://127.0.0.1:28332`). The connection is
+  /// opened by the first call to [`Self::subscribe`], and re-established
+  /// with backoff whenever it is lost.
+  pub(crate) fn new(address: &str) -> Result<Self> {
+    let endpoint = address
+      .strip_prefix("tcp://")
+      .ok_or_else(|| anyhow!("unsupported ZMQ address `{address}`: only tcp:// is supported"))?
+      .to_string();
+
+    Ok(Self {
+      endpoint,
+      read_timeout: READ_TIMEOUT,
+      started: Once::new(),
+      subscriptions: Arc::new(Mutex::new(Vec::new())),
+    })
+  }
+
+  pub(crate) fn subscribe(&self) -> mpsc::Receiver<ZmqEvent> {
+    let (subscription, receiver) = Subscription::new(EVENT_BUFFER);
+    self.subscriptions.lock().unwrap().push(subscription);
+    self.started.call_once(|| self.spawn());
+    receiver
+  }
+
+  fn spawn(&self) {
+    let endpoint = self.endpoint.clone();
+    let read_timeout = self.read_timeout;
+    let subscriptions = self.subscriptions.clone();
+
+    thread::spawn(move || {
+      let mut delay = RECONNECT_DELAY_MIN;
+
+      while !SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+        match Self::connect(&endpoint, read_timeout) {
+          Ok(stream) => {
+            log::info!("Connected to ZMQ publisher at tcp://{endpoint}");
+            delay = RECONNECT_DELAY_MIN;
+
+            // Anything published while disconnected was lost.
+            Self::broadcast(&subscriptions, ZmqEvent::Gap);
+
+            if let Err(err) = Self::run(stream, &subscriptions) {
+              if err.downcast_ref::<io::Error>().is_some_and(|err| {
+                matches!(
+                  err.kind(),
+                  io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                )
+              }) {
+                log::info!(
+                  "no ZMQ notifications from tcp://{endpoint} for {}s, reconnecting",
+                  read_timeout.as_secs()
+                );
+              } else {
+                log::warn!("ZMQ connection to tcp://{endpoint} lost: {err}");
+              }
+            }
+
+            Self::broadcast(&subscriptions, ZmqEvent::Gap);
+          }
+          Err(err) => {
+            log::warn!(
+              "failed to connect to ZMQ publisher at tcp://{endpoint}, retrying in {}s: {err}",
+              delay.as_secs()
+            );
+            thread::sleep(delay);
+            delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+          }
+        }
+      }
+    });
+  }
+
+  fn broadcast(subscriptions: &Mutex<Vec<Subscription>>, event: ZmqEvent) {
+    subscriptions
+      .lock()
+      .unwrap()
+      .retain_mut(|subscription| subscription.send(&event));
+  }
+
+  fn connect(endpoint: &str, read_timeout: Duration) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(endpoint)?;
+    stream.set_nodelay(true)?;
+    stream.set_read_timeout(Some(read_timeout))?;
+
+    stream.write_all(&greeting())?;
+
+    let mut peer = [0; 64];
+    stream.read_exact(&mut peer)?;
+    ensure!(
+      peer[0] == 0xFF && peer[9] == 0x7F,
+      "peer did not send a ZMTP greeting"
+    );
+    ensure!(peer[10] >= 3, "unsupported ZMTP version {}", peer[10]);
+
+    let mut ready = Vec::new();
+    ready.push(5);
+    ready.extend_from_slice(b"READY");
+    ready.push(11);
+    ready.extend_from_slice(b"Socket-Type");
+    ready.extend_from_slice(&3u32.to_be_bytes());
+    ready.extend_from_slice(b"SUB");
+    write_frame(&mut stream, FLAG_COMMAND, &ready)?;
+
+    let (flags, body) = read_frame(&mut stream)?;
+    ensure!(
+      flags & FLAG_COMMAND != 0 && body.get(1..6) == Some(b"READY"),
+      "peer did not complete ZMTP handshake"
+    );
+
+    let mut subscribe = vec![1];
+    subscribe.extend_from_slice(TOPIC_HASHBLOCK);
+    write_frame(&mut stream, 0, &subscribe)?;
+
+    Ok(stream)
+  }
+
+  fn run(mut stream: TcpStream, subscriptions: &Mutex<Vec<Subscription>>) -> Result {
+    let mut sequences = HashMap::<Vec<u8>, u32>::new();
+
+    loop {
+      let parts = read_message(&mut stream)?;
+
+      let [topic, body, rest @ ..] = parts.as_slice() else {
+        continue;
+      };
+
+      if topic != TOPIC_HASHBLOCK {
+        continue;
+      }
+
+      if let Some(sequence) = rest
+        .first()
+        .and_then(|sequence| <[u8; 4]>::try_from(sequence.as_slice()).ok())
+        .map(u32::from_le_bytes)
+        && let Some(last) = sequences.insert(topic.clone(), sequence)
+        && sequence != last.wrapping_add(1)
+      {
+        log::warn!(
+          "ZMQ {} sequence gap: expected {}, got {sequence}",
+          String::from_utf8_lossy(topic),
+          last.wrapping_add(1),
+        );
+        Self::broadcast(subscriptions, ZmqEvent::Gap);
+      }
+
+      let Ok(mut hash) = <[u8; 32]>::try_from(body.as_slice()) else {
+        log::warn!(
+          "skipping ZMQ hashblock notification with {}-byte body",
+          body.len()
+        );
+        Self::broadcast(subscriptions, ZmqEvent::Gap);
+        continue;
+      };
+
+      hash.reverse();
+
+      Self::broadcast(
+        subscriptions,
+        ZmqEvent::Block(BlockHash::from_byte_array(hash)),
+      );
+    }
+  }
+}
+
+fn greeting() -> [u8; 64] {
+  let mut greeting = [0; 64];
+  greeting[0] = 0xFF;
+  greeting[9] = 0x7F;
+  greeting[10] = 3;
+  greeting[11] = 0;
+  greeting[12..16].copy_from_slice(b"NULL");
+  greeting
+}
+
+fn write_frame(writer: &mut impl Write, flags: u8, body: &[u8]) -> io::Result<()> {
+  if let Ok(len) = u8::try_from(body.len()) {
+    writer.write_all(&[flags, len])?;
+  } else {
+    writer.write_all(&[flags | FLAG_LONG])?;
+    writer.write_all(&(body.len() as u64).to_be_bytes())?;
+  }
+  writer.write_all(body)
+}
+
+fn read_frame(reader: &mut impl Read) -> Result<(u8, Vec<u8>)> {
+  let mut flags = [0];
+  reader.read_exact(&mut flags)?;
+  let flags = flags[0];
+
+  let len = if flags & FLAG_LONG != 0 {
+    let mut len = [0; 8];
+    reader.read_exact(&mut len)?;
+    u64::from_be_bytes(len)
+  } else {
+    let mut len = [0];
+    reader.read_exact(&mut len)?;
+    len[0].into()
+  };
+
+  ensure!(len <= MAX_FRAME_SIZE, "ZMTP frame too large: {len} bytes");
+
+  let mut body = vec![0; usize::try_from(len)?];
+  reader.read_exact(&mut body)?;
+
+  Ok((flags, body))
+}
+
+/// Read one multipart message, skipping any interleaved command frames
+/// (e.g. heartbeats).
+fn read_message(reader: &mut impl Read) -> Result<Vec<Vec<u8>>> {
+  let mut parts = Vec::new();
+
+  loop {
+    let (flags, body) = read_frame(reader)?;
+
+    if flags & FLAG_COMMAND != 0 {
+      continue;
+    }
+
+    parts.push(body);
+
+    if flags & FLAG_MORE == 0 {
+      return Ok(parts);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, std::net::TcpListener};
+
+  /// A single-connection ZMQ PUB endpoint, speaking the same subset of ZMTP
+  /// as the subscriber.
+  struct Publisher {
+    listener: TcpListener,
+  }
+
+  impl Publisher {
+    fn new() -> Self {
+      Self {
+        listener: TcpListener::bind("127.0.0.1:0").unwrap(),
+      }
+    }
+
+    fn address(&self) -> String {
+      format!("tcp://{}", self.listener.local_addr().unwrap())
+    }
+
+    fn accept(&self) -> TcpStream {
+      let (mut stream, _) = self.listener.accept().unwrap();
+
+      let mut greeting = [0; 64];
+      stream.read_exact(&mut greeting).unwrap();
+      assert_eq!(&greeting[12..16], b"NULL");
+      stream.write_all(&super::greeting()).unwrap();
+
+      let (flags, body) = read_frame(&mut stream).unwrap();
+      assert_eq!(flags, FLAG_COMMAND);
+      assert!(body.ends_with(b"Socket-Type\0\0\0\x03SUB"));
+
+      let mut ready = vec![5];
+      ready.extend_from_slice(b"READY");
+      write_frame(&mut stream, FLAG_COMMAND, &ready).unwrap();
+
+      let (_, body) = read_frame(&mut stream).unwrap();
+      assert_eq!(body[0], 1);
+      assert_eq!(&body[1..], TOPIC_HASHBLOCK);
+
+      stream
+    }
+
+    fn publish(stream: &mut TcpStream, topic: &[u8], body: &[u8], sequence: u32) {
+      write_frame(stream, FLAG_MORE, topic).unwrap();
+      write_frame(stream, FLAG_MORE, body).unwrap();
+      write_frame(stream, 0, &sequence.to_le_bytes()).unwrap();
+    }
+  }
+
+  fn recv(events: &mpsc::Receiver<ZmqEvent>) -> ZmqEvent {
+    events.recv_timeout(Duration::from_secs(10)).unwrap()
+  }
+
+  #[test]
+  fn invalid_address() {
+    assert_eq!(
+      ZmqSubscriber::new("ipc:///tmp/dogecoind")
+        .err()
+        .unwrap()
+        .to_string(),
+      "unsupported ZMQ address `ipc:///tmp/dogecoind`: only tcp:// is supported",
+    );
+  }
+
+  #[test]
+  fn blocks() {
+    let publisher = Publisher::new();
+    let subscriber = ZmqSubscriber::new(&publisher.address()).unwrap();
+    let events = subscriber.subscribe();
+
+    let mut stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    let mut hash = [0; 32];
+    hash[0] = 1;
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &hash, 0);
+
+    hash.reverse();
+    assert_eq!(
+      recv(&events),
+      ZmqEvent::Block(BlockHash::from_byte_array(hash))
+    );
+  }
+
+  #[test]
+  fn malformed_notification_is_skipped() {
+    let publisher = Publisher::new();
+    let subscriber = ZmqSubscriber::new(&publisher.address()).unwrap();
+    let events = subscriber.subscribe();
+
+    let mut stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &[0; 31], 0);
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &[0; 32], 1);
+    assert_eq!(recv(&events), ZmqEvent::Block(BlockHash::all_zeros()));
+  }
+
+  #[test]
+  fn reconnect_after_read_timeout() {
+    let publisher = Publisher::new();
+    let mut subscriber = ZmqSubscriber::new(&publisher.address()).unwrap();
+    subscriber.read_timeout = Duration::from_millis(100);
+    let events = subscriber.subscribe();
+
+    let _stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    let mut stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &[0; 32], 0);
+    assert_eq!(recv(&events), ZmqEvent::Block(BlockHash::all_zeros()));
+  }
+
+  #[test]
+  fn sequence_gap() {
+    let publisher = Publisher::new();
+    let subscriber = ZmqSubscriber::new(&publisher.address()).unwrap();
+    let events = subscriber.subscribe();
+
+    let mut stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &[0; 32], 7);
+    assert_eq!(recv(&events), ZmqEvent::Block(BlockHash::all_zeros()));
+
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &[0; 32], 9);
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+    assert_eq!(recv(&events), ZmqEvent::Block(BlockHash::all_zeros()));
+  }
+
+  #[test]
+  fn connects_on_first_subscribe() {
+    let publisher = Publisher::new();
+    publisher.listener.set_nonblocking(true).unwrap();
+
+    let subscriber = ZmqSubscriber::new(&publisher.address()).unwrap();
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(
+      publisher.listener.accept().unwrap_err().kind(),
+      io::ErrorKind::WouldBlock,
+    );
+
+    publisher.listener.set_nonblocking(false).unwrap();
+    let events = subscriber.subscribe();
+    publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+  }
+
+  #[test]
+  fn slow_receiver_drops_events_and_gets_gap() {
+    let (mut subscription, events) = Subscription::new(2);
+
+    let block = |n| ZmqEvent::Block(BlockHash::from_byte_array([n; 32]));
+
+    assert!(subscription.send(&block(0)));
+    assert!(subscription.send(&block(1)));
+    assert!(subscription.send(&block(2)));
+    assert!(subscription.send(&block(3)));
+
+    assert_eq!(events.try_recv().unwrap(), block(0));
+    assert!(subscription.send(&block(4)));
+    assert_eq!(events.try_recv().unwrap(), block(1));
+    assert_eq!(events.try_recv().unwrap(), ZmqEvent::Gap);
+    assert!(events.try_recv().is_err());
+
+    assert!(subscription.send(&block(5)));
+    assert_eq!(events.try_recv().unwrap(), ZmqEvent::Gap);
+    assert_eq!(events.try_recv().unwrap(), block(5));
+
+    drop(events);
+    assert!(!subscription.send(&block(6)));
+  }
+
+  /// Bytes a libzmq 4.3 PUB socket (as used by Dogecoin Core's
+  /// `-zmqpubhashblock`/`-zmqpubrawtx`) writes to a subscriber: a ZMTP 3.1
+  /// greeting with the ZMTP 1.0 compatible signature padding, a READY
+  /// command, then `hashblock` and `rawtx` notifications for the mainnet
+  /// genesis block, and a `hashblock` whose sequence number skips one. The
+  /// `rawtx` notification must be ignored, since it was not subscribed to.
+  const LIBZMQ_PUB: &str = concat!(
+    // greeting: signature, version 3.1, NULL mechanism, as-server 0, filler
+    "ff00000000000000017f",
+    "0301",
+    "4e554c4c00000000000000000000000000000000",
+    "00",
+    "00000000000000000000000000000000000000000000000000000000000000",
+    // READY command with Socket-Type=PUB
+    "0419",
+    "055245414459",
+    "0b536f636b65742d54797065",
+    "00000003505542",
+    // hashblock, sequence 0
+    "010968617368626c6f636b",
+    "01201a91e3dace36e2be3bf030a65679fe821aa1d6ef92e7c9902eb318182c355691",
+    "000400000000",
+    // rawtx, sequence 0
+    "01057261777478",
+    "018f",
+    "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff1004ffff",
+    "001d0104084e696e746f6e646fffffffff010058850c020000004341040184710fa689ad5023690c80f3a49c",
+    "8f13f8d45b8c857fbcbc8bc4a8e4d3eb4b10f4d4604fa08dce601aaf0f470216fe1b51850b4acf21b179c4",
+    "5070ac7b03a9ac00000000",
+    "000400000000",
+    // hashblock, sequence 2
+    "010968617368626c6f636b",
+    "01201a91e3dace36e2be3bf030a65679fe821aa1d6ef92e7c9902eb318182c355691",
+    "000402000000",
+  );
+
+  #[test]
+  fn libzmq_publisher() {
+    let stream = hex::decode(LIBZMQ_PUB).unwrap();
+    let (greeting, rest) = stream.split_at(64);
+    let (ready, messages) = rest.split_at(27);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let subscriber =
+      ZmqSubscriber::new(&format!("tcp://{}", listener.local_addr().unwrap())).unwrap();
+    let events = subscriber.subscribe();
+
+    let (mut connection, _) = listener.accept().unwrap();
+
+    // libzmq sends the signature before reading the peer's.
+    connection.write_all(&greeting[..10]).unwrap();
+    let mut peer = [0; 64];
+    connection.read_exact(&mut peer).unwrap();
+    connection.write_all(&greeting[10..]).unwrap();
+
+    read_frame(&mut connection).unwrap();
+    connection.write_all(ready).unwrap();
+
+    assert_eq!(
+      read_frame(&mut connection).unwrap().1[1..],
+      *TOPIC_HASHBLOCK
+    );
+
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    connection.write_all(messages).unwrap();
+
+    let genesis = Chain::Dogecoin.genesis_block();
+
+    assert_eq!(recv(&events), ZmqEvent::Block(genesis.block_hash()));
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+    assert_eq!(recv(&events), ZmqEvent::Block(genesis.block_hash()));
+  }
+
+  #[test]
+  fn reconnect() {
+    let publisher = Publisher::new();
+    let subscriber = ZmqSubscriber::new(&publisher.address()).unwrap();
+    let events = subscriber.subscribe();
+
+    let stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    drop(stream);
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    let mut stream = publisher.accept();
+    assert_eq!(recv(&events), ZmqEvent::Gap);
+
+    Publisher::publish(&mut stream, TOPIC_HASHBLOCK, &[0; 32], 0);
+    assert_eq!(recv(&events), ZmqEvent::Block(BlockHash::all_zeros()));
+  }
+}
@@ -2,7 +2,7 @@ use {
   super::*,
   anyhow::ensure,
   axum::http::header::HeaderValue,
-  bitcoin::blockdata::opcodes,
+  bitcoin::{blockdata::opcodes, hashes::HashEngine},
   brotli::enc::{
     BrotliEncoderParams, backward_references::BrotliEncoderMode, writer::CompressorWriter,
   },
@@ -236,6 +236,72 @@ impl Inscription {
     HeaderValue::from_str(str::from_utf8(self.content_encoding.as_ref()?).unwrap_or_default()).ok()
   }
 
+  /// SHA-256 of the body after undoing `content_encoding`. Returns `None` if
+  /// there is no body, if it uses an encoding other than brotli or fails to
+  /// decode, or if the decoded body is larger than `max` bytes.
+  pub fn content_hash(&self, max: usize) -> Option<sha256::Hash> {
+    let body = self.body()?;
+
+    let mut engine = sha256::Hash::engine();
+
+    match self.content_encoding.as_deref() {
+      None => {
+        if body.len() > max {
+          return None;
+        }
+
+        engine.input(body);
+      }
+      Some(encoding) if encoding == BROTLI.as_bytes() => {
+        let mut decompressor = brotli::Decompressor::new(body, BROTLI_BUFFER_SIZE)
+          .take(max.into_u64().saturating_add(1));
+
+        let mut buffer = vec![0; BROTLI_BUFFER_SIZE];
+
+        let mut decoded = 0;
+
+        loop {
+          let n = decompressor.read(&mut buffer).ok()?;
+
+          if n == 0 {
+            break;
+          }
+
+          decoded += n;
+
+          if decoded > max {
+            return None;
+          }
+
+          engine.input(&buffer[..n]);
+        }
+      }
+      Some(_) => return None,
+    }
+
+    Some(sha256::Hash::from_engine(engine))
+  }
+
+  /// The body after undoing `content_encoding`, truncated to `max` bytes.
+  pub(crate) fn decoded_body(&self, max: usize) -> Option<Cow<[u8]>> {
+    let body = self.body()?;
+
+    match self.content_encoding.as_deref() {
+      None => Some(Cow::Borrowed(&body[..body.len().min(max)])),
+      Some(encoding) if encoding == BROTLI.as_bytes() => {
+        let mut decoded = Vec::new();
+
+        brotli::Decompressor::new(body, BROTLI_BUFFER_SIZE)
+          .take(max.into_u64())
+          .read_to_end(&mut decoded)
+          .ok()?;
+
+        Some(Cow::Owned(decoded))
+      }
+      Some(_) => None,
+    }
+  }
+
   pub fn delegate(&self) -> Option<InscriptionId> {
     InscriptionId::from_value(self.delegate.as_deref()?)
   }
@@ -277,6 +343,11 @@ impl Inscription {
     Some(u64::from_le_bytes(pointer))
   }
 
+  #[cfg(test)]
+  pub(crate) fn to_script_sig(&self) -> ScriptBuf {
+    self.append_reveal_script(script::Builder::new())
+  }
+
   #[cfg(test)]
   pub(crate) fn to_witness(&self) -> Witness {
     let builder = script::Builder::new();
@@ -1268,4 +1339,87 @@ mod tests {
       .is_none()
     );
   }
+
+  #[test]
+  fn content_hash_is_taken_over_decoded_body() {
+    let body = b"such content, very hash".repeat(100);
+
+    let mut compressed = Vec::new();
+
+    CompressorWriter::new(&mut compressed, BROTLI_BUFFER_SIZE, 11, 22)
+      .write_all(&body)
+      .unwrap();
+
+    let expected = sha256::Hash::hash(&body);
+
+    assert_eq!(
+      Inscription {
+        body: Some(body),
+        ..default()
+      }
+      .content_hash(usize::MAX),
+      Some(expected),
+    );
+
+    assert_eq!(
+      Inscription {
+        body: Some(compressed.clone()),
+        content_encoding: Some(BROTLI.into()),
+        ..default()
+      }
+      .content_hash(usize::MAX),
+      Some(expected),
+    );
+
+    assert_eq!(
+      Inscription {
+        body: Some(compressed),
+        content_encoding: Some("gzip".into()),
+        ..default()
+      }
+      .content_hash(usize::MAX),
+      None,
+    );
+
+    assert_eq!(
+      Inscription {
+        body: Some(vec![0, 1, 2, 3]),
+        content_encoding: Some(BROTLI.into()),
+        ..default()
+      }
+      .content_hash(usize::MAX),
+      None,
+    );
+
+    assert_eq!(Inscription::default().content_hash(usize::MAX), None);
+  }
+
+  #[test]
+  fn content_hash_is_skipped_past_max() {
+    let body = b"such content, very hash".repeat(100);
+
+    let mut compressed = Vec::new();
+
+    CompressorWriter::new(&mut compressed, BROTLI_BUFFER_SIZE, 11, 22)
+      .write_all(&body)
+      .unwrap();
+
+    let uncompressed = Inscription {
+      body: Some(body.clone()),
+      ..default()
+    };
+
+    let compressed = Inscription {
+      body: Some(compressed),
+      content_encoding: Some(BROTLI.into()),
+      ..default()
+    };
+
+    let expected = sha256::Hash::hash(&body);
+
+    for inscription in [uncompressed, compressed] {
+      assert_eq!(inscription.content_hash(body.len()), Some(expected));
+      assert_eq!(inscription.content_hash(body.len() - 1), None);
+    }
+  }
 }
@@ -48,7 +48,7 @@ use {
     },
     consensus::{self, Decodable, Encodable},
     hash_types::{BlockHash, TxMerkleNode},
-    hashes::Hash,
+    hashes::{Hash, sha256},
     policy::MAX_STANDARD_TX_WEIGHT,
     script,
     secp256k1::{self, Secp256k1},
@@ -60,8 +60,8 @@ use {
   ciborium::Value,
   clap::{ArgGroup, Parser},
   doginals::{
-    Artifact, Charm, Dune, DuneId, Dunestone, Edict, Epoch, Etching, Height, Koinu, KoinuPoint,
-    Pile, Rarity, SpacedDune, Terms, varint,
+    Artifact, Charm, CharmHeights, Dune, DuneId, Dunestone, Edict, Epoch, Etching, Height, Koinu,
+    KoinuPoint, Pile, Rarity, SpacedDune, Terms, varint,
   },
   error::{ResultExt, SnafuError},
   regex::Regex,
@@ -133,6 +133,7 @@ mod object;
 mod option_ext;
 pub mod options;
 pub mod outgoing;
+mod policy;
 mod properties;
 mod re;
 mod representation;
@@ -150,7 +151,7 @@ const BROTLI: &str = "br";
 const BROTLI_BUFFER_SIZE: usize = 4096;
 const INTERRUPT_LIMIT: u64 = 5;
 const MAX_STANDARD_OP_RETURN_SIZE: usize = 83;
-const TARGET_POSTAGE: Amount = Amount::from_sat(10_000);
+const TARGET_POSTAGE: Amount = policy::DEFAULT_POSTAGE;
 
 static INTERRUPTS: AtomicU64 = AtomicU64::new(0);
 static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
@@ -257,6 +258,16 @@ fn unversioned_leaf_script_from_witness(witness: &Witness) -> Option<&Script> {
   witness.tapscript()
 }
 
+/// Returns the last push of a scriptSig, which for a P2SH spend is the
+/// serialized redeem script. The spent output is not checked, so the result
+/// may be an arbitrary push from any kind of input.
+fn redeem_script_from_script_sig(script_sig: &Script) -> Option<&Script> {
+  match script_sig.instructions().last()?.ok()? {
+    script::Instruction::PushBytes(push) => Some(Script::from_bytes(push.as_bytes())),
+    script::Instruction::Op(_) => None,
+  }
+}
+
 pub fn main() {
   env_logger::init();
 
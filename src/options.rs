@@ -18,7 +18,7 @@ pub struct Options {
   pub(crate) dogecoin_rpc_username: Option<String>,
   #[arg(
     long,
-    help = "Connect to Dogecoin Core ZMQ at <DOGECOIN_ZMQ_ADDRESS> (e.g. tcp://127.0.0.1:28332)."
+    help = "Subscribe to Dogecoin Core `hashblock` ZMQ notifications at <DOGECOIN_ZMQ_ADDRESS> (e.g. tcp://127.0.0.1:28332)."
   )]
   pub(crate) dogecoin_zmq_address: Option<String>,
   #[arg(long, help = "Max <N> requests in flight. [default: 12]")]
@@ -66,6 +66,8 @@ pub struct Options {
     help = "Set index cache size to <INDEX_CACHE_SIZE> bytes. [default: 1/4 available RAM]"
   )]
   pub(crate) index_cache_size: Option<usize>,
+  #[arg(long, help = "Track inscriptions by SHA-256 of their decoded content.")]
+  pub(crate) index_content_hashes: bool,
   #[arg(long, help = "Track location of dunes.")]
   pub(crate) index_dunes: bool,
   #[arg(long, help = "Track location of all koinu.")]
@@ -76,6 +78,11 @@ pub struct Options {
     help = "Track rare koinu and start indexing from height 0."
   )]
   pub(crate) index_rare_koinu: bool,
+  #[arg(
+    long,
+    help = "Index words in text, JSON and HTML inscriptions for full-text search."
+  )]
+  pub(crate) index_text: bool,
   #[arg(long, help = "Store transactions in index.")]
   pub(crate) index_transactions: bool,
   #[arg(
@@ -95,11 +102,21 @@ pub struct Options {
     help = "Do not index inscriptions."
   )]
   pub(crate) no_index_inscriptions: bool,
+  #[arg(
+    long,
+    help = "Index from `.blk` files in the Dogecoin data directory without connecting to Dogecoin Core."
+  )]
+  pub(crate) offline: bool,
   #[arg(
     long,
     help = "Require basic HTTP authentication with <SERVER_PASSWORD>. Credentials are sent in cleartext. Consider using authentication in conjunction with HTTPS."
   )]
   pub(crate) server_password: Option<String>,
+  #[arg(
+    long,
+    help = "Query `dog server` running at <SERVER_URL> instead of opening the index in read-only subcommands."
+  )]
+  pub(crate) server_url: Option<Url>,
   #[arg(
     long,
     help = "Require basic HTTP authentication with <SERVER_USERNAME>. Credentials are sent in cleartext. Consider using authentication in conjunction with HTTPS."
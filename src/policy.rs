@@ -0,0 +1,130 @@
+//! Dogecoin Core relay policy.
+//!
+//! Dogecoin Core prices transactions per kilobyte rather than per virtual
+//! byte, refuses to relay outputs below the dust limit, and charges one
+//! kilobyte's worth of additional fee for each output below the soft dust
+//! limit. Transactions built by the wallet follow these rules so that they
+//! relay with default node settings.
+
+use super::*;
+
+/// Outputs worth less than 0.01 DOGE are not relayed.
+pub(crate) const DUST_LIMIT: Amount = Amount::from_sat(1_000_000);
+
+/// Outputs worth less than 1 DOGE incur a soft dust surcharge.
+pub(crate) const SOFT_DUST_LIMIT: Amount = Amount::from_sat(100_000_000);
+
+/// Value of new outputs holding inscriptions or dunes, the smallest value
+/// which relays.
+pub(crate) const DEFAULT_POSTAGE: Amount = DUST_LIMIT;
+
+/// Smallest fee rate that relays with default settings, 0.01 DOGE/kB.
+#[cfg(test)]
+pub(crate) const MIN_FEE_PER_KB: Amount = Amount::from_sat(1_000_000);
+
+/// Value below which an output with `script_pubkey` is not relayed.
+/// Provably unspendable OP_RETURN outputs are exempt.
+pub(crate) fn dust_limit(script_pubkey: &Script) -> Amount {
+  if script_pubkey.is_op_return() {
+    Amount::ZERO
+  } else {
+    DUST_LIMIT
+  }
+}
+
+/// Whether `output` incurs the soft dust surcharge.
+pub(crate) fn is_soft_dust(output: &TxOut) -> bool {
+  !output.script_pubkey.is_op_return() && output.value < SOFT_DUST_LIMIT
+}
+
+/// Surcharge for each soft dust output, one kilobyte at `fee_rate`.
+pub(crate) fn soft_dust_surcharge(fee_rate: FeeRate) -> Amount {
+  fee_rate.fee(1000)
+}
+
+/// Fee for a transaction of `size` bytes with `outputs`, including soft dust
+/// surcharges.
+pub(crate) fn fee(fee_rate: FeeRate, size: usize, outputs: &[TxOut]) -> Amount {
+  let soft_dust = outputs.iter().filter(|output| is_soft_dust(output)).count();
+
+  fee_rate.fee(size) + soft_dust_surcharge(fee_rate) * soft_dust.into_u64()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn output(value: u64) -> TxOut {
+    TxOut {
+      value: Amount::from_sat(value),
+      script_pubkey: ScriptBuf::new_p2pkh(&bitcoin::PubkeyHash::all_zeros()),
+    }
+  }
+
+  fn op_return() -> TxOut {
+    TxOut {
+      value: Amount::ZERO,
+      script_pubkey: ScriptBuf::new_op_return([]),
+    }
+  }
+
+  #[test]
+  fn thresholds() {
+    assert_eq!(DUST_LIMIT, Amount::from_str("0.01 BTC").unwrap());
+    assert_eq!(SOFT_DUST_LIMIT, Amount::from_str("1 BTC").unwrap());
+    assert_eq!(MIN_FEE_PER_KB, Amount::from_str("0.01 BTC").unwrap());
+    assert_eq!(DEFAULT_POSTAGE, DUST_LIMIT);
+    assert_eq!(TARGET_POSTAGE, DEFAULT_POSTAGE);
+  }
+
+  #[test]
+  fn dust_limit_exempts_op_return() {
+    assert_eq!(dust_limit(&output(0).script_pubkey), DUST_LIMIT);
+    assert_eq!(dust_limit(&op_return().script_pubkey), Amount::ZERO);
+  }
+
+  #[test]
+  fn soft_dust() {
+    assert!(is_soft_dust(&output(DUST_LIMIT.to_sat())));
+    assert!(is_soft_dust(&output(SOFT_DUST_LIMIT.to_sat() - 1)));
+    assert!(!is_soft_dust(&output(SOFT_DUST_LIMIT.to_sat())));
+    assert!(!is_soft_dust(&op_return()));
+  }
+
+  #[test]
+  fn fee_includes_soft_dust_surcharge() {
+    let fee_rate = FeeRate::from_per_kb(MIN_FEE_PER_KB);
+
+    assert_eq!(soft_dust_surcharge(fee_rate), MIN_FEE_PER_KB);
+
+    assert_eq!(
+      fee(
+        fee_rate,
+        250,
+        &[output(SOFT_DUST_LIMIT.to_sat()), op_return()]
+      ),
+      Amount::from_sat(250_000),
+    );
+
+    assert_eq!(
+      fee(
+        fee_rate,
+        250,
+        &[
+          output(DUST_LIMIT.to_sat()),
+          output(SOFT_DUST_LIMIT.to_sat())
+        ]
+      ),
+      Amount::from_sat(1_250_000),
+    );
+
+    assert_eq!(
+      fee(
+        fee_rate,
+        250,
+        &[output(DUST_LIMIT.to_sat()), output(DUST_LIMIT.to_sat())]
+      ),
+      Amount::from_sat(2_250_000),
+    );
+  }
+}
@@ -11,6 +11,8 @@ pub(crate) static ADDRESS: LazyLock<Regex> = re!(
 );
 pub(crate) static COINKITE_SATSCARD_URL: LazyLock<Regex> =
   re!(r"https://(get)?koinucard.com/start#(?<parameters>.*)");
+pub(crate) static DNS_NAME: LazyLock<Regex> = re!(r"[^.\s]+\.(?<namespace>[a-z]+)");
+pub(crate) static DOGEMAP: LazyLock<Regex> = re!(r"([0-9]+\.)?(?<block>[0-9]+)\.dogemap");
 pub(crate) static HASH: LazyLock<Regex> = re!(r"[[:xdigit:]]{64}");
 pub(crate) static INSCRIPTION_ID: LazyLock<Regex> = re!(r"[[:xdigit:]]{64}i\d+");
 pub(crate) static INSCRIPTION_NUMBER: LazyLock<Regex> = re!(r"-?[0-9]{1,63}");
@@ -33,14 +33,19 @@ pub struct Settings {
   index: Option<PathBuf>,
   index_addresses: bool,
   index_cache_size: Option<usize>,
+  index_content_hashes: bool,
   index_dunes: bool,
   index_koinu: bool,
   index_rare_koinu: bool,
+  index_text: bool,
   index_transactions: bool,
   only_protocols: Option<Vec<String>>,
   integration_test: bool,
   max_savepoints: Option<usize>,
   no_index_inscriptions: bool,
+  offline: bool,
+  #[serde(skip)]
+  remote_url: Option<String>,
   savepoint_interval: Option<usize>,
   server_password: Option<String>,
   server_url: Option<String>,
@@ -77,8 +82,10 @@ impl Settings {
         | "INDEX"
         | "INDEX_ADDRESSES"
         | "INDEX_CACHE_SIZE"
+        | "INDEX_CONTENT_HASHES"
         | "INDEX_DUNES"
         | "INDEX_KOINU"
+        | "INDEX_TEXT"
         | "INDEX_TRANSACTIONS"
         | "ONLY_PROTOCOLS"
         | "INTEGRATION_TEST"
@@ -209,14 +216,18 @@ impl Settings {
       index: self.index.or(source.index),
       index_addresses: self.index_addresses || source.index_addresses,
       index_cache_size: self.index_cache_size.or(source.index_cache_size),
+      index_content_hashes: self.index_content_hashes || source.index_content_hashes,
       index_dunes: self.index_dunes || source.index_dunes,
       index_koinu: self.index_koinu || source.index_koinu,
       index_rare_koinu: self.index_rare_koinu || source.index_rare_koinu,
+      index_text: self.index_text || source.index_text,
       index_transactions: self.index_transactions || source.index_transactions,
       only_protocols: self.only_protocols.or(source.only_protocols),
       integration_test: self.integration_test || source.integration_test,
       max_savepoints: self.max_savepoints.or(source.max_savepoints),
       no_index_inscriptions: self.no_index_inscriptions || source.no_index_inscriptions,
+      offline: self.offline || source.offline,
+      remote_url: self.remote_url.or(source.remote_url),
       savepoint_interval: self.savepoint_interval.or(source.savepoint_interval),
       server_password: self.server_password.or(source.server_password),
       server_url: self.server_url.or(source.server_url),
@@ -250,9 +261,11 @@ impl Settings {
       index: options.index,
       index_addresses: options.index_addresses,
       index_cache_size: options.index_cache_size,
+      index_content_hashes: options.index_content_hashes,
       index_dunes: options.index_dunes,
       index_koinu: options.index_koinu,
       index_rare_koinu: options.index_rare_koinu,
+      index_text: options.index_text,
       index_transactions: options.index_transactions,
       only_protocols: if options.only.is_empty() {
         None
@@ -262,6 +275,8 @@ impl Settings {
       integration_test: options.integration_test,
       max_savepoints: options.max_savepoints,
       no_index_inscriptions: options.no_index_inscriptions,
+      offline: options.offline,
+      remote_url: options.server_url.map(|url| url.to_string()),
       savepoint_interval: options.savepoint_interval,
       server_password: options.server_password,
       server_url: None,
@@ -348,9 +363,11 @@ impl Settings {
       index: get_path("INDEX"),
       index_addresses: get_bool("INDEX_ADDRESSES"),
       index_cache_size: get_usize("INDEX_CACHE_SIZE")?,
+      index_content_hashes: get_bool("INDEX_CONTENT_HASHES"),
       index_dunes: get_bool("INDEX_DUNES"),
       index_koinu: get_bool("INDEX_KOINU"),
       index_rare_koinu: get_bool("INDEX_RARE_KOINU"),
+      index_text: get_bool("INDEX_TEXT"),
       index_transactions: get_bool("INDEX_TRANSACTIONS"),
       only_protocols: env.get("ONLY_PROTOCOLS").map(|s| {
         s.split(',')
@@ -361,6 +378,8 @@ impl Settings {
       integration_test: get_bool("INTEGRATION_TEST"),
       max_savepoints: get_usize("MAX_SAVEPOINTS")?,
       no_index_inscriptions: get_bool("NO_INDEX_INSCRIPTIONS"),
+      offline: get_bool("OFFLINE"),
+      remote_url: None,
       savepoint_interval: get_usize("SAVEPOINT_INTERVAL")?,
       server_password: get_string("SERVER_PASSWORD"),
       server_url: get_string("SERVER_URL"),
@@ -391,14 +410,18 @@ impl Settings {
       index: None,
       index_addresses: true,
       index_cache_size: None,
+      index_content_hashes: true,
       index_dunes: true,
       index_koinu: true,
       index_rare_koinu: false,
+      index_text: false,
       index_transactions: false,
       only_protocols: None,
       integration_test: false,
       max_savepoints: None,
       no_index_inscriptions: false,
+      offline: false,
+      remote_url: None,
       savepoint_interval: None,
       server_password: None,
       server_url: Some(server_url.into()),
@@ -462,14 +485,18 @@ impl Settings {
           usize::try_from(sys.total_memory() / 4)?
         }
       }),
+      index_content_hashes: self.index_content_hashes,
       index_dunes: self.index_dunes,
       index_koinu: self.index_koinu || self.index_rare_koinu,
       index_rare_koinu: self.index_rare_koinu,
+      index_text: self.index_text,
       index_transactions: self.index_transactions,
       only_protocols: self.only_protocols,
       integration_test: self.integration_test,
       max_savepoints: Some(self.max_savepoints.unwrap_or(2)),
       no_index_inscriptions: self.no_index_inscriptions,
+      offline: self.offline,
+      remote_url: self.remote_url,
       savepoint_interval: Some(self.savepoint_interval.unwrap_or(10)),
       server_password: self.server_password,
       server_url: self.server_url,
@@ -508,6 +535,9 @@ impl Settings {
       .zip(self.dogecoin_rpc_password.as_ref())
     {
       Ok(Auth::UserPass((*user).clone(), (*pass).clone()))
+    } else if self.offline {
+      // There is no node, and so no cookie file, when indexing offline.
+      Ok(Auth::UserPass(String::new(), String::new()))
     } else {
       Ok(Auth::CookieFile(self.cookie_file()?))
     }
@@ -516,6 +546,12 @@ impl Settings {
   pub fn dogecoin_rpc_client(&self, wallet: Option<String>) -> Result<Client> {
     let rpc_url = self.dogecoin_rpc_url(wallet);
 
+    // Offline indexing reads `.blk` files directly; hand back an unverified
+    // client so nothing blocks waiting for a node that isn't there.
+    if self.offline {
+      return Ok(Client::new(&rpc_url, Auth::None)?);
+    }
+
     let dogecoin_credentials = self.dogecoin_credentials()?;
 
     log::trace!(
@@ -667,6 +703,17 @@ impl Settings {
     }
   }
 
+  /// Height from which outputs that may hold a dune commitment are recorded.
+  /// A commitment may confirm any number of blocks before its etching, so
+  /// commit outputs are recorded from genesis on chains where dunes activate.
+  pub fn first_dune_commit_height(&self) -> u32 {
+    if self.first_dune_height() == u32::MAX {
+      u32::MAX
+    } else {
+      0
+    }
+  }
+
   pub fn height_limit(&self) -> Option<u32> {
     self.height_limit
   }
@@ -691,6 +738,10 @@ impl Settings {
     self.index_cache_size.unwrap()
   }
 
+  pub fn index_content_hashes_raw(&self) -> bool {
+    self.index_content_hashes
+  }
+
   pub fn index_koinu_raw(&self) -> bool {
     self.index_koinu
   }
@@ -699,6 +750,10 @@ impl Settings {
     self.index_rare_koinu
   }
 
+  pub fn index_text_raw(&self) -> bool {
+    self.index_text
+  }
+
   pub fn index_transactions_raw(&self) -> bool {
     self.index_transactions
   }
@@ -707,6 +762,10 @@ impl Settings {
     self.only_protocols.as_deref()
   }
 
+  pub fn offline(&self) -> bool {
+    self.offline
+  }
+
   pub fn integration_test(&self) -> bool {
     self.integration_test
   }
@@ -742,6 +801,10 @@ impl Settings {
   pub fn server_url(&self) -> Option<&str> {
     self.server_url.as_deref()
   }
+
+  pub(crate) fn remote_url(&self) -> Option<&str> {
+    self.remote_url.as_deref()
+  }
 }
 
 #[cfg(test)]
@@ -1235,12 +1298,15 @@ mod tests {
       ("INDEX", "index"),
       ("INDEX_ADDRESSES", "1"),
       ("INDEX_CACHE_SIZE", "4"),
+      ("INDEX_CONTENT_HASHES", "1"),
       ("INDEX_DUNES", "1"),
       ("INDEX_KOINU", "1"),
+      ("INDEX_TEXT", "1"),
       ("INDEX_TRANSACTIONS", "1"),
       ("INTEGRATION_TEST", "1"),
       ("MAX_SAVEPOINTS", "2"),
       ("NO_INDEX_INSCRIPTIONS", "1"),
+      ("OFFLINE", "1"),
       ("SAVEPOINT_INTERVAL", "10"),
       ("SERVER_PASSWORD", "server password"),
       ("SERVER_URL", "server url"),
@@ -1287,12 +1353,16 @@ mod tests {
         index: Some("index".into()),
         index_addresses: true,
         index_cache_size: Some(4),
+        index_content_hashes: true,
         index_dunes: true,
         index_koinu: true,
+        index_text: true,
         index_transactions: true,
         only_protocols: None,
         integration_test: true,
         no_index_inscriptions: true,
+        offline: true,
+        remote_url: None,
         server_password: Some("server password".into()),
         server_url: Some("server url".into()),
         server_username: Some("server username".into()),
@@ -1389,13 +1459,17 @@ mod tests {
           "--height-limit=3",
           "--index-addresses",
           "--index-cache-size=4",
+          "--index-content-hashes",
           "--index-dunes",
           "--index-koinu",
+          "--index-text",
           "--index-transactions",
           "--index=index",
           "--integration-test",
           "--no-index-inscriptions",
+          "--offline",
           "--server-password=server password",
+          "--server-url=http://localhost:8080",
           "--server-username=server username",
         ])
         .unwrap()
@@ -1424,12 +1498,16 @@ mod tests {
         index: Some("index".into()),
         index_addresses: true,
         index_cache_size: Some(4),
+        index_content_hashes: true,
         index_dunes: true,
         index_koinu: true,
+        index_text: true,
         index_transactions: true,
         only_protocols: None,
         integration_test: true,
         no_index_inscriptions: true,
+        offline: true,
+        remote_url: Some("http://localhost:8080/".into()),
         server_password: Some("server password".into()),
         server_url: None,
         server_username: Some("server username".into()),
@@ -1518,6 +1596,12 @@ mod tests {
     );
   }
 
+  #[test]
+  fn dune_commit_outputs_are_recorded_from_genesis_once_dunes_activate() {
+    assert_eq!(parse(&[]).first_dune_commit_height(), u32::MAX);
+    assert_eq!(parse(&["--integration-test"]).first_dune_commit_height(), 0);
+  }
+
   #[test]
   fn index_koinu_forces_genesis_scan() {
     assert_eq!(parse(&["--index-koinu"]).first_inscription_height(), 0);
@@ -15,6 +15,7 @@ pub mod inscribe;
 pub mod list;
 pub mod parity;
 pub mod parse;
+mod remote;
 pub mod scan;
 pub mod server;
 mod settings;
@@ -67,7 +68,7 @@ pub(crate) enum Subcommand {
   #[command(about = "Display information about a block's subsidy")]
   Subsidy(subsidy::Subsidy),
   #[command(about = "Display Dogecoin supply information")]
-  Supply,
+  Supply(supply::Supply),
   #[command(about = "Generate teleburn addresses")]
   Teleburn(teleburn::Teleburn),
   #[command(about = "Display koinu traits")]
@@ -107,9 +108,9 @@ impl Subcommand {
       }
       Self::Settings => settings::run(settings),
       Self::Subsidy(subsidy) => subsidy.run(),
-      Self::Supply => supply::run(),
+      Self::Supply(supply) => supply.run(),
       Self::Teleburn(teleburn) => teleburn.run(),
-      Self::Traits(traits) => traits.run(),
+      Self::Traits(traits) => traits.run(settings),
       Self::Verify(verify) => verify.run(),
       Self::Wallet(wallet) => wallet.run(settings),
       Self::Wallets => wallets::run(settings),
@@ -1,4 +1,10 @@
-use {super::super::*, crate::subcommand::dns::DnsInfo};
+use {
+  super::super::*,
+  crate::{
+    index::DnsEntry,
+    subcommand::{dns::DnsInfo, remote::Remote},
+  },
+};
 
 #[derive(Clone, Debug, Parser)]
 pub struct ResolveCommand {
@@ -11,10 +17,15 @@ pub struct ResolveCommand {
 
 impl ResolveCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let index = Index::open(&settings)?;
-    index.update()?;
+    let entry: Option<DnsEntry> = if let Some(remote) = Remote::new(&settings)? {
+      remote.get(&["r", "dns", "name", &self.name])?
+    } else {
+      let index = Index::open(&settings)?;
+      index.update()?;
+      index.get_dns_name(&self.name)?
+    };
 
-    if let Some(entry) = index.get_dns_name(&self.name)? {
+    if let Some(entry) = entry {
       if self.json {
         let info = DnsInfo::from(entry);
         println!("{}", serde_json::to_string_pretty(&info)?);
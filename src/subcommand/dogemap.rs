@@ -1,4 +1,4 @@
-use {super::*, crate::index::DogemapEntry};
+use {super::*, crate::index::DogemapEntry, remote::Remote};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DogemapInfo {
@@ -6,11 +6,18 @@ pub struct DogemapInfo {
   pub owner_inscription_id: String,
   pub claim_height: u32,
   pub claim_timestamp: u32,
+  pub owner: Option<String>,
 }
 
-impl From<DogemapEntry> for DogemapInfo {
-  fn from(e: DogemapEntry) -> Self {
+impl DogemapInfo {
+  fn load(index: &Index, e: DogemapEntry) -> Result<Self> {
+    let owner = index.get_dogemap_owner(e.block_number)?;
+    Ok(Self::new(e, owner))
+  }
+
+  fn new(e: DogemapEntry, owner: Option<String>) -> Self {
     Self {
+      owner,
       block_number: e.block_number,
       owner_inscription_id: e.owner_inscription_id.to_string(),
       claim_height: e.claim_height,
@@ -19,6 +26,25 @@ impl From<DogemapEntry> for DogemapInfo {
   }
 }
 
+/// One move of a Dogemap claim inscription. `from` is `None` for the claim
+/// itself, and `from`/`to` are `None` when the script has no address.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DogemapTransfer {
+  pub height: u32,
+  pub timestamp: u32,
+  pub location: KoinuPoint,
+  pub from: Option<String>,
+  pub to: Option<String>,
+}
+
+/// A `{parcel}.{block_number}.dogemap` child of a block's claim inscription.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DogemapParcel {
+  pub block_number: u32,
+  pub parcel: u32,
+  pub inscription_id: InscriptionId,
+}
+
 #[derive(Clone, Debug, Parser)]
 pub struct DogemapCommand {
   #[command(subcommand)]
@@ -31,6 +57,10 @@ pub enum DogemapSubcommand {
   Status(StatusCommand),
   #[command(about = "List all claimed block numbers")]
   List(ListCommand),
+  #[command(about = "List block numbers owned by an address")]
+  Owned(OwnedCommand),
+  #[command(about = "List parcels of a claimed block number")]
+  Parcels(ParcelsCommand),
 }
 
 impl DogemapCommand {
@@ -38,6 +68,8 @@ impl DogemapCommand {
     match self.command {
       DogemapSubcommand::Status(cmd) => cmd.run(settings),
       DogemapSubcommand::List(cmd) => cmd.run(settings),
+      DogemapSubcommand::Owned(cmd) => cmd.run(settings),
+      DogemapSubcommand::Parcels(cmd) => cmd.run(settings),
     }
   }
 }
@@ -54,12 +86,55 @@ pub struct StatusCommand {
 
 impl StatusCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let index = Index::open(&settings)?;
-    index.update()?;
-
-    if let Some(entry) = index.get_dogemap_claim(self.block_number)? {
-      let info = DogemapInfo::from(entry);
-      println!("{}", serde_json::to_string_pretty(&info)?);
+    let claim = if let Some(remote) = Remote::new(&settings)? {
+      #[derive(Deserialize)]
+      struct Dogemap {
+        owner: Option<String>,
+      }
+
+      match remote.get::<DogemapEntry>(&[
+        "r",
+        "dogemap",
+        "block",
+        &self.block_number.to_string(),
+      ])? {
+        Some(entry) => {
+          let owner = remote
+            .get::<Dogemap>(&["dogemap", &self.block_number.to_string()])?
+            .and_then(|dogemap| dogemap.owner);
+
+          Some((
+            DogemapInfo::new(entry, owner),
+            remote
+              .get::<Vec<DogemapTransfer>>(&[
+                "r",
+                "dogemap",
+                "transfers",
+                &self.block_number.to_string(),
+              ])?
+              .unwrap_or_default(),
+          ))
+        }
+        None => None,
+      }
+    } else {
+      let index = Index::open(&settings)?;
+      index.update()?;
+
+      match index.get_dogemap_claim(self.block_number)? {
+        Some(entry) => Some((
+          DogemapInfo::load(&index, entry)?,
+          index.get_dogemap_transfers(self.block_number)?,
+        )),
+        None => None,
+      }
+    };
+
+    if let Some((info, transfers)) = claim {
+      let mut json = serde_json::to_value(info)?;
+      json["transfers"] = serde_json::to_value(transfers)?;
+
+      println!("{}", serde_json::to_string_pretty(&json)?);
     } else {
       println!(
         "{}",
@@ -94,7 +169,10 @@ impl ListCommand {
 
     let entries = index.list_dogemaps(self.limit, self.offset)?;
     let total = index.count_dogemaps()?;
-    let infos: Vec<DogemapInfo> = entries.into_iter().map(DogemapInfo::from).collect();
+    let infos = entries
+      .into_iter()
+      .map(|entry| DogemapInfo::load(&index, entry))
+      .collect::<Result<Vec<DogemapInfo>>>()?;
 
     println!(
       "{}",
@@ -109,3 +187,63 @@ impl ListCommand {
     Ok(None)
   }
 }
+
+// ---------------------------------------------------------------------------
+// dogemap owned <address>
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Parser)]
+pub struct OwnedCommand {
+  #[arg(help = "Address to list Dogemaps for")]
+  pub address: String,
+}
+
+impl OwnedCommand {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+    index.update()?;
+
+    let blocks = index.get_dogemaps_by_owner(&self.address)?;
+
+    println!(
+      "{}",
+      serde_json::to_string_pretty(&serde_json::json!({
+        "address": self.address,
+        "total": blocks.len(),
+        "blocks": blocks,
+      }))?
+    );
+
+    Ok(None)
+  }
+}
+
+// ---------------------------------------------------------------------------
+// dogemap parcels <block_number>
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Parser)]
+pub struct ParcelsCommand {
+  #[arg(help = "Block number to list parcels for")]
+  pub block_number: u32,
+}
+
+impl ParcelsCommand {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+    index.update()?;
+
+    let parcels = index.get_dogemap_parcels(self.block_number)?;
+
+    println!(
+      "{}",
+      serde_json::to_string_pretty(&serde_json::json!({
+        "block_number": self.block_number,
+        "total": parcels.len(),
+        "parcels": parcels,
+      }))?
+    );
+
+    Ok(None)
+  }
+}
@@ -59,6 +59,20 @@ pub struct Drc20Transfer {
   pub from_address: String,
 }
 
+/// A DRC-20 operation, recorded per tick for the explorer's activity list.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Drc20Activity {
+  /// "deploy", "mint", "inscribe-transfer" or "transfer"
+  pub op: String,
+  /// Amount moved, or the max supply for a deploy, scaled by 10^decimals
+  pub amount: u128,
+  pub from: Option<String>,
+  pub to: Option<String>,
+  pub txid: Txid,
+  pub height: u32,
+  pub timestamp: u32,
+}
+
 // ---------------------------------------------------------------------------
 // Amount helpers
 // ---------------------------------------------------------------------------
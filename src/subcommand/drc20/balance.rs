@@ -1,4 +1,10 @@
-use {super::super::*, crate::subcommand::drc20::format_amount};
+use {
+  super::super::*,
+  crate::subcommand::{
+    drc20::{Drc20Token, format_amount},
+    remote::Remote,
+  },
+};
 
 #[derive(Clone, Debug, Parser)]
 pub struct BalanceCommand {
@@ -14,19 +20,37 @@ pub struct BalanceCommand {
 
 impl BalanceCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let index = Index::open(&settings)?;
-    index.update()?;
+    let remote = Remote::new(&settings)?;
+
+    let index = if remote.is_none() {
+      let index = Index::open(&settings)?;
+      index.update()?;
+      Some(index)
+    } else {
+      None
+    };
 
     if let Some(ref tick) = self.tick {
       // Single token balance
-      let (available, transferable) = index.get_drc20_balance(&self.address, tick)?;
+      let ((available, transferable), token) = if let Some(remote) = &remote {
+        let balance = remote
+          .get::<api::Drc20Balance>(&["r", "drc20", "balance", &self.address.to_string(), tick])?
+          .ok_or_else(|| anyhow!("could not get balance of {} from server", self.address))?;
+        (
+          (balance.available, balance.transferable),
+          remote.get::<Drc20Token>(&["r", "drc20", "token", tick])?,
+        )
+      } else {
+        let index = index.as_ref().unwrap();
+        (
+          index.get_drc20_balance(&self.address, tick)?,
+          index.get_drc20_token(tick)?,
+        )
+      };
       let total = available + transferable;
 
       // Get decimals for display
-      let decimals = index
-        .get_drc20_token(tick)?
-        .map(|t| t.decimals)
-        .unwrap_or(8);
+      let decimals = token.map(|t| t.decimals).unwrap_or(8);
 
       if self.json {
         let out = serde_json::json!({
@@ -46,7 +70,21 @@ impl BalanceCommand {
       }
     } else {
       // All token balances for address
-      let balances = index.get_drc20_balances(&self.address)?;
+      let balances = if let Some(remote) = &remote {
+        remote
+          .get::<Vec<api::Drc20BalanceEntry>>(&[
+            "r",
+            "drc20",
+            "balance",
+            &self.address.to_string(),
+          ])?
+          .unwrap_or_default()
+          .into_iter()
+          .map(|entry| (entry.tick, entry.available, entry.transferable))
+          .collect()
+      } else {
+        index.unwrap().get_drc20_balances(&self.address)?
+      };
 
       if self.json {
         let rows: Vec<_> = balances
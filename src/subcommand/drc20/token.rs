@@ -1,4 +1,7 @@
-use super::super::*;
+use {
+  super::super::*,
+  crate::subcommand::{drc20::Drc20Token, remote::Remote},
+};
 
 #[derive(Clone, Debug, Parser)]
 pub struct TokenCommand {
@@ -11,10 +14,15 @@ pub struct TokenCommand {
 
 impl TokenCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let index = Index::open(&settings)?;
-    index.update()?;
+    let token: Option<Drc20Token> = if let Some(remote) = Remote::new(&settings)? {
+      remote.get(&["r", "drc20", "token", &self.tick])?
+    } else {
+      let index = Index::open(&settings)?;
+      index.update()?;
+      index.get_drc20_token(&self.tick)?
+    };
 
-    if let Some(token) = index.get_drc20_token(&self.tick)? {
+    if let Some(token) = token {
       if self.json {
         println!("{}", serde_json::to_string_pretty(&token)?);
       } else {
@@ -1,4 +1,7 @@
-use {super::super::*, crate::subcommand::drc20::format_amount};
+use {
+  super::super::*,
+  crate::subcommand::{drc20::format_amount, remote::Remote},
+};
 
 #[derive(Clone, Debug, Parser)]
 pub struct TokensCommand {
@@ -8,11 +11,16 @@ pub struct TokensCommand {
 
 impl TokensCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let index = Index::open(&settings)?;
-    index.update()?;
+    let tokens = if let Some(remote) = Remote::new(&settings)? {
+      remote.get(&["r", "drc20", "tokens"])?.unwrap_or_default()
+    } else {
+      let index = Index::open(&settings)?;
+      index.update()?;
 
-    let mut tokens = index.get_drc20_tokens()?;
-    tokens.sort_by(|a, b| a.tick.to_lowercase().cmp(&b.tick.to_lowercase()));
+      let mut tokens = index.get_drc20_tokens()?;
+      tokens.sort_by(|a, b| a.tick.to_lowercase().cmp(&b.tick.to_lowercase()));
+      tokens
+    };
 
     if self.json {
       println!("{}", serde_json::to_string_pretty(&tokens)?);
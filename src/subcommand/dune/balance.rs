@@ -1,4 +1,7 @@
-use {super::super::*, crate::subcommand::inscribe::parse_dogecoin_address};
+use {
+  super::{super::*, format_pile},
+  crate::subcommand::{inscribe::parse_dogecoin_address, remote::Remote},
+};
 
 #[derive(Clone, Debug, Parser)]
 pub struct BalanceCommand {
@@ -11,22 +14,45 @@ pub struct BalanceCommand {
 
 impl BalanceCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let index = Index::open(&settings)?;
+    let script = parse_dogecoin_address(&self.address)?;
 
-    ensure!(
-      index.has_dune_index(),
-      "`dog dune balance` requires index created with `--index-dunes` flag"
-    );
+    let balances = if let Some(remote) = Remote::new(&settings)? {
+      let status = remote.status()?;
 
-    ensure!(
-      index.has_address_index(),
-      "`dog dune balance` requires index created with `--index-addresses` flag"
-    );
+      ensure!(
+        status.dune_index,
+        "`dog dune balance` requires server index created with `--index-dunes` flag"
+      );
 
-    index.update()?;
+      ensure!(
+        status.address_index,
+        "`dog dune balance` requires server index created with `--index-addresses` flag"
+      );
 
-    let script = parse_dogecoin_address(&self.address)?;
-    let balances = index.get_dune_balances_for_script(script.as_bytes())?;
+      remote
+        .get::<api::AddressPortfolio>(&["r", "address", &self.address.to_string(), "portfolio"])?
+        .map(|portfolio| portfolio.dunes_balances)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(dune, decimal, symbol)| (dune, decimal.value, decimal.scale, symbol))
+        .collect()
+    } else {
+      let index = Index::open(&settings)?;
+
+      ensure!(
+        index.has_dune_index(),
+        "`dog dune balance` requires index created with `--index-dunes` flag"
+      );
+
+      ensure!(
+        index.has_address_index(),
+        "`dog dune balance` requires index created with `--index-addresses` flag"
+      );
+
+      index.update()?;
+
+      index.get_dune_balances_for_script(script.as_bytes())?
+    };
 
     if self.json {
       let rows: Vec<serde_json::Value> = balances
@@ -63,21 +89,3 @@ impl BalanceCommand {
     Ok(None)
   }
 }
-
-fn format_pile(amount: u128, divisibility: u8, symbol: Option<char>) -> String {
-  let sym = symbol.unwrap_or('¤');
-  if divisibility == 0 {
-    return format!("{amount}\u{A0}{sym}");
-  }
-  let scale = 10u128.pow(divisibility as u32);
-  let whole = amount / scale;
-  let frac = amount % scale;
-  if frac == 0 {
-    format!("{whole}\u{A0}{sym}")
-  } else {
-    format!(
-      "{whole}.{frac:0>width$}\u{A0}{sym}",
-      width = divisibility as usize
-    )
-  }
-}
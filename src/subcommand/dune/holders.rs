@@ -0,0 +1,122 @@
+use {
+  super::{super::*, format_pile},
+  crate::subcommand::remote::Remote,
+};
+
+#[derive(Clone, Debug, Parser)]
+pub struct HoldersCommand {
+  #[arg(help = "Dune name to look up (e.g. UNCOMMON•GOODS or UNCOMMONGOODS)")]
+  pub name: String,
+
+  #[arg(long, help = "Output as JSON")]
+  pub json: bool,
+}
+
+impl HoldersCommand {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let spaced: SpacedDune = self
+      .name
+      .parse()
+      .map_err(|_| anyhow!("invalid dune name: {}", self.name))?;
+
+    let holders = if let Some(remote) = Remote::new(&settings)? {
+      let status = remote.status()?;
+
+      ensure!(
+        status.dune_index,
+        "`dog dune holders` requires server index created with `--index-dunes` flag"
+      );
+
+      ensure!(
+        status.address_index,
+        "`dog dune holders` requires server index created with `--index-addresses` flag"
+      );
+
+      match remote.get::<api::Dune>(&["dune", &spaced.dune.to_string()])? {
+        None => None,
+        Some(dune) => {
+          let mut holders = Vec::new();
+
+          for page in 0.. {
+            let Some(response) = remote.get::<api::DuneHolders>(&[
+              "dune",
+              &spaced.dune.to_string(),
+              "holders",
+              &page.to_string(),
+            ])?
+            else {
+              break;
+            };
+
+            holders.extend(response.holders);
+
+            if !response.more {
+              break;
+            }
+          }
+
+          Some((dune.entry, holders))
+        }
+      }
+    } else {
+      let index = Index::open(&settings)?;
+
+      ensure!(
+        index.has_dune_index(),
+        "`dog dune holders` requires index created with `--index-dunes` flag"
+      );
+
+      ensure!(
+        index.has_address_index(),
+        "`dog dune holders` requires index created with `--index-addresses` flag"
+      );
+
+      index.update()?;
+
+      match index.dune(spaced.dune)? {
+        None => None,
+        Some((id, entry, _parent)) => Some((entry, index.get_dune_holders(id)?)),
+      }
+    };
+
+    let Some((entry, holders)) = holders else {
+      bail!("dune '{}' not found", self.name);
+    };
+
+    if self.json {
+      let rows: Vec<serde_json::Value> = holders
+        .iter()
+        .map(|holder| {
+          serde_json::json!({
+            "address": holder.address,
+            "amount": holder.amount,
+            "display": format_pile(holder.amount, entry.divisibility, entry.symbol),
+          })
+        })
+        .collect();
+      println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+          "dune": entry.spaced_dune.to_string(),
+          "holders": rows,
+        }))?
+      );
+    } else {
+      println!("Holders of {}", entry.spaced_dune);
+      println!("{:<40} {:>20}", "Address", "Balance");
+      println!("{}", "-".repeat(62));
+      for holder in &holders {
+        println!(
+          "{:<40} {:>20}",
+          holder.address,
+          format_pile(holder.amount, entry.divisibility, entry.symbol)
+        );
+      }
+      if holders.is_empty() {
+        println!("No holders found.");
+      }
+    }
+
+    Ok(None)
+  }
+}
@@ -4,6 +4,9 @@ use super::super::*;
 pub struct ListCommand {
   #[arg(long, help = "Output as JSON")]
   pub json: bool,
+
+  #[arg(long, help = "Only list dunes that can be minted in the next block")]
+  pub mintable: bool,
 }
 
 impl ListCommand {
@@ -17,7 +20,12 @@ impl ListCommand {
 
     index.update()?;
 
-    let entries = index.dunes()?;
+    let mut entries = index.dunes()?;
+
+    if self.mintable {
+      let height = index.block_height()?.unwrap_or(Height(0)).n() + 1;
+      entries.retain(|(_id, entry)| entry.mintable(height.into()).is_ok());
+    }
 
     if self.json {
       let rows: Vec<serde_json::Value> = entries
@@ -53,7 +61,11 @@ impl ListCommand {
           id.block,
         );
       }
-      println!("\n{} dune(s) total.", entries.len());
+      if self.mintable {
+        println!("\n{} dune(s) mintable.", entries.len());
+      } else {
+        println!("\n{} dune(s) total.", entries.len());
+      }
     }
 
     Ok(None)
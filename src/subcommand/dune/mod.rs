@@ -1,6 +1,7 @@
 use super::*;
 
 pub mod balance;
+pub mod holders;
 pub mod info;
 pub mod list;
 
@@ -18,6 +19,8 @@ pub enum DuneSubcommand {
   Info(info::InfoCommand),
   #[command(about = "Show dune balances for a Dogecoin address")]
   Balance(balance::BalanceCommand),
+  #[command(about = "List addresses holding a dune")]
+  Holders(holders::HoldersCommand),
 }
 
 impl DuneCommand {
@@ -26,6 +29,25 @@ impl DuneCommand {
       DuneSubcommand::List(cmd) => cmd.run(settings),
       DuneSubcommand::Info(cmd) => cmd.run(settings),
       DuneSubcommand::Balance(cmd) => cmd.run(settings),
+      DuneSubcommand::Holders(cmd) => cmd.run(settings),
     }
   }
 }
+
+fn format_pile(amount: u128, divisibility: u8, symbol: Option<char>) -> String {
+  let sym = symbol.unwrap_or('¤');
+  if divisibility == 0 {
+    return format!("{amount}\u{A0}{sym}");
+  }
+  let scale = 10u128.pow(divisibility as u32);
+  let whole = amount / scale;
+  let frac = amount % scale;
+  if frac == 0 {
+    format!("{whole}\u{A0}{sym}")
+  } else {
+    format!(
+      "{whole}.{frac:0>width$}\u{A0}{sym}",
+      width = divisibility as usize
+    )
+  }
+}
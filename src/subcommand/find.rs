@@ -2,10 +2,19 @@ use super::*;
 
 #[derive(Debug, Parser)]
 pub(crate) struct Find {
-  #[arg(help = "Find output and offset of <SAT>.")]
-  sat: Koinu,
+  #[arg(
+    required_unless_present = "content",
+    help = "Find output and offset of <SAT>."
+  )]
+  sat: Option<Koinu>,
   #[clap(help = "Find output and offset of all koinu in the range [<SAT>, <END>).")]
   end: Option<Koinu>,
+  #[arg(
+    long,
+    conflicts_with_all = ["sat", "end"],
+    help = "Find inscriptions whose decoded content matches <CONTENT>."
+  )]
+  content: Option<PathBuf>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -13,6 +22,12 @@ pub struct Output {
   pub satpoint: KoinuPoint,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentOutput {
+  pub content_hash: sha256::Hash,
+  pub inscriptions: Vec<InscriptionId>,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct FindRangeOutput {
   pub start: u64,
@@ -24,24 +39,59 @@ impl Find {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
     let index = Index::open(&settings)?;
 
+    if let Some(content) = self.content {
+      return Self::find_content(&index, &content);
+    }
+
     if !index.has_koinu_index() {
       bail!("find requires index created with `--index-koinu` flag");
     }
 
     index.update()?;
 
+    let sat = self.sat.unwrap();
+
     match self.end {
-      Some(end) => match index.find_range(self.sat, end)? {
+      Some(end) => match index.find_range(sat, end)? {
         Some(mut results) => {
           results.sort_by_key(|find_range_output| find_range_output.start);
           Ok(Some(Box::new(results)))
         }
         None => Err(anyhow!("range has not been mined as of index height")),
       },
-      None => match index.find(self.sat)? {
+      None => match index.find(sat)? {
         Some(satpoint) => Ok(Some(Box::new(Output { satpoint }))),
         None => Err(anyhow!("sat has not been mined as of index height")),
       },
     }
   }
+  fn find_content(index: &Index, path: &Path) -> SubcommandResult {
+    if !index.has_content_hash_index() {
+      bail!("find --content requires index created with `--index-content-hashes` flag");
+    }
+
+    index.update()?;
+
+    let content = fs::read(path).with_context(|| format!("io error reading {}", path.display()))?;
+
+    let content_hash = sha256::Hash::hash(&content);
+
+    let mut inscriptions = Vec::new();
+
+    for page in 0.. {
+      let (ids, more) =
+        index.get_inscription_ids_by_content_hash_paginated(content_hash, 100, page)?;
+
+      inscriptions.extend(ids);
+
+      if !more {
+        break;
+      }
+    }
+
+    Ok(Some(Box::new(ContentOutput {
+      content_hash,
+      inscriptions,
+    })))
+  }
 }
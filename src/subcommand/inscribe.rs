@@ -47,9 +47,6 @@ const CHUNK_SIZE: usize = 240;
 /// Maximum total chunk-data bytes to embed per transaction (~6 chunks).
 const MAX_PAYLOAD: usize = 1500;
 
-/// Default postage attached to the inscription output (koinu).
-const POSTAGE_DEFAULT: u64 = 100_000;
-
 #[derive(Debug, Parser)]
 #[command(
   after_help = "Exactly one of --file, --dns, or --dogemap must be provided.\n\n\
@@ -77,10 +74,10 @@ pub struct InscribeCommand {
 
   #[arg(
     long,
-    default_value = "1.0",
-    help = "Fee rate in koinu per virtual byte"
+    default_value = "0.01 doge/kB",
+    help = "Fee rate in koinu per byte, or DOGE per kB if suffixed with `doge/kB`"
   )]
-  pub fee_rate: f64,
+  pub fee_rate: FeeRate,
 
   #[arg(
     long,
@@ -99,14 +96,20 @@ pub struct InscribeCommand {
 
   #[arg(
     long,
-    help = "Koinu to attach to the inscription output (default: 100000 = 0.001 DOGE)"
+    help = "Koinu to attach to the inscription output (default: 1000000 = 0.01 DOGE)"
   )]
   pub postage: Option<u64>,
 }
 
 impl InscribeCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
-    let postage = self.postage.unwrap_or(POSTAGE_DEFAULT);
+    let postage = self.postage.unwrap_or(policy::DEFAULT_POSTAGE.to_sat());
+
+    ensure!(
+      postage >= policy::DUST_LIMIT.to_sat(),
+      "postage {postage} koinu below dust limit {} koinu",
+      policy::DUST_LIMIT.to_sat(),
+    );
     let client = settings.dogecoin_rpc_client(self.wallet.clone())?;
 
     // ── Resolve source: --file, --dns, or --dogemap ──────────────────────────
@@ -196,18 +199,29 @@ impl InscribeCommand {
     //
     // TX0 is the only tx with a large UTXO as input, so only TX0 has a change
     // output (2 outputs total).  Continuation txs have 1 output each.
+    //
+    // Outputs below the soft dust limit pay a surcharge, so fees depend on
+    // carry values and are computed from the last tx backwards.  TX0's change
+    // is assumed to be above the soft dust limit until the UTXO is selected.
 
-    let fees: Vec<u64> = (0..n_txs)
-      .map(|i| {
-        let n_out = if i == 0 { 2 } else { 1 };
-        calc_fee(script_sig_size(&segments_per_tx[i]), n_out, self.fee_rate)
-      })
-      .collect();
-
+    let mut fees = vec![0u64; n_txs];
     let mut carry = vec![0u64; n_txs];
     carry[n_txs - 1] = postage;
-    for i in (0..n_txs - 1).rev() {
-      carry[i] = carry[i + 1] + fees[i + 1];
+    for i in (0..n_txs).rev() {
+      if i < n_txs - 1 {
+        carry[i] = carry[i + 1] + fees[i + 1];
+      }
+
+      let mut outputs = vec![Amount::from_sat(carry[i])];
+      if i == 0 {
+        outputs.push(policy::SOFT_DUST_LIMIT);
+      }
+
+      fees[i] = calc_fee(
+        script_sig_size(&segments_per_tx[i]),
+        &outputs,
+        self.fee_rate,
+      );
     }
 
     let total_fees: u64 = fees.iter().sum();
@@ -223,7 +237,18 @@ impl InscribeCommand {
 
     let (utxo_txid, utxo_vout, utxo_value, utxo_script) = select_utxo(&client, total_needed)?;
 
-    let change_amount = utxo_value.to_sat().saturating_sub(total_needed);
+    let mut change_amount = utxo_value.to_sat().saturating_sub(total_needed);
+
+    // Change below the soft dust limit pays its surcharge, and change below the
+    // dust limit would not relay, so it is left as fee.
+    if change_amount < policy::SOFT_DUST_LIMIT.to_sat() {
+      change_amount =
+        change_amount.saturating_sub(policy::soft_dust_surcharge(self.fee_rate).to_sat());
+    }
+
+    if change_amount < policy::DUST_LIMIT.to_sat() {
+      change_amount = 0;
+    }
 
     // ── Get output scripts ────────────────────────────────────────────────────
 
@@ -453,18 +478,28 @@ fn script_sig_size(segments: &[Vec<u8>]) -> usize {
 
 // ── Fee calculation ───────────────────────────────────────────────────────────
 
-/// Calculate the fee in koinu for a single transaction.
+/// Calculate the fee in koinu for a single transaction paying to P2PKH
+/// `outputs`, including soft dust surcharges.
 ///
 /// Sizes:
 /// - tx overhead  = 10 bytes (version 4 + locktime 4 + 1-byte varint × 2)
 /// - per input    = 32 (txid) + 4 (vout) + varint(scriptSig_len) + scriptSig_len + 4 (seq)
 /// - per output   = 8 (value) + 1 (varint) + 25 (P2PKH script)
-fn calc_fee(script_sig_bytes: usize, n_outputs: usize, fee_rate: f64) -> u64 {
+fn calc_fee(script_sig_bytes: usize, outputs: &[Amount], fee_rate: FeeRate) -> u64 {
   let script_varint = if script_sig_bytes < 0xfd { 1usize } else { 3 };
   let input_size = 32 + 4 + script_varint + script_sig_bytes + 4;
-  let output_size = n_outputs * (8 + 1 + 25);
+  let output_size = outputs.len() * (8 + 1 + 25);
   let total = 10 + input_size + output_size;
-  (total as f64 * fee_rate).ceil() as u64
+
+  let outputs = outputs
+    .iter()
+    .map(|value| TxOut {
+      value: *value,
+      script_pubkey: bitcoin::ScriptBuf::new_p2pkh(&bitcoin::PubkeyHash::all_zeros()),
+    })
+    .collect::<Vec<TxOut>>();
+
+  policy::fee(fee_rate, total, &outputs).to_sat()
 }
 
 // ── UTXO selection ────────────────────────────────────────────────────────────
@@ -631,3 +666,20 @@ fn detect_mime(path: &Path, data: &[u8]) -> String {
     .essence_str()
     .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn calc_fee_charges_soft_dust_outputs() {
+    let fee_rate = FeeRate::from_per_kb(policy::MIN_FEE_PER_KB);
+
+    // 10 + (32 + 4 + 1 + 100 + 4) + (8 + 1 + 25) = 185 bytes
+    assert_eq!(calc_fee(100, &[policy::SOFT_DUST_LIMIT], fee_rate), 185_000);
+    assert_eq!(
+      calc_fee(100, &[policy::DEFAULT_POSTAGE], fee_rate),
+      1_185_000
+    );
+  }
+}
@@ -0,0 +1,124 @@
+use {super::*, serde::de::DeserializeOwned};
+
+/// JSON client for the `/r/*` API of a running `dog server`, used by
+/// read-only subcommands instead of opening the index when `--server-url` is
+/// set, since the server holds the index database open.
+pub(crate) struct Remote {
+  client: reqwest::blocking::Client,
+  url: Url,
+}
+
+impl Remote {
+  pub(crate) fn new(settings: &Settings) -> Result<Option<Self>> {
+    let Some(url) = settings.remote_url() else {
+      return Ok(None);
+    };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+      reqwest::header::ACCEPT,
+      reqwest::header::HeaderValue::from_static("application/json"),
+    );
+
+    if let Some((username, password)) = settings.credentials() {
+      let credentials = base64_encode(format!("{username}:{password}").as_bytes());
+      headers.insert(
+        reqwest::header::AUTHORIZATION,
+        reqwest::header::HeaderValue::from_str(&format!("Basic {credentials}"))?,
+      );
+    }
+
+    Ok(Some(Self {
+      client: reqwest::blocking::ClientBuilder::new()
+        .default_headers(headers)
+        .build()?,
+      url: url.parse().context("invalid server URL")?,
+    }))
+  }
+
+  /// URL of the endpoint at `segments` below the server URL, keeping any
+  /// base path and percent-encoding each segment.
+  fn endpoint(&self, segments: &[&str]) -> Result<Url> {
+    let mut url = self.url.clone();
+
+    url
+      .path_segments_mut()
+      .map_err(|()| anyhow!("invalid server URL {}", self.url))?
+      .pop_if_empty()
+      .extend(segments);
+
+    Ok(url)
+  }
+
+  /// Returns `None` if the server reports that the resource was not found.
+  /// The server responds to unknown routes with an empty 404, which is an
+  /// error, since it means the server does not support the query.
+  pub(crate) fn get<T: DeserializeOwned>(&self, segments: &[&str]) -> Result<Option<T>> {
+    let url = self.endpoint(segments)?;
+
+    let response = self
+      .client
+      .get(url.clone())
+      .send()
+      .with_context(|| format!("failed to query {url}"))?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+      if response.text()?.is_empty() {
+        bail!("{url} not found, server does not support this query");
+      }
+
+      return Ok(None);
+    }
+
+    if !response.status().is_success() {
+      bail!("{url} returned {}: {}", response.status(), response.text()?);
+    }
+
+    Ok(Some(serde_json::from_str(&response.text()?).with_context(
+      || format!("failed to deserialize response from {url}"),
+    )?))
+  }
+
+  pub(crate) fn status(&self) -> Result<api::Status> {
+    self
+      .get(&["status"])?
+      .ok_or_else(|| anyhow!("could not get status from {}", self.url))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn endpoint_keeps_base_path_and_escapes_segments() {
+    let remote = |url: &str| Remote {
+      client: reqwest::blocking::Client::new(),
+      url: url.parse().unwrap(),
+    };
+
+    assert_eq!(
+      remote("http://127.0.0.1:8080/dog/")
+        .endpoint(&["r", "dns", "name", "such wow/#?.doge"])
+        .unwrap()
+        .as_str(),
+      "http://127.0.0.1:8080/dog/r/dns/name/such%20wow%2F%23%3F.doge",
+    );
+
+    assert_eq!(
+      remote("http://127.0.0.1:8080/dog")
+        .endpoint(&["status"])
+        .unwrap()
+        .as_str(),
+      "http://127.0.0.1:8080/dog/status",
+    );
+
+    assert_eq!(
+      remote("http://127.0.0.1:8080")
+        .endpoint(&["status"])
+        .unwrap()
+        .as_str(),
+      "http://127.0.0.1:8080/status",
+    );
+  }
+}
@@ -20,7 +20,7 @@
 
 use {
   super::*,
-  crate::index::updater::blk_reader::BlkReader,
+  crate::index::updater::{auxpow::DogecoinBlock, blk_reader::BlkReader},
   std::{collections::HashMap, collections::HashSet, fs, io::Write, path::PathBuf},
 };
 
@@ -75,7 +75,7 @@ impl ScanCommand {
 
     let reader = settings.dogecoin_blocks_dir().and_then(|dir| {
       let index_copy_dir = settings.dogecoin_blk_index_copy_dir()?;
-      BlkReader::open(&dir, &index_copy_dir).ok().flatten()
+      BlkReader::open(&dir, &index_copy_dir, chain).ok().flatten()
     });
 
     if reader.is_none() {
@@ -571,51 +571,19 @@ fn emit_inscription(
 /// Get a block by height via RPC.
 ///
 /// Dogecoin blocks after height 371,337 carry an AuxPoW header that the
-/// `bitcoin` crate cannot deserialize. We work around this by fetching the
-/// list of txids first (`getblock … 1`), then pulling each transaction
-/// individually via `getrawtransaction`. Transactions are plain legacy format
-/// (no AuxPoW), so they deserialize fine.
-fn fetch_block_rpc(client: &bitcoincore_rpc::Client, height: u32) -> crate::Result<bitcoin::Block> {
-  use {bitcoin::consensus::deserialize, bitcoincore_rpc::RpcApi, serde::Deserialize};
+/// `bitcoin` crate cannot deserialize, so the raw block (`getblock … 0`) is
+/// parsed with [`DogecoinBlock::parse`], which understands the AuxPoW section.
+fn fetch_block_rpc(client: &bitcoincore_rpc::Client, height: u32) -> crate::Result<DogecoinBlock> {
+  use bitcoincore_rpc::RpcApi;
 
   let hash = client.get_block_hash(height.into())?;
 
-  // Only decode the txid list — avoids AuxPoW deserialization of the header.
-  #[derive(Deserialize)]
-  struct BlockTxids {
-    tx: Vec<bitcoin::Txid>,
-  }
-  let info: BlockTxids = client.call(
+  let hex: String = client.call(
     "getblock",
-    &[serde_json::to_value(hash)?, serde_json::Value::from(1u8)],
+    &[serde_json::to_value(hash)?, serde_json::Value::from(0u8)],
   )?;
 
-  // Fetch and deserialize each transaction individually.
-  let txdata = info
-    .tx
-    .iter()
-    .map(|txid| {
-      let hex: String = client.call(
-        "getrawtransaction",
-        &[serde_json::to_value(txid)?, serde_json::Value::from(false)],
-      )?;
-      let bytes = hex::decode(hex.trim())?;
-      deserialize::<bitcoin::Transaction>(&bytes).map_err(|e| anyhow::anyhow!(e))
-    })
-    .collect::<crate::Result<Vec<_>>>()?;
-
-  // Construct a minimal Block — scan only uses `txdata`.
-  Ok(bitcoin::Block {
-    header: bitcoin::block::Header {
-      version: bitcoin::block::Version::ONE,
-      prev_blockhash: bitcoin::BlockHash::all_zeros(),
-      merkle_root: bitcoin::TxMerkleNode::all_zeros(),
-      time: 0,
-      bits: bitcoin::CompactTarget::from_consensus(0),
-      nonce: 0,
-    },
-    txdata,
-  })
+  DogecoinBlock::parse(&hex::decode(hex.trim())?)
 }
 
 /// Map a MIME content-type to a file extension for the exported content file.
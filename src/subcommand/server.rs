@@ -1,17 +1,21 @@
 use {
   self::{
     accept_encoding::AcceptEncoding,
+    accept_html::AcceptHtml,
     accept_json::AcceptJson,
     error::{OptionExt, ServerError, ServerResult},
   },
   super::*,
+  crate::subcommand::dns::is_valid_dns_namespace,
   crate::templates::{
-    AddressHtml, BlockHtml, BlocksHtml, ChildrenHtml, ClockSvg, CollectionsHtml, DuneHtml,
-    DuneNotFoundHtml, DunesHtml, GalleriesHtml, GalleryHtml, HomeHtml, InputHtml, InscriptionHtml,
+    AddressHtml, BlockHtml, BlocksHtml, ChildrenHtml, ClockSvg, CollectionsHtml, DnsHtml,
+    DnsNameHtml, DogemapHtml, DogemapsHtml, Drc20Html, Drc20TokensHtml, DuneHtml, DuneNotFoundHtml,
+    DunesHtml, GalleriesHtml, GalleryHtml, HomeHtml, InputHtml, InscriptionHtml,
     InscriptionsBlockHtml, InscriptionsHtml, ItemHtml, KoinuHtml, KoinuRelicsHtml, KoinucardHtml,
     MonitorHtml, OutputHtml, PageContent, PageHtml, ParentsHtml, PreviewAudioHtml, PreviewCodeHtml,
     PreviewFontHtml, PreviewImageHtml, PreviewMarkdownHtml, PreviewModelHtml, PreviewPdfHtml,
-    PreviewTextHtml, PreviewUnknownHtml, PreviewVideoHtml, RareTxt, TransactionHtml,
+    PreviewTextHtml, PreviewUnknownHtml, PreviewVideoHtml, RareTxt, TextSearchHtml,
+    TransactionHtml,
   },
   axum::{
     Router,
@@ -40,6 +44,7 @@ use {
 pub use server_config::ServerConfig;
 
 mod accept_encoding;
+mod accept_html;
 mod accept_json;
 mod error;
 pub mod query;
@@ -78,6 +83,21 @@ struct Search {
   query: String,
 }
 
+#[derive(Deserialize)]
+struct SearchQuery {
+  #[serde(alias = "q")]
+  query: String,
+  #[serde(rename = "type")]
+  ty: Option<SearchType>,
+  page: Option<usize>,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum SearchType {
+  Text,
+}
+
 #[derive(RustEmbed)]
 #[folder = "static"]
 struct StaticAssets;
@@ -267,9 +287,26 @@ impl Server {
         )
         .route("/preview/{inscription_id}", get(Self::preview))
         .route("/rare.txt", get(Self::rare_txt))
+        .route("/dns", get(Self::dns))
+        .route("/dns/page/{page}", get(Self::dns_paginated))
+        .route("/dns/{name}", get(Self::dns_name))
         .route("/dogemap/{block}", get(Self::dogemap))
+        .route("/dogemap/{block}/svg", get(Self::dogemap_image))
         .route("/dogemaps", get(Self::dogemaps))
+        .route("/dogemaps/{page}", get(Self::dogemaps_paginated))
+        .route("/drc20", get(Self::drc20_tokens))
+        .route("/drc20/{tick}", get(Self::drc20_token))
         .route("/dune/{dune}", get(Self::dune))
+        .route("/dune/{dune}/activity", get(Self::dune_activity))
+        .route(
+          "/dune/{dune}/activity/{page}",
+          get(Self::dune_activity_paginated),
+        )
+        .route("/dune/{dune}/holders", get(Self::dune_holders))
+        .route(
+          "/dune/{dune}/holders/{page}",
+          get(Self::dune_holders_paginated),
+        )
         .route("/dunes", get(Self::dunes))
         .route("/dunes/{page}", get(Self::dunes_paginated))
         .route("/koinu/{sat}", get(Self::sat))
@@ -295,6 +332,19 @@ impl Server {
         .route("/blockhash/{height}", get(r::block_hash_from_height_string))
         .route("/blockheight", get(r::blockheight_string))
         .route("/blocktime", get(r::blocktime_string))
+        .route(
+          "/r/address/{address}/inscriptions",
+          get(r::address_inscriptions),
+        )
+        .route(
+          "/r/address/{address}/inscriptions/{page}",
+          get(r::address_inscriptions_paginated),
+        )
+        .route("/r/address/{address}/portfolio", get(r::portfolio))
+        .route(
+          "/r/address/{address}/portfolio/{page}",
+          get(r::portfolio_paginated),
+        )
         .route("/r/blockhash", get(r::blockhash))
         .route("/r/blockhash/{height}", get(r::blockhash_at_height))
         .route("/r/blockheight", get(r::blockheight_string))
@@ -308,6 +358,35 @@ impl Server {
           "/r/children/{inscription_id}/inscriptions/{page}",
           get(r::children_inscriptions_paginated),
         )
+        .route(
+          "/r/collection/{inscription_id}/holders",
+          get(r::collection_holders),
+        )
+        .route(
+          "/r/collection/{inscription_id}/holders/{page}",
+          get(r::collection_holders_paginated),
+        )
+        .route(
+          "/r/collection/{inscription_id}/stats",
+          get(r::collection_stats),
+        )
+        .route(
+          "/r/collection/{inscription_id}/traits",
+          get(r::collection_traits),
+        )
+        .route(
+          "/r/collection/{inscription_id}/traits/{name}/{value}",
+          get(r::collection_trait_inscriptions),
+        )
+        .route(
+          "/r/collection/{inscription_id}/traits/{name}/{value}/{page}",
+          get(r::collection_trait_inscriptions_paginated),
+        )
+        .route("/r/content-hash/{content_hash}", get(r::content_hash))
+        .route(
+          "/r/content-hash/{content_hash}/{page}",
+          get(r::content_hash_paginated),
+        )
         .route("/r/parents/{inscription_id}", get(r::parents))
         .route(
           "/r/parents/{inscription_id}/{page}",
@@ -321,6 +400,7 @@ impl Server {
           "/r/parents/{inscription_id}/inscriptions/{page}",
           get(r::parent_inscriptions_paginated),
         )
+        .route("/r/koinu/stats", get(r::koinu_stats))
         .route("/r/sat/{sat_number}", get(r::sat))
         .route("/r/sat/{sat_number}/{page}", get(r::sat_paginated))
         .route("/r/tx/{txid}", get(r::tx))
@@ -330,15 +410,27 @@ impl Server {
           get(r::undelegated_content),
         )
         .route("/r/utxo/{outpoint}", get(r::utxo))
+        .route("/r/dune/{dune}/mints/{start}/{end}", get(r::dune_mints))
+        .route("/r/dunes/mintable", get(r::mintable_dunes))
         .route("/r/drc20/tokens", get(r::drc20_tokens))
         .route("/r/drc20/token/{tick}", get(r::drc20_token))
         .route("/r/drc20/balance/{address}", get(r::drc20_balances))
         .route("/r/drc20/balance/{address}/{tick}", get(r::drc20_balance))
+        .route("/r/drc20/transfer/{outpoint}", get(r::drc20_transfer))
         .route("/r/dns/name/{name}", get(r::dns_name))
         .route("/r/dns/namespace/{namespace}", get(r::dns_namespace))
         .route("/r/dns/stats", get(r::dns_stats))
         .route("/r/dogemap/block/{block_number}", get(r::dogemap_claim))
+        .route(
+          "/r/dogemap/block/{block_number}/parcels",
+          get(r::dogemap_parcels),
+        )
         .route("/r/dogemap/list", get(r::dogemap_list))
+        .route("/r/dogemap/owner/{address}", get(r::dogemap_owner))
+        .route(
+          "/r/dogemap/transfers/{block_number}",
+          get(r::dogemap_transfers),
+        )
         .route("/r/dogemap/count", get(r::dogemap_count));
 
       let proxiable_routes = Router::new()
@@ -723,6 +815,9 @@ impl Server {
            inscriptions,
            sat_balance,
            dunes_balances,
+           drc20_balances,
+           names,
+           dogemaps,
            lazy_lookup,
          }| AddressHtml {
           address: koinucard.address.to_string(),
@@ -730,8 +825,11 @@ impl Server {
           inscriptions,
           outputs,
           dunes_balances,
+          drc20_balances,
+          names,
+          dogemaps,
           sat_balance,
-          lazy_lookup: lazy_lookup.unwrap_or(false),
+          lazy_lookup,
         },
       );
 
@@ -765,7 +863,7 @@ impl Server {
       });
       let blocktime = index.block_time(sat.height())?;
 
-      let charms = sat.charms();
+      let charms = sat.charms(server_config.chain.charm_heights());
 
       let address = if let Some(satpoint) = satpoint {
         if satpoint.outpoint == unbound_outpoint() {
@@ -813,6 +911,7 @@ impl Server {
         KoinuHtml {
           address,
           blocktime,
+          charms,
           inscriptions,
           sat,
           satpoint,
@@ -991,6 +1090,103 @@ impl Server {
     task::block_in_place(|| Ok(RareTxt(index.rare_sat_satpoints()?)))
   }
 
+  async fn dns(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+  ) -> ServerResult<PageHtml<DnsHtml>> {
+    Self::dns_paginated(Extension(server_config), Extension(index), Path(0)).await
+  }
+
+  async fn dns_paginated(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(page_index): Path<usize>,
+  ) -> ServerResult<PageHtml<DnsHtml>> {
+    const PAGE_SIZE: usize = 100;
+
+    task::block_in_place(|| {
+      let mut names = index.get_dns_names(page_index * PAGE_SIZE, PAGE_SIZE + 1)?;
+
+      let more = names.len() > PAGE_SIZE;
+      names.truncate(PAGE_SIZE);
+
+      let mut namespaces: Vec<(String, Vec<String>)> = Vec::new();
+
+      for (namespace, name) in names {
+        match namespaces.last_mut() {
+          Some((last, names)) if *last == namespace => names.push(name),
+          _ => namespaces.push((namespace, vec![name])),
+        }
+      }
+
+      Ok(
+        DnsHtml {
+          total: index.count_dns_names()?,
+          namespaces,
+          prev: page_index.checked_sub(1),
+          next: more.then_some(page_index + 1),
+        }
+        .page(server_config),
+      )
+    })
+  }
+
+  async fn dns_name(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(name): Path<String>,
+  ) -> ServerResult<PageHtml<DnsNameHtml>> {
+    task::block_in_place(|| {
+      let entry = index
+        .get_dns_name(&name)?
+        .ok_or_not_found(|| format!("name {name}"))?;
+
+      Ok(DnsNameHtml { entry }.page(server_config))
+    })
+  }
+
+  async fn drc20_tokens(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+  ) -> ServerResult<PageHtml<Drc20TokensHtml>> {
+    task::block_in_place(|| {
+      let mut tokens = index.get_drc20_tokens()?;
+      tokens.sort_by(|a, b| {
+        b.deploy_height
+          .cmp(&a.deploy_height)
+          .then_with(|| a.tick.cmp(&b.tick))
+      });
+
+      Ok(Drc20TokensHtml { tokens }.page(server_config))
+    })
+  }
+
+  async fn drc20_token(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(tick): Path<String>,
+  ) -> ServerResult<PageHtml<Drc20Html>> {
+    task::block_in_place(|| {
+      let token = index
+        .get_drc20_token(&tick)?
+        .ok_or_not_found(|| format!("DRC-20 token {tick}"))?;
+
+      let mut holders = index.get_drc20_holders(&tick)?;
+      let holder_count = holders.len();
+      holders.truncate(100);
+
+      Ok(
+        Drc20Html {
+          activity: index.get_drc20_activity(&tick, 50)?,
+          holder_count,
+          holders,
+          token,
+        }
+        .page(server_config),
+      )
+    })
+  }
+
   async fn dune(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -1004,19 +1200,11 @@ impl Server {
         ));
       }
 
-      let dune = match dune_query {
-        query::Dune::Spaced(spaced_dune) => spaced_dune.dune,
-        query::Dune::Id(dune_id) => index
-          .get_dune_by_id(dune_id)?
-          .ok_or_not_found(|| format!("dune {dune_id}"))?,
-        query::Dune::Number(number) => index
-          .get_dune_by_number(usize::try_from(number).unwrap())?
-          .ok_or_not_found(|| format!("dune number {number}"))?,
-      };
+      let dune = Self::resolve_dune(&index, dune_query)?;
 
       let Some((id, entry, parent)) = index.dune(dune)? else {
-        return Ok(if accept_json {
-          StatusCode::NOT_FOUND.into_response()
+        return if accept_json {
+          Err(ServerError::NotFound(format!("dune {dune} not found")))
         } else {
           let unlock = if let Some(height) = dune.unlock_height(server_config.chain.network()) {
             Some((height, index.block_time(height)?))
@@ -1024,12 +1212,14 @@ impl Server {
             None
           };
 
-          (
-            StatusCode::NOT_FOUND,
-            DuneNotFoundHtml { dune, unlock }.page(server_config),
+          Ok(
+            (
+              StatusCode::NOT_FOUND,
+              DuneNotFoundHtml { dune, unlock }.page(server_config),
+            )
+              .into_response(),
           )
-            .into_response()
-        });
+        };
       };
 
       let block_height = index.block_height()?.unwrap_or(Height(0));
@@ -1057,28 +1247,147 @@ impl Server {
     })
   }
 
+  fn resolve_dune(index: &Index, query: query::Dune) -> ServerResult<Dune> {
+    Ok(match query {
+      query::Dune::Spaced(spaced_dune) => spaced_dune.dune,
+      query::Dune::Id(dune_id) => index
+        .get_dune_by_id(dune_id)?
+        .ok_or_not_found(|| format!("dune {dune_id}"))?,
+      query::Dune::Number(number) => index
+        .get_dune_by_number(usize::try_from(number).unwrap())?
+        .ok_or_not_found(|| format!("dune number {number}"))?,
+    })
+  }
+
+  async fn dune_activity(
+    Extension(index): Extension<Arc<Index>>,
+    Path(dune_query): Path<DeserializeFromStr<query::Dune>>,
+  ) -> ServerResult<Json<api::DuneActivities>> {
+    Self::dune_activity_paginated(Extension(index), Path((dune_query, 0))).await
+  }
+
+  async fn dune_activity_paginated(
+    Extension(index): Extension<Arc<Index>>,
+    Path((DeserializeFromStr(dune_query), page)): Path<(DeserializeFromStr<query::Dune>, usize)>,
+  ) -> ServerResult<Json<api::DuneActivities>> {
+    task::block_in_place(|| {
+      if !index.has_dune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no dune index".to_string(),
+        ));
+      }
+
+      let dune = Self::resolve_dune(&index, dune_query)?;
+
+      let (id, ..) = index
+        .dune(dune)?
+        .ok_or_not_found(|| format!("dune {dune}"))?;
+
+      let (activity, more) = index.get_dune_activity_paginated(id, 100, page)?;
+
+      Ok(Json(api::DuneActivities {
+        activity,
+        more,
+        page,
+      }))
+    })
+  }
+
+  async fn dune_holders(
+    Extension(index): Extension<Arc<Index>>,
+    Path(dune_query): Path<DeserializeFromStr<query::Dune>>,
+  ) -> ServerResult<Json<api::DuneHolders>> {
+    Self::dune_holders_paginated(Extension(index), Path((dune_query, 0))).await
+  }
+
+  async fn dune_holders_paginated(
+    Extension(index): Extension<Arc<Index>>,
+    Path((DeserializeFromStr(dune_query), page)): Path<(DeserializeFromStr<query::Dune>, usize)>,
+  ) -> ServerResult<Json<api::DuneHolders>> {
+    task::block_in_place(|| {
+      if !index.has_dune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no dune index".to_string(),
+        ));
+      }
+
+      if !index.has_address_index() {
+        return Err(ServerError::NotFound(
+          "this server has no address index".to_string(),
+        ));
+      }
+
+      let dune = Self::resolve_dune(&index, dune_query)?;
+
+      let (id, ..) = index
+        .dune(dune)?
+        .ok_or_not_found(|| format!("dune {dune}"))?;
+
+      let mut holders = index
+        .get_dune_holders(id)?
+        .into_iter()
+        .skip(page.saturating_mul(100))
+        .take(101)
+        .collect::<Vec<api::DuneHolder>>();
+
+      let more = holders.len() > 100;
+
+      if more {
+        holders.pop();
+      }
+
+      Ok(Json(api::DuneHolders {
+        holders,
+        more,
+        page,
+      }))
+    })
+  }
+
   async fn dogemap(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(block_number): Path<u32>,
+    AcceptHtml(accept_html): AcceptHtml,
   ) -> ServerResult {
     task::block_in_place(|| {
       let claim = index.get_dogemap_claim(block_number)?;
+      let owner = index.get_dogemap_owner(block_number)?;
+      let transfers = index.get_dogemap_transfers(block_number)?;
       let block_opt = index.get_block_by_height(block_number)?;
 
-      let (tx_count, block_hash_hex) = if let Some(ref block) = block_opt {
-        (block.txdata.len(), block.block_hash().to_string())
-      } else {
-        (0, String::new())
-      };
+      let tx_count = block_opt.as_ref().map_or(0, |block| block.txdata.len());
+      let block_hash = block_opt.as_ref().map(|block| block.block_hash());
 
-      let hash_bytes: Vec<u8> = block_opt
-        .as_ref()
-        .map(|b| b.block_hash().to_byte_array().to_vec())
+      let rarity = Self::dogemap_rarity(block_number);
+
+      if accept_html {
+        return Ok(
+          DogemapHtml {
+            block_number,
+            rarity,
+            block_hash,
+            tx_count,
+            parcels: if claim.is_some() {
+              index.get_dogemap_parcels(block_number)?
+            } else {
+              Vec::new()
+            },
+            claim,
+            owner,
+            transfers,
+          }
+          .page(server_config)
+          .into_response(),
+        );
+      }
+
+      let hash_bytes: Vec<u8> = block_hash
+        .map(|hash| hash.to_byte_array().to_vec())
         .unwrap_or_default();
 
       let svg = Self::dogemap_svg(block_number, &hash_bytes, tx_count);
 
-      let rarity = Self::dogemap_rarity(block_number);
       let metaverse = Self::dogemap_metaverse(&hash_bytes, tx_count, block_number);
 
       Ok(Self::live_json(serde_json::json!({
@@ -1088,7 +1397,9 @@ impl Server {
         "owner_inscription_id": claim.as_ref().map(|e| e.owner_inscription_id.to_string()),
         "claim_height": claim.as_ref().map(|e| e.claim_height),
         "claim_timestamp": claim.as_ref().map(|e| e.claim_timestamp),
-        "block_hash": block_hash_hex,
+        "owner": owner,
+        "transfers": transfers,
+        "block_hash": block_hash.map(|hash| hash.to_string()).unwrap_or_default(),
         "tx_count": tx_count,
         "svg": svg,
         "metaverse": metaverse,
@@ -1096,24 +1407,94 @@ impl Server {
     })
   }
 
-  async fn dogemaps(Extension(index): Extension<Arc<Index>>) -> ServerResult {
+  async fn dogemap_image(
+    Extension(index): Extension<Arc<Index>>,
+    Path(block_number): Path<u32>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      let block = index
+        .get_block_by_height(block_number)?
+        .ok_or_not_found(|| format!("block {block_number}"))?;
+
+      Ok(
+        (
+          [
+            (header::CONTENT_TYPE, "image/svg+xml"),
+            (header::CACHE_CONTROL, "public, max-age=3600"),
+          ],
+          Self::dogemap_svg(
+            block_number,
+            &block.block_hash().to_byte_array(),
+            block.txdata.len(),
+          ),
+        )
+          .into_response(),
+      )
+    })
+  }
+
+  async fn dogemaps(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    accept_html: AcceptHtml,
+  ) -> ServerResult {
+    Self::dogemaps_paginated(
+      Extension(server_config),
+      Extension(index),
+      Path(0),
+      accept_html,
+    )
+    .await
+  }
+
+  async fn dogemaps_paginated(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(page_index): Path<usize>,
+    AcceptHtml(accept_html): AcceptHtml,
+  ) -> ServerResult {
+    const PAGE_SIZE: usize = 100;
+
     task::block_in_place(|| {
-      let entries = index.list_dogemaps(100, 0)?;
+      let mut entries = index.list_dogemaps(PAGE_SIZE + 1, page_index * PAGE_SIZE)?;
       let total = index.count_dogemaps()?;
-      let claims: Vec<serde_json::Value> = entries
+
+      let more = entries.len() > PAGE_SIZE;
+      entries.truncate(PAGE_SIZE);
+
+      let entries = entries
         .into_iter()
-        .map(|e| {
-          serde_json::json!({
-            "block_number": e.block_number,
-            "owner_inscription_id": e.owner_inscription_id.to_string(),
-            "claim_height": e.claim_height,
-            "claim_timestamp": e.claim_timestamp,
-          })
+        .map(|entry| {
+          let owner = index.get_dogemap_owner(entry.block_number)?;
+          Ok((entry, owner))
         })
-        .collect();
-      Ok(Self::live_json(
-        serde_json::json!({ "total": total, "claims": claims }),
-      ))
+        .collect::<Result<Vec<(crate::index::DogemapEntry, Option<String>)>>>()?;
+
+      Ok(if !accept_html {
+        let claims = entries
+          .into_iter()
+          .map(|(e, owner)| {
+            serde_json::json!({
+              "block_number": e.block_number,
+              "owner_inscription_id": e.owner_inscription_id.to_string(),
+              "claim_height": e.claim_height,
+              "claim_timestamp": e.claim_timestamp,
+              "owner": owner,
+            })
+          })
+          .collect::<Vec<serde_json::Value>>();
+
+        Self::live_json(serde_json::json!({ "total": total, "claims": claims }))
+      } else {
+        DogemapsHtml {
+          entries,
+          total,
+          prev: page_index.checked_sub(1),
+          next: more.then_some(page_index + 1),
+        }
+        .page(server_config)
+        .into_response()
+      })
     })
   }
 
@@ -1390,6 +1771,9 @@ impl Server {
           outputs,
           inscriptions,
           dunes_balances,
+          drc20_balances,
+          names,
+          dogemaps,
           lazy_lookup,
         } = info;
 
@@ -1399,8 +1783,11 @@ impl Server {
           inscriptions,
           outputs,
           dunes_balances,
+          drc20_balances,
+          names,
+          dogemaps,
           sat_balance,
-          lazy_lookup: lazy_lookup.unwrap_or(false),
+          lazy_lookup,
         }
         .page(server_config)
         .into_response()
@@ -1409,35 +1796,91 @@ impl Server {
   }
 
   fn address_info(index: &Index, address: &Address) -> ServerResult<Option<api::AddressInfo>> {
-    if !index.has_address_index() {
+    let mut info = Self::address_holdings(index, address)?;
+
+    if let Some(address) = index
+      .chain()
+      .address_string_from_script(&address.script_pubkey())
+    {
+      info.drc20_balances = index
+        .get_drc20_balances(&address)?
+        .into_iter()
+        .map(|(tick, available, transferable)| {
+          (
+            tick,
+            api::Drc20Balance {
+              available,
+              transferable,
+            },
+          )
+        })
+        .collect();
+
+      info.dogemaps = index.get_dogemaps_by_owner(&address)?;
+
+      if index.has_inscription_index() {
+        info.inscriptions = Some(index.get_inscription_ids_by_address(&address)?);
+      }
+    }
+
+    if let Some(inscriptions) = &info.inscriptions {
+      info.names = index.get_dns_names_for_inscriptions(inscriptions)?;
+    }
+
+    Ok(Some(info))
+  }
+
+  /// Outputs held by `address` and the koinus, inscriptions and dunes on
+  /// them, without the DRC-20 balances, names and dogemaps tracked by address.
+  fn address_holdings(index: &Index, address: &Address) -> ServerResult<api::AddressInfo> {
+    Ok(if !index.has_address_index() {
       // Lazy fallback: use RPC to scan for UTXOs without a full index
       match index.lazy_address_lookup(address) {
-        Ok(info) => return Ok(Some(info)),
+        Ok(info) => info,
+        // Inscription holdings are tracked even without an address index, so
+        // still serve those, but flag the balance and outputs as unknown
+        Err(err) if index.has_inscription_index() => {
+          log::warn!("lazy address lookup for {address} failed: {err}");
+
+          api::AddressInfo {
+            outputs: Vec::new(),
+            inscriptions: None,
+            sat_balance: 0,
+            dunes_balances: None,
+            drc20_balances: BTreeMap::new(),
+            names: Vec::new(),
+            dogemaps: Vec::new(),
+            lazy_lookup: Some(false),
+          }
+        }
         Err(err) => {
           return Err(ServerError::Internal(
             err.context("lazy address lookup failed"),
           ));
         }
       }
-    }
-
-    let mut outputs = index.get_address_info(address)?;
+    } else {
+      let mut outputs = index.get_address_info(address)?;
 
-    outputs.sort();
+      outputs.sort();
 
-    let sat_balance = index.get_sat_balances_for_outputs(&outputs)?;
+      let sat_balance = index.get_sat_balances_for_outputs(&outputs)?;
 
-    let inscriptions = index.get_inscriptions_for_outputs(&outputs)?;
+      let inscriptions = index.get_inscriptions_for_outputs(&outputs)?;
 
-    let dunes_balances = index.get_aggregated_dune_balances_for_outputs(&outputs)?;
+      let dunes_balances = index.get_aggregated_dune_balances_for_outputs(&outputs)?;
 
-    Ok(Some(api::AddressInfo {
-      sat_balance,
-      outputs,
-      inscriptions,
-      dunes_balances,
-      lazy_lookup: None,
-    }))
+      api::AddressInfo {
+        sat_balance,
+        outputs,
+        inscriptions,
+        dunes_balances,
+        drc20_balances: BTreeMap::new(),
+        names: Vec::new(),
+        dogemaps: Vec::new(),
+        lazy_lookup: None,
+      }
+    })
   }
 
   async fn block(
@@ -1768,10 +2211,24 @@ impl Server {
   }
 
   async fn search_by_query(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Query(search): Query<Search>,
-  ) -> ServerResult<Redirect> {
-    Self::search(index, search.query).await
+    Query(search): Query<SearchQuery>,
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
+    match search.ty {
+      Some(SearchType::Text) => {
+        Self::search_text(
+          server_config,
+          index,
+          search.query,
+          search.page.unwrap_or_default(),
+          accept_json,
+        )
+        .await
+      }
+      None => Ok(Self::search(index, search.query).await?.into_response()),
+    }
   }
 
   async fn search_by_path(
@@ -1802,6 +2259,15 @@ impl Server {
         )))
       } else if let Some(captures) = re::ORDINALS_SATSCARD_URL.captures(query) {
         Ok(Redirect::to(&format!("/koinucard?{}", &captures["query"])))
+      } else if let Some(captures) = re::DOGEMAP.captures(query) {
+        Ok(Redirect::to(&format!("/dogemap/{}", &captures["block"])))
+      } else if re::DNS_NAME
+        .captures(query)
+        .is_some_and(|captures| is_valid_dns_namespace(&captures["namespace"]))
+      {
+        Ok(Redirect::to(&format!("/dns/{query}")))
+      } else if query.len() == 4 && index.get_drc20_token(query)?.is_some() {
+        Ok(Redirect::to(&format!("/drc20/{query}")))
       } else if re::SPACED_DUNE.is_match(query) {
         Ok(Redirect::to(&format!("/dune/{query}")))
       } else if re::DUNE_ID.is_match(query) {
@@ -1822,20 +2288,60 @@ impl Server {
     })
   }
 
-  async fn favicon() -> ServerResult {
-    Ok(
-      Self::static_asset(Path("/favicon.png".to_string()))
-        .await
-        .into_response(),
-    )
-  }
-
-  async fn feed(
-    Extension(server_config): Extension<Arc<ServerConfig>>,
-    Extension(index): Extension<Arc<Index>>,
+  async fn search_text(
+    server_config: Arc<ServerConfig>,
+    index: Arc<Index>,
+    query: String,
+    page: usize,
+    accept_json: bool,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let mut builder = rss::ChannelBuilder::default();
+      if !index.has_text_index() {
+        return Err(ServerError::NotFound(
+          "this server has no text index".into(),
+        ));
+      }
+
+      let (inscriptions, more) = index.search_text(&query, 100, page)?;
+
+      let prev = page.checked_sub(1);
+
+      let next = more.then_some(page + 1);
+
+      Ok(if accept_json {
+        Json(api::TextSearch {
+          ids: inscriptions,
+          more,
+          page,
+        })
+        .into_response()
+      } else {
+        TextSearchHtml {
+          query,
+          inscriptions,
+          prev,
+          next,
+        }
+        .page(server_config)
+        .into_response()
+      })
+    })
+  }
+
+  async fn favicon() -> ServerResult {
+    Ok(
+      Self::static_asset(Path("/favicon.png".to_string()))
+        .await
+        .into_response(),
+    )
+  }
+
+  async fn feed(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      let mut builder = rss::ChannelBuilder::default();
 
       let chain = server_config.chain;
       match chain {
@@ -3326,6 +3832,64 @@ mod tests {
     TestServer::new().assert_redirect("/search/AB•CD", "/dune/AB•CD");
   }
 
+  #[test]
+  fn search_for_dogemap_returns_dogemap() {
+    TestServer::new().assert_redirect("/search/5056597.dogemap", "/dogemap/5056597");
+  }
+
+  #[test]
+  fn search_for_dogemap_parcel_returns_dogemap() {
+    TestServer::new().assert_redirect("/search/3.5056597.dogemap", "/dogemap/5056597");
+  }
+
+  #[test]
+  fn dogemap_serves_json_unless_html_is_requested() {
+    let server = TestServer::builder().chain(Chain::DogecoinRegtest).build();
+
+    for path in ["/dogemap/0", "/dogemaps"] {
+      let response = server.get(path);
+      assert_eq!(response.status(), StatusCode::OK);
+      assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json",
+      );
+
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url(path))
+        .header(header::ACCEPT, "text/html,application/xhtml+xml")
+        .send()
+        .unwrap();
+      assert_eq!(response.status(), StatusCode::OK);
+      assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/html;charset=utf-8",
+      );
+    }
+  }
+
+  #[test]
+  fn dns_is_paginated() {
+    let server = TestServer::builder().chain(Chain::DogecoinRegtest).build();
+
+    server.assert_response_regex("/dns", StatusCode::OK, ".*<dd>0</dd>.*prev\\s*next.*");
+
+    server.assert_response_regex(
+      "/dns/page/1",
+      StatusCode::OK,
+      ".*<a class=prev href=/dns/page/0>prev</a>\\s*next.*",
+    );
+  }
+
+  #[test]
+  fn search_for_dns_name_returns_dns_name() {
+    TestServer::new().assert_redirect("/search/jon.doge", "/dns/jon.doge");
+  }
+
+  #[test]
+  fn search_for_spaced_dune_with_dot_is_not_dns_name() {
+    TestServer::new().assert_redirect("/search/AB.CD", "/dune/AB.CD");
+  }
+
   #[test]
   fn search_by_dune_id_returns_dune() {
     let server = TestServer::builder()
@@ -3637,6 +4201,38 @@ mod tests {
     );
   }
 
+  #[test]
+  fn dune_holders_require_address_index() {
+    let server = TestServer::builder()
+      .chain(Chain::DogecoinRegtest)
+      .index_dunes()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.assert_response(
+      "/dune/AAAAAAAAAAAAA/holders",
+      StatusCode::NOT_FOUND,
+      "this server has no address index",
+    );
+  }
+
+  #[test]
+  fn dune_activity_of_unetched_dune_is_not_found() {
+    let server = TestServer::builder()
+      .chain(Chain::DogecoinRegtest)
+      .index_dunes()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.assert_response(
+      "/dune/AAAAAAAAAAAAA/activity",
+      StatusCode::NOT_FOUND,
+      "dune AAAAAAAAAAAAA not found",
+    );
+  }
+
   #[test]
   fn dunes_are_displayed_on_dunes_page() {
     let server = TestServer::builder()
@@ -4801,6 +5397,21 @@ mod tests {
     );
   }
 
+  #[test]
+  fn recursive_koinu_stats() {
+    let test_server = TestServer::new();
+
+    test_server.mine_blocks(2);
+
+    let stats = test_server.get_json::<api::KoinuStats>("/r/koinu/stats");
+
+    assert_eq!(stats.height, Some(2));
+    assert_eq!(stats.supply, Height(3).starting_sat().n());
+    assert_eq!(stats.rarity[&Rarity::Mythic], 1);
+    assert_eq!(stats.rarity[&Rarity::Uncommon], 2);
+    assert_eq!(stats.rarity.values().sum::<u64>(), stats.supply);
+  }
+
   #[test]
   fn detect_unrecoverable_reorg() {
     let test_server = TestServer::new();
@@ -9206,8 +9817,11 @@ next
               inscriptions: Some(Vec::new()),
               outputs: Vec::new(),
               dunes_balances: None,
+              drc20_balances: BTreeMap::new(),
+              names: Vec::new(),
+              dogemaps: Vec::new(),
               sat_balance: 0,
-              lazy_lookup: false,
+              lazy_lookup: None,
             }),
           )),
         },
@@ -9231,8 +9845,11 @@ next
               inscriptions: Some(Vec::new()),
               outputs: Vec::new(),
               dunes_balances: None,
+              drc20_balances: BTreeMap::new(),
+              names: Vec::new(),
+              dogemaps: Vec::new(),
               sat_balance: 0,
-              lazy_lookup: false,
+              lazy_lookup: None,
             }),
           )),
         },
@@ -9511,4 +10128,484 @@ next
       response.text().unwrap(),
     );
   }
+
+  #[test]
+  fn inscriptions_sharing_content_are_listed_by_content_hash() {
+    let server = TestServer::builder()
+      .chain(Chain::DogecoinRegtest)
+      .dog_flag("--index-content-hashes")
+      .build();
+
+    server.mine_blocks(2);
+
+    let first = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, Witness::new())],
+        script_sig: inscription("text/plain;charset=utf-8", "much same").to_script_sig(),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    let second = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 0, 0, Witness::new())],
+        script_sig: inscription("text/html;charset=utf-8", "much same").to_script_sig(),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    let content_hash = sha256::Hash::hash(b"much same");
+
+    pretty_assert_eq!(
+      server.get_json::<api::ContentHashInscriptions>(format!("/r/content-hash/{content_hash}")),
+      api::ContentHashInscriptions {
+        ids: vec![first, second],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{first}"))
+        .first_of_content,
+      Some(true),
+    );
+
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{second}"))
+        .first_of_content,
+      Some(false),
+    );
+  }
+
+  #[test]
+  fn address_inscriptions_without_address_index() {
+    let server = TestServer::builder().chain(Chain::DogecoinRegtest).build();
+
+    server.mine_blocks(1);
+
+    let recipient = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+
+    let address = Chain::DogecoinRegtest
+      .address_string_from_script(&recipient.script_pubkey())
+      .unwrap();
+
+    let id = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, Witness::new())],
+        script_sig: inscription("text/plain;charset=utf-8", "wow").to_script_sig(),
+        recipient: Some(recipient),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    pretty_assert_eq!(
+      server.get_json::<api::AddressInscriptions>(format!("/r/address/{address}/inscriptions")),
+      api::AddressInscriptions {
+        ids: vec![id],
+        more: false,
+        page: 0,
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::AddressInscriptions>(format!("/r/address/{address}/inscriptions/1")),
+      api::AddressInscriptions {
+        ids: Vec::new(),
+        more: false,
+        page: 1,
+      }
+    );
+
+    let info = server.get_json::<api::AddressInfo>(format!("/address/{address}"));
+
+    assert_eq!(info.inscriptions, Some(vec![id]));
+    assert_eq!(info.lazy_lookup, Some(false));
+
+    server.assert_response_regex(
+      format!("/address/{address}"),
+      StatusCode::OK,
+      ".*<dt>koinu balance</dt>\n  <dd>unknown</dd>.*",
+    );
+  }
+
+  #[test]
+  fn address_portfolio() {
+    let server = TestServer::builder()
+      .chain(Chain::DogecoinRegtest)
+      .index_addresses()
+      .build();
+
+    server.mine_blocks(4);
+
+    let recipient = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+
+    let address = Chain::DogecoinRegtest
+      .address_string_from_script(&recipient.script_pubkey())
+      .unwrap();
+
+    let mut txids = Vec::new();
+
+    // DRC-20 operations are indexed from witness envelopes
+    for (block, operation) in [
+      (
+        1,
+        r#"{"p":"drc-20","op":"deploy","tick":"dogi","max":"1000","lim":"100","dec":0}"#,
+      ),
+      (2, r#"{"p":"drc-20","op":"mint","tick":"dogi","amt":"60"}"#),
+    ] {
+      txids.push(server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          block,
+          0,
+          0,
+          inscription("text/plain", operation).to_witness(),
+        )],
+        recipient: Some(recipient.clone()),
+        ..default()
+      }));
+
+      server.mine_blocks(1);
+    }
+
+    let mut inscriptions = Vec::new();
+
+    for (block, body) in [(3, "1.dogemap"), (4, "jon.doge")] {
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(block, 0, 0, Witness::new())],
+        script_sig: inscription("text/plain;charset=utf-8", body).to_script_sig(),
+        recipient: Some(recipient.clone()),
+        ..default()
+      });
+
+      txids.push(txid);
+      inscriptions.push(InscriptionId { txid, index: 0 });
+
+      server.mine_blocks(1);
+    }
+
+    pretty_assert_eq!(
+      server.get_json::<api::AddressPortfolio>(format!("/r/address/{address}/portfolio")),
+      api::AddressPortfolio {
+        address: address.clone(),
+        sat_balance: txids
+          .iter()
+          .map(|txid| server.core.tx_by_id(*txid).output[0].value.to_sat())
+          .sum(),
+        inscriptions,
+        dunes_balances: Vec::new(),
+        drc20_balances: [(
+          "dogi".into(),
+          api::Drc20Balance {
+            available: 60,
+            transferable: 0,
+          },
+        )]
+        .into(),
+        names: vec!["jon.doge".into()],
+        dogemaps: vec![1],
+        more: false,
+        page: 0,
+      }
+    );
+
+    let page =
+      server.get_json::<api::AddressPortfolio>(format!("/r/address/{address}/portfolio/1"));
+
+    assert!(page.inscriptions.is_empty());
+    assert!(page.drc20_balances.is_empty());
+    assert!(page.names.is_empty());
+    assert!(page.dogemaps.is_empty());
+    assert!(!page.more);
+    assert_eq!(page.page, 1);
+  }
+
+  #[test]
+  fn collection_stats_and_holders() {
+    let server = TestServer::builder().chain(Chain::DogecoinRegtest).build();
+
+    server.mine_blocks(4);
+
+    let alice = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([1; 20]),
+      Network::Regtest,
+    );
+    let bob = Address::p2pkh(
+      bitcoin::PubkeyHash::from_byte_array([2; 20]),
+      Network::Regtest,
+    );
+
+    let alice_address = Chain::DogecoinRegtest
+      .address_string_from_script(&alice.script_pubkey())
+      .unwrap();
+    let bob_address = Chain::DogecoinRegtest
+      .address_string_from_script(&bob.script_pubkey())
+      .unwrap();
+
+    let parent = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, Witness::new())],
+        script_sig: inscription("text/plain;charset=utf-8", "parent").to_script_sig(),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    let mut parent_output = (5, 1, 0);
+
+    for (height, (block, content_type, recipient)) in (6..).zip([
+      (2, "text/plain;charset=utf-8", &alice),
+      (3, "image/png", &alice),
+      (4, "text/plain;charset=utf-8", &bob),
+    ]) {
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[
+          (block, 0, 0, Witness::new()),
+          (
+            parent_output.0,
+            parent_output.1,
+            parent_output.2,
+            Witness::new(),
+          ),
+        ],
+        script_sig: Inscription {
+          content_type: Some(content_type.into()),
+          body: Some("child".into()),
+          parents: vec![parent.value()],
+          ..default()
+        }
+        .to_script_sig(),
+        outputs: 2,
+        recipient: Some(recipient.clone()),
+        ..default()
+      });
+
+      server.mine_blocks(1);
+
+      parent_output = (height, 1, 1);
+    }
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionStats>(format!("/r/collection/{parent}/stats")),
+      api::CollectionStats {
+        parent,
+        height: Some(8),
+        child_count: 3,
+        unique_holders: 2,
+        holder_distribution: [(1, 1), (2, 1)].into(),
+        first_mint_height: Some(6),
+        last_mint_height: Some(8),
+        content_types: [
+          ("image/png".into(), 1),
+          ("text/plain;charset=utf-8".into(), 2),
+        ]
+        .into(),
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionHolders>(format!("/r/collection/{parent}/holders")),
+      api::CollectionHolders {
+        holders: vec![
+          api::CollectionHolder {
+            address: alice_address.clone(),
+            count: 2,
+          },
+          api::CollectionHolder {
+            address: bob_address,
+            count: 1,
+          },
+        ],
+        more: false,
+        page: 0,
+      }
+    );
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(8, 1, 0, Witness::new())],
+      recipient: Some(alice),
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionHolders>(format!("/r/collection/{parent}/holders/0")),
+      api::CollectionHolders {
+        holders: vec![api::CollectionHolder {
+          address: alice_address,
+          count: 3,
+        }],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server
+        .get_json::<api::CollectionStats>(format!("/r/collection/{parent}/stats"))
+        .holder_distribution,
+      [(3, 1)].into(),
+    );
+
+    server.assert_response(
+      format!("/r/collection/{}/stats", inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {} not found", inscription_id(1)),
+    );
+  }
+
+  #[test]
+  fn collection_traits() {
+    let server = TestServer::builder().chain(Chain::DogecoinRegtest).build();
+
+    server.mine_blocks(3);
+
+    let parent = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, Witness::new())],
+        script_sig: inscription("text/plain;charset=utf-8", "parent").to_script_sig(),
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    let mut children = Vec::new();
+    let mut parent_output = (4, 1, 0);
+
+    for (height, (block, background)) in (5..).zip([(2, "dark red"), (3, "blue")]) {
+      children.push(InscriptionId {
+        txid: server.core.broadcast_tx(TransactionTemplate {
+          inputs: &[
+            (block, 0, 0, Witness::new()),
+            (
+              parent_output.0,
+              parent_output.1,
+              parent_output.2,
+              Witness::new(),
+            ),
+          ],
+          script_sig: Inscription {
+            content_type: Some("text/plain;charset=utf-8".into()),
+            body: Some("child".into()),
+            parents: vec![parent.value()],
+            properties: Properties {
+              attributes: Attributes {
+                title: None,
+                traits: Traits {
+                  items: vec![("background".into(), Trait::String(background.into()))],
+                },
+              },
+              ..default()
+            }
+            .to_cbor(),
+            ..default()
+          }
+          .to_script_sig(),
+          outputs: 2,
+          ..default()
+        }),
+        index: 0,
+      });
+
+      server.mine_blocks(1);
+
+      parent_output = (height, 1, 1);
+    }
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionTraits>(format!("/r/collection/{parent}/traits")),
+      api::CollectionTraits {
+        parent,
+        members: 2,
+        traits: [(
+          "background".into(),
+          [
+            (
+              "blue".into(),
+              api::CollectionTraitValue {
+                count: 1,
+                rarity_score: 2.0,
+              },
+            ),
+            (
+              "dark red".into(),
+              api::CollectionTraitValue {
+                count: 1,
+                rarity_score: 2.0,
+              },
+            ),
+          ]
+          .into(),
+        )]
+        .into(),
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionTraitInscriptions>(format!(
+        "/r/collection/{parent}/traits/background/dark%20red"
+      )),
+      api::CollectionTraitInscriptions {
+        ids: vec![children[0]],
+        more: false,
+        page: 0,
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionTraitInscriptions>(format!(
+        "/r/collection/{parent}/traits/background/blue/0"
+      )),
+      api::CollectionTraitInscriptions {
+        ids: vec![children[1]],
+        more: false,
+        page: 0,
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::CollectionTraitInscriptions>(format!(
+        "/r/collection/{parent}/traits/background/blue/1"
+      )),
+      api::CollectionTraitInscriptions {
+        ids: Vec::new(),
+        more: false,
+        page: 1,
+      }
+    );
+
+    server.assert_response(
+      format!("/r/collection/{}/traits", inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {} not found", inscription_id(1)),
+    );
+
+    server.assert_response(
+      format!("/r/collection/{}/traits/background/blue", inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {} not found", inscription_id(1)),
+    );
+  }
 }
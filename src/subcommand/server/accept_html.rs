@@ -0,0 +1,26 @@
+use super::*;
+
+/// Whether the client asked for HTML. Endpoints that served JSON before they
+/// had an explorer page use this instead of `AcceptJson`, so that clients
+/// which don't ask for HTML keep getting JSON.
+pub(crate) struct AcceptHtml(pub(crate) bool);
+
+impl<S> axum::extract::FromRequestParts<S> for AcceptHtml
+where
+  S: Send + Sync,
+{
+  type Rejection = std::convert::Infallible;
+
+  async fn from_request_parts(
+    parts: &mut http::request::Parts,
+    _state: &S,
+  ) -> Result<Self, Self::Rejection> {
+    Ok(Self(
+      parts
+        .headers
+        .get("accept")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("text/html")),
+    ))
+  }
+}
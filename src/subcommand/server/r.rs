@@ -1,5 +1,37 @@
 use super::*;
 
+pub(super) async fn address_inscriptions(
+  index: Extension<Arc<Index>>,
+  address: Path<String>,
+) -> ServerResult<Json<api::AddressInscriptions>> {
+  address_inscriptions_paginated(index, Path((address.0, 0))).await
+}
+
+pub(super) async fn address_inscriptions_paginated(
+  Extension(index): Extension<Arc<Index>>,
+  Path((address_str, page)): Path<(String, u64)>,
+) -> ServerResult<Json<api::AddressInscriptions>> {
+  task::block_in_place(|| {
+    if !index.has_inscription_index() {
+      return Err(ServerError::NotFound(
+        "this server has no inscription index".into(),
+      ));
+    }
+
+    let script = crate::subcommand::inscribe::parse_dogecoin_address(&address_str)
+      .map_err(|err| ServerError::BadRequest(format!("Invalid address: {err}")))?;
+
+    let address = index
+      .chain()
+      .address_string_from_script(&script)
+      .ok_or_not_found(|| format!("address {address_str}"))?;
+
+    let (ids, more) = index.get_inscription_ids_by_address_paginated(&address, 100, page)?;
+
+    Ok(Json(api::AddressInscriptions { ids, more, page }))
+  })
+}
+
 pub(super) async fn blockhash(
   Extension(index): Extension<Arc<Index>>,
 ) -> ServerResult<Json<String>> {
@@ -220,6 +252,92 @@ pub(super) async fn children_paginated(
   })
 }
 
+pub(super) async fn collection_holders(
+  Extension(index): Extension<Arc<Index>>,
+  Path(parent): Path<InscriptionId>,
+) -> ServerResult<Json<api::CollectionHolders>> {
+  collection_holders_paginated(Extension(index), Path((parent, 0))).await
+}
+
+pub(super) async fn collection_holders_paginated(
+  Extension(index): Extension<Arc<Index>>,
+  Path((parent, page)): Path<(InscriptionId, usize)>,
+) -> ServerResult<Json<api::CollectionHolders>> {
+  task::block_in_place(|| {
+    let summary = index
+      .collection_summary(parent)?
+      .ok_or_not_found(|| format!("inscription {parent}"))?;
+
+    let mut holders = summary
+      .holders
+      .iter()
+      .skip(page.saturating_mul(100))
+      .take(101)
+      .cloned()
+      .collect::<Vec<api::CollectionHolder>>();
+
+    let more = holders.len() > 100;
+
+    if more {
+      holders.pop();
+    }
+
+    Ok(Json(api::CollectionHolders {
+      holders,
+      more,
+      page,
+    }))
+  })
+}
+
+pub(super) async fn collection_stats(
+  Extension(index): Extension<Arc<Index>>,
+  Path(parent): Path<InscriptionId>,
+) -> ServerResult<Json<api::CollectionStats>> {
+  task::block_in_place(|| {
+    Ok(Json(
+      index
+        .collection_summary(parent)?
+        .ok_or_not_found(|| format!("inscription {parent}"))?
+        .stats
+        .clone(),
+    ))
+  })
+}
+
+pub(super) async fn collection_traits(
+  Extension(index): Extension<Arc<Index>>,
+  Path(parent): Path<InscriptionId>,
+) -> ServerResult<Json<api::CollectionTraits>> {
+  task::block_in_place(|| {
+    Ok(Json(
+      index
+        .get_collection_traits(parent)?
+        .ok_or_not_found(|| format!("inscription {parent}"))?,
+    ))
+  })
+}
+
+pub(super) async fn collection_trait_inscriptions(
+  Extension(index): Extension<Arc<Index>>,
+  Path((parent, name, value)): Path<(InscriptionId, String, String)>,
+) -> ServerResult<Json<api::CollectionTraitInscriptions>> {
+  collection_trait_inscriptions_paginated(Extension(index), Path((parent, name, value, 0))).await
+}
+
+pub(super) async fn collection_trait_inscriptions_paginated(
+  Extension(index): Extension<Arc<Index>>,
+  Path((parent, name, value, page)): Path<(InscriptionId, String, String, usize)>,
+) -> ServerResult<Json<api::CollectionTraitInscriptions>> {
+  task::block_in_place(|| {
+    let (ids, more) = index
+      .get_collection_trait_inscriptions_paginated(parent, &name, &value, 100, page)?
+      .ok_or_not_found(|| format!("inscription {parent}"))?;
+
+    Ok(Json(api::CollectionTraitInscriptions { ids, more, page }))
+  })
+}
+
 pub(super) async fn content(
   index: Extension<Arc<Index>>,
   settings: Extension<Arc<Settings>>,
@@ -238,6 +356,31 @@ pub(super) async fn content(
   .await
 }
 
+pub(super) async fn content_hash(
+  Extension(index): Extension<Arc<Index>>,
+  Path(content_hash): Path<DeserializeFromStr<sha256::Hash>>,
+) -> ServerResult<Json<api::ContentHashInscriptions>> {
+  content_hash_paginated(Extension(index), Path((content_hash, 0))).await
+}
+
+pub(super) async fn content_hash_paginated(
+  Extension(index): Extension<Arc<Index>>,
+  Path((DeserializeFromStr(content_hash), page)): Path<(DeserializeFromStr<sha256::Hash>, u64)>,
+) -> ServerResult<Json<api::ContentHashInscriptions>> {
+  task::block_in_place(|| {
+    if !index.has_content_hash_index() {
+      return Err(ServerError::NotFound(
+        "this server has no content hash index".into(),
+      ));
+    }
+
+    let (ids, more) =
+      index.get_inscription_ids_by_content_hash_paginated(content_hash, 100, page)?;
+
+    Ok(Json(api::ContentHashInscriptions { ids, more, page }))
+  })
+}
+
 pub(super) async fn content_inner(
   index: &Index,
   settings: &Settings,
@@ -348,6 +491,39 @@ pub(super) fn content_response(
   Ok(Some((headers, body)))
 }
 
+pub(super) async fn dune_mints(
+  Extension(index): Extension<Arc<Index>>,
+  Path((DeserializeFromStr(dune_query), start, end)): Path<(
+    DeserializeFromStr<query::Dune>,
+    u32,
+    u32,
+  )>,
+) -> ServerResult<Json<api::DuneMints>> {
+  task::block_in_place(|| {
+    if !index.has_dune_index() {
+      return Err(ServerError::NotFound(
+        "this server has no dune index".to_string(),
+      ));
+    }
+
+    let dune = Server::resolve_dune(&index, dune_query)?;
+
+    let (id, ..) = index
+      .dune(dune)?
+      .ok_or_not_found(|| format!("dune {dune}"))?;
+
+    let blocks = index.get_dune_block_mints(id, start, end)?;
+
+    Ok(Json(api::DuneMints {
+      id,
+      start,
+      end,
+      mints: blocks.values().sum(),
+      blocks,
+    }))
+  })
+}
+
 pub(super) async fn inscription(
   Extension(index): Extension<Arc<Index>>,
   Extension(server_config): Extension<Arc<ServerConfig>>,
@@ -434,6 +610,20 @@ pub(super) async fn metadata(
   })
 }
 
+pub(super) async fn mintable_dunes(
+  Extension(index): Extension<Arc<Index>>,
+) -> ServerResult<Json<Vec<api::MintableDune>>> {
+  task::block_in_place(|| {
+    if !index.has_dune_index() {
+      return Err(ServerError::NotFound(
+        "this server has no dune index".to_string(),
+      ));
+    }
+
+    Ok(Json(index.get_mintable_dunes()?))
+  })
+}
+
 pub(super) async fn parents(
   Extension(index): Extension<Arc<Index>>,
   Path(inscription_id): Path<InscriptionId>,
@@ -500,6 +690,109 @@ pub(super) async fn parents_paginated(
   })
 }
 
+pub(super) async fn portfolio(
+  index: Extension<Arc<Index>>,
+  address: Path<String>,
+) -> ServerResult<Json<api::AddressPortfolio>> {
+  portfolio_paginated(index, Path((address.0, 0))).await
+}
+
+pub(super) async fn portfolio_paginated(
+  Extension(index): Extension<Arc<Index>>,
+  Path((address_str, page)): Path<(String, usize)>,
+) -> ServerResult<Json<api::AddressPortfolio>> {
+  task::block_in_place(|| {
+    const PAGE_SIZE: u64 = 100;
+
+    let script = crate::subcommand::inscribe::parse_dogecoin_address(&address_str)
+      .map_err(|err| ServerError::BadRequest(format!("Invalid address: {err}")))?;
+    let address = Address::from_script(&script, Network::Bitcoin)
+      .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+    let info = Server::address_holdings(&index, &address)?;
+
+    let page_index = page.into_u64();
+
+    let dogecoin_address = index.chain().address_string_from_script(&script);
+
+    let (inscriptions, inscriptions_more) = match &dogecoin_address {
+      Some(address) if index.has_inscription_index() => {
+        index.get_inscription_ids_by_address_paginated(address, PAGE_SIZE, page_index)?
+      }
+      _ => {
+        let inscriptions = info.inscriptions.unwrap_or_default();
+
+        let start = page_index.saturating_mul(PAGE_SIZE);
+
+        (
+          inscriptions
+            .iter()
+            .skip(start.try_into().unwrap())
+            .take(PAGE_SIZE.try_into().unwrap())
+            .copied()
+            .collect(),
+          inscriptions.len().into_u64() > start.saturating_add(PAGE_SIZE),
+        )
+      }
+    };
+
+    let (drc20_balances, drc20_more) = match &dogecoin_address {
+      Some(address) => index.get_drc20_balances_paginated(address, PAGE_SIZE, page_index)?,
+      None => (Vec::new(), false),
+    };
+
+    let (dogemaps, dogemaps_more) = match &dogecoin_address {
+      Some(address) => index.get_dogemaps_by_owner_paginated(address, PAGE_SIZE, page_index)?,
+      None => (Vec::new(), false),
+    };
+
+    // names are those of the inscriptions on this page
+    let names = index.get_dns_names_for_inscriptions(&inscriptions)?;
+
+    Ok(Json(api::AddressPortfolio {
+      address: address_str,
+      sat_balance: info.sat_balance,
+      inscriptions,
+      dunes_balances: info.dunes_balances.unwrap_or_default(),
+      drc20_balances: drc20_balances
+        .into_iter()
+        .map(|(tick, available, transferable)| {
+          (
+            tick,
+            api::Drc20Balance {
+              available,
+              transferable,
+            },
+          )
+        })
+        .collect(),
+      names,
+      dogemaps,
+      more: inscriptions_more || drc20_more || dogemaps_more,
+      page,
+    }))
+  })
+}
+
+pub(super) async fn koinu_stats(
+  Extension(index): Extension<Arc<Index>>,
+) -> ServerResult<Json<api::KoinuStats>> {
+  task::block_in_place(|| {
+    let height = index.block_height()?;
+
+    let end = height.map_or(Height(0), |height| height + 1);
+
+    Ok(Json(api::KoinuStats {
+      height: height.map(|height| height.n()),
+      supply: end.starting_sat().n(),
+      rarity: Rarity::ALL
+        .into_iter()
+        .map(|rarity| (rarity, rarity.supply(end)))
+        .collect(),
+    }))
+  })
+}
+
 pub(super) async fn sat(
   Extension(index): Extension<Arc<Index>>,
   Path(sat): Path<u64>,
@@ -666,19 +959,6 @@ pub(super) async fn utxo(
 // DRC-20 recursive API (ord-style JSON)
 // ---------------------------------------------------------------------------
 
-#[derive(serde::Serialize)]
-pub(super) struct Drc20BalanceResponse {
-  pub available: u128,
-  pub transferable: u128,
-}
-
-#[derive(serde::Serialize)]
-pub(super) struct Drc20BalanceEntry {
-  pub tick: String,
-  pub available: u128,
-  pub transferable: u128,
-}
-
 pub(super) async fn drc20_tokens(
   Extension(index): Extension<Arc<Index>>,
 ) -> ServerResult<Json<Vec<crate::subcommand::drc20::Drc20Token>>> {
@@ -705,12 +985,12 @@ pub(super) async fn drc20_token(
 pub(super) async fn drc20_balances(
   Extension(index): Extension<Arc<Index>>,
   Path(address): Path<String>,
-) -> ServerResult<Json<Vec<Drc20BalanceEntry>>> {
+) -> ServerResult<Json<Vec<api::Drc20BalanceEntry>>> {
   task::block_in_place(|| {
     let list = index.get_drc20_balances(&address)?;
-    let entries: Vec<Drc20BalanceEntry> = list
+    let entries: Vec<api::Drc20BalanceEntry> = list
       .into_iter()
-      .map(|(tick, available, transferable)| Drc20BalanceEntry {
+      .map(|(tick, available, transferable)| api::Drc20BalanceEntry {
         tick,
         available,
         transferable,
@@ -723,16 +1003,29 @@ pub(super) async fn drc20_balances(
 pub(super) async fn drc20_balance(
   Extension(index): Extension<Arc<Index>>,
   Path((address, tick)): Path<(String, String)>,
-) -> ServerResult<Json<Drc20BalanceResponse>> {
+) -> ServerResult<Json<api::Drc20Balance>> {
   task::block_in_place(|| {
     let (available, transferable) = index.get_drc20_balance(&address, &tick)?;
-    Ok(Json(Drc20BalanceResponse {
+    Ok(Json(api::Drc20Balance {
       available,
       transferable,
     }))
   })
 }
 
+pub(super) async fn drc20_transfer(
+  Extension(index): Extension<Arc<Index>>,
+  Path(outpoint): Path<OutPoint>,
+) -> ServerResult<Json<crate::subcommand::drc20::Drc20Transfer>> {
+  task::block_in_place(|| {
+    Ok(Json(
+      index
+        .get_drc20_transfer(outpoint)?
+        .ok_or_not_found(|| format!("drc20 transfer {outpoint}"))?,
+    ))
+  })
+}
+
 // ---------------------------------------------------------------------------
 // DNS (Dogecoin Name System) recursive API
 // ---------------------------------------------------------------------------
@@ -798,6 +1091,48 @@ pub(super) async fn dogemap_claim(
   })
 }
 
+pub(super) async fn dogemap_parcels(
+  Extension(index): Extension<Arc<Index>>,
+  Path(block_number): Path<u32>,
+) -> ServerResult<Json<Vec<crate::subcommand::dogemap::DogemapParcel>>> {
+  task::block_in_place(|| {
+    index
+      .get_dogemap_claim(block_number)?
+      .ok_or_not_found(|| format!("dogemap block {block_number}"))?;
+
+    Ok(Json(index.get_dogemap_parcels(block_number)?))
+  })
+}
+
+#[derive(serde::Serialize)]
+pub(super) struct DogemapOwnerResponse {
+  pub address: String,
+  pub blocks: Vec<u32>,
+}
+
+pub(super) async fn dogemap_owner(
+  Extension(index): Extension<Arc<Index>>,
+  Path(address): Path<String>,
+) -> ServerResult<Json<DogemapOwnerResponse>> {
+  task::block_in_place(|| {
+    let blocks = index.get_dogemaps_by_owner(&address)?;
+    Ok(Json(DogemapOwnerResponse { address, blocks }))
+  })
+}
+
+pub(super) async fn dogemap_transfers(
+  Extension(index): Extension<Arc<Index>>,
+  Path(block_number): Path<u32>,
+) -> ServerResult<Json<Vec<crate::subcommand::dogemap::DogemapTransfer>>> {
+  task::block_in_place(|| {
+    index
+      .get_dogemap_claim(block_number)?
+      .ok_or_not_found(|| format!("dogemap block {block_number}"))?;
+
+    Ok(Json(index.get_dogemap_transfers(block_number)?))
+  })
+}
+
 #[derive(serde::Deserialize)]
 pub(super) struct DogemapListQuery {
   #[serde(default = "default_dogemap_limit")]
@@ -1,27 +1,47 @@
 use super::*;
 
+#[derive(Debug, Parser)]
+pub(crate) struct Supply {
+  #[arg(
+    long,
+    help = "Display supply as of block <HEIGHT>. Defaults to the block that mines the last numbered koinu."
+  )]
+  height: Option<u32>,
+  #[arg(long, help = "Break supply down by rarity.")]
+  rarity: bool,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub supply: u64,
   pub first: u64,
   pub last: u64,
   pub last_mined_in_block: u32,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub rarity: Option<BTreeMap<Rarity, u64>>,
 }
 
-pub(crate) fn run() -> SubcommandResult {
-  let mut last = 0;
+impl Supply {
+  pub(crate) fn run(self) -> SubcommandResult {
+    let height = self.height.unwrap_or(Koinu::LAST.height().n());
 
-  loop {
-    if Height(last + 1).subsidy() == 0 {
-      break;
-    }
-    last += 1;
-  }
+    // Dogecoin's block subsidy never reaches zero, so supply is only
+    // meaningful up to a given block.
+    let end = Height(height + 1);
 
-  Ok(Some(Box::new(Output {
-    supply: Koinu::SUPPLY,
-    first: 0,
-    last: Koinu::SUPPLY - 1,
-    last_mined_in_block: last,
-  })))
+    let supply = end.starting_sat().n();
+
+    Ok(Some(Box::new(Output {
+      supply,
+      first: 0,
+      last: supply - 1,
+      last_mined_in_block: height,
+      rarity: self.rarity.then(|| {
+        Rarity::ALL
+          .into_iter()
+          .map(|rarity| (rarity, rarity.supply(end)))
+          .collect()
+      }),
+    })))
+  }
 }
@@ -18,10 +18,11 @@ pub struct Output {
   pub period: u32,
   pub offset: u64,
   pub rarity: Rarity,
+  pub charms: Vec<Charm>,
 }
 
 impl Traits {
-  pub(crate) fn run(self) -> SubcommandResult {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
     Ok(Some(Box::new(Output {
       number: self.sat.n(),
       decimal: self.sat.decimal().to_string(),
@@ -33,6 +34,7 @@ impl Traits {
       period: self.sat.period(),
       offset: self.sat.third(),
       rarity: self.sat.rarity(),
+      charms: Charm::charms(self.sat.charms(settings.chain().charm_heights())),
     })))
   }
 }
@@ -10,6 +10,7 @@ mod batch_command;
 pub mod burn;
 pub mod cardinals;
 pub mod create;
+pub mod drc20;
 pub mod dump;
 pub mod inscribe;
 pub mod inscriptions;
@@ -60,6 +61,8 @@ pub(crate) enum Subcommand {
   Cardinals,
   #[command(about = "Create new wallet")]
   Create(create::Create),
+  #[command(subcommand, about = "DRC-20 token commands")]
+  Drc20(drc20::Drc20),
   #[command(about = "Dump wallet descriptors")]
   Dump,
   #[command(about = "Create inscription")]
@@ -127,6 +130,7 @@ impl WalletCommand {
       Subcommand::Burn(burn) => burn.run(wallet),
       Subcommand::Cardinals => cardinals::run(wallet),
       Subcommand::Create(_) | Subcommand::Restore(_) => unreachable!(),
+      Subcommand::Drc20(drc20) => drc20.run(wallet),
       Subcommand::Dump => dump::run(wallet),
       Subcommand::Inscribe(inscribe) => inscribe.run(wallet),
       Subcommand::Inscriptions => inscriptions::run(wallet),
@@ -11,7 +11,10 @@ pub struct Burn {
   cbor_metadata: Option<PathBuf>,
   #[arg(long, help = "Don't sign or broadcast transaction.")]
   dry_run: bool,
-  #[arg(long, help = "Use fee rate of <FEE_RATE> koinu/vB.")]
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`."
+  )]
   fee_rate: FeeRate,
   #[arg(
     long,
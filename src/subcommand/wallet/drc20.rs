@@ -0,0 +1,349 @@
+use {
+  super::*,
+  crate::subcommand::drc20::{Drc20Token, format_amount, parse_amount},
+  indicatif::{ProgressBar, ProgressStyle},
+};
+
+pub mod buy;
+pub mod deploy;
+pub mod list;
+pub mod mint;
+pub mod send;
+pub mod transfer;
+
+#[derive(Debug, Parser)]
+pub(crate) enum Drc20 {
+  #[command(about = "Buy a DRC-20 transfer inscription from a listing")]
+  Buy(buy::Buy),
+  #[command(about = "Deploy a DRC-20 token")]
+  Deploy(deploy::Deploy),
+  #[command(about = "List DRC-20 tokens for sale, inscribing a transfer first if necessary")]
+  List(list::List),
+  #[command(about = "Mint DRC-20 tokens")]
+  Mint(mint::Mint),
+  #[command(about = "Send DRC-20 tokens, inscribing a transfer first if necessary")]
+  Send(send::Send),
+  #[command(about = "Inscribe a DRC-20 transfer")]
+  Transfer(transfer::Transfer),
+}
+
+impl Drc20 {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    match self {
+      Self::Buy(buy) => buy.run(wallet),
+      Self::Deploy(deploy) => deploy.run(wallet),
+      Self::List(list) => list.run(wallet),
+      Self::Mint(mint) => mint.run(wallet),
+      Self::Send(send) => send.run(wallet),
+      Self::Transfer(transfer) => transfer.run(wallet),
+    }
+  }
+}
+
+#[derive(Debug, Parser)]
+pub(super) struct InscribeArgs {
+  #[arg(
+    long,
+    help = "Use <COMMIT_FEE_RATE> koinu/vbyte for commit transaction.\nDefaults to <FEE_RATE> if unset."
+  )]
+  pub(crate) commit_fee_rate: Option<FeeRate>,
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`."
+  )]
+  pub(crate) fee_rate: FeeRate,
+  #[arg(long, help = "Don't sign or broadcast transactions.")]
+  pub(crate) dry_run: bool,
+  #[arg(long, alias = "nobackup", help = "Do not back up recovery key.")]
+  pub(crate) no_backup: bool,
+  #[arg(
+    long,
+    help = "Include <AMOUNT> postage with inscription. [default: 0.01 DOGE]",
+    value_name = "AMOUNT"
+  )]
+  pub(crate) postage: Option<Amount>,
+}
+
+impl InscribeArgs {
+  fn inscribe(
+    &self,
+    wallet: &Wallet,
+    destination: Address,
+    operation: serde_json::Value,
+  ) -> Result<batch::Output> {
+    batch::Plan {
+      commit_fee_rate: self.commit_fee_rate.unwrap_or(self.fee_rate),
+      destinations: vec![destination],
+      dry_run: self.dry_run,
+      etching: None,
+      inscriptions: vec![inscription(operation)],
+      mode: batch::Mode::SeparateOutputs,
+      no_backup: self.no_backup,
+      no_limit: false,
+      parent_info: Vec::new(),
+      postages: vec![self.postage.unwrap_or(TARGET_POSTAGE)],
+      reinscribe: false,
+      reveal_fee_rate: self.fee_rate,
+      reveal_satpoints: Vec::new(),
+      satpoint: None,
+    }
+    .create_inscriptions(
+      &wallet.locked_utxos().clone().into_keys().collect(),
+      wallet.get_runic_outputs()?.unwrap_or_default(),
+      wallet.utxos(),
+      wallet,
+    )
+  }
+}
+
+/// DRC-20 operations are uncompressed JSON in a plain text inscription, which
+/// is what the indexer and other DRC-20 indexers expect.
+fn inscription(operation: serde_json::Value) -> Inscription {
+  Inscription {
+    body: Some(operation.to_string().into_bytes()),
+    content_type: Some("text/plain;charset=utf-8".into()),
+    ..default()
+  }
+}
+
+fn operation(op: &str, tick: &str, fields: &[(&str, serde_json::Value)]) -> serde_json::Value {
+  let mut operation = serde_json::Map::new();
+
+  operation.insert("p".into(), "drc-20".into());
+  operation.insert("op".into(), op.into());
+  operation.insert("tick".into(), tick.into());
+
+  for (key, value) in fields {
+    operation.insert((*key).into(), value.clone());
+  }
+
+  operation.into()
+}
+
+fn check_tick(tick: &str) -> Result {
+  ensure!(
+    tick.len() == 4,
+    "DRC-20 tick must be exactly 4 bytes: `{tick}`"
+  );
+
+  Ok(())
+}
+
+fn deployed_token(wallet: &Wallet, tick: &str) -> Result<Drc20Token> {
+  check_tick(tick)?;
+
+  wallet
+    .get_drc20_token(tick)?
+    .ok_or_else(|| anyhow!("DRC-20 token `{tick}` has not been deployed"))
+}
+
+fn parse_positive_amount(amount: &str, token: &Drc20Token) -> Result<u128> {
+  parse_amount(amount, token.decimals)
+    .filter(|amount| *amount > 0)
+    .ok_or_else(|| {
+      anyhow!(
+        "invalid `{}` amount `{amount}`: must be positive with at most {} decimal places",
+        token.tick,
+        token.decimals
+      )
+    })
+}
+
+/// Find a wallet address whose available balance covers `amount`. Transfer
+/// inscriptions only move balance from the address they are inscribed to, so
+/// balances held at different addresses cannot be combined.
+fn source_address(wallet: &Wallet, token: &Drc20Token, amount: u128) -> Result<Address> {
+  let chain = wallet.chain();
+
+  let script_pubkeys = wallet
+    .utxos()
+    .values()
+    .map(|output| output.script_pubkey.clone())
+    .collect::<BTreeSet<ScriptBuf>>();
+
+  let mut available = 0;
+  let mut transferable = 0;
+
+  for script_pubkey in script_pubkeys {
+    let Some(address) = chain.address_string_from_script(&script_pubkey) else {
+      continue;
+    };
+
+    let balance = wallet.get_drc20_balance(&address, &token.tick)?;
+
+    if balance.available >= amount {
+      return Ok(chain.address_from_script(&script_pubkey)?);
+    }
+
+    available += balance.available;
+    transferable += balance.transferable;
+  }
+
+  bail!(
+    "insufficient `{}` balance: {} required, wallet has {} available and {} transferable{}",
+    token.tick,
+    format_amount(amount, token.decimals),
+    format_amount(available, token.decimals),
+    format_amount(transferable, token.decimals),
+    if available >= amount {
+      ", but no single address holds enough"
+    } else {
+      ""
+    },
+  )
+}
+
+/// Find a wallet transfer inscription of exactly `amount` of `token`.
+fn find_transfer(
+  wallet: &Wallet,
+  token: &Drc20Token,
+  amount: u128,
+) -> Result<Option<(InscriptionId, KoinuPoint)>> {
+  let tick = token.tick.to_lowercase();
+
+  let mut checked = HashSet::new();
+
+  for (id, info) in wallet.inscription_info() {
+    if !checked.insert(info.satpoint.outpoint) {
+      continue;
+    }
+
+    if let Some(transfer) = wallet.get_drc20_transfer(info.satpoint.outpoint)?
+      && transfer.tick == tick
+      && transfer.amount == amount
+    {
+      return Ok(Some((id, info.satpoint)));
+    }
+  }
+
+  Ok(None)
+}
+
+/// Number of blocks to wait for a newly inscribed transfer to be indexed
+/// before giving up.
+const TRANSFER_WAIT_BLOCKS: u64 = 10;
+
+/// Wait for a newly inscribed transfer to be indexed, so that it can be spent.
+/// Gives up after `TRANSFER_WAIT_BLOCKS` blocks, since the transfer may never
+/// confirm, for example if its fee rate is too low.
+fn wait_for_transfer(
+  wallet: &Wallet,
+  id: InscriptionId,
+  outpoint: OutPoint,
+  command: &str,
+) -> Result {
+  eprintln!("Waiting for transfer inscription {id} to confirm…");
+
+  let start = wallet.dogecoin_client().get_block_count()?;
+
+  let progress = ProgressBar::new(TRANSFER_WAIT_BLOCKS).with_style(
+    ProgressStyle::default_bar()
+      .template("Waiting {elapsed} {spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} blocks")
+      .unwrap()
+      .progress_chars("█▓▒░ "),
+  );
+
+  loop {
+    if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+      progress.abandon();
+      bail!(
+        "transfer inscription {id} was not yet indexed, run `dog wallet drc20 {command}` again once it confirms"
+      );
+    }
+
+    let blocks = wallet
+      .dogecoin_client()
+      .get_block_count()?
+      .saturating_sub(start);
+
+    progress.set_position(blocks.min(TRANSFER_WAIT_BLOCKS));
+    progress.tick();
+
+    // The server falls back to RPC for unindexed inscriptions, so wait for the
+    // output itself to be indexed rather than for the inscription to exist.
+    if wallet
+      .get_output(outpoint)?
+      .is_some_and(|output| output.indexed)
+    {
+      progress.finish_and_clear();
+
+      ensure!(
+        wallet.get_drc20_transfer(outpoint)?.is_some(),
+        "transfer inscription {id} was not indexed as a valid DRC-20 transfer",
+      );
+
+      return Ok(());
+    }
+
+    if blocks >= TRANSFER_WAIT_BLOCKS {
+      progress.abandon();
+      bail!(
+        "transfer inscription {id} was not indexed within {TRANSFER_WAIT_BLOCKS} blocks, run `dog wallet drc20 {command}` again once it confirms"
+      );
+    }
+
+    thread::sleep(if wallet.integration_test() {
+      Duration::from_millis(100)
+    } else {
+      Duration::from_secs(5)
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn operation_field_order() {
+    assert_eq!(
+      operation(
+        "deploy",
+        "dogi",
+        &[("max", "21000000".into()), ("lim", "1000".into())]
+      )
+      .to_string(),
+      r#"{"p":"drc-20","op":"deploy","tick":"dogi","max":"21000000","lim":"1000"}"#,
+    );
+  }
+
+  #[test]
+  fn inscription_is_uncompressed_plain_text() {
+    let inscription = inscription(operation("mint", "dogi", &[("amt", "1000".into())]));
+
+    assert_eq!(inscription.content_type(), Some("text/plain;charset=utf-8"));
+    assert_eq!(inscription.content_encoding, None);
+    assert_eq!(
+      inscription.body(),
+      Some(br#"{"p":"drc-20","op":"mint","tick":"dogi","amt":"1000"}"#.as_slice())
+    );
+  }
+
+  #[test]
+  fn tick_must_be_four_bytes() {
+    assert!(check_tick("dogi").is_ok());
+    assert!(check_tick("dog").is_err());
+    assert!(check_tick("doges").is_err());
+  }
+
+  #[test]
+  fn amounts_must_be_positive() {
+    let token = Drc20Token {
+      tick: "dogi".into(),
+      max_supply: 100,
+      mint_limit: 10,
+      decimals: 2,
+      minted: 0,
+      deploy_inscription: String::new(),
+      deploy_height: 0,
+      deploy_timestamp: 0,
+      deployer: String::new(),
+      mint_count: 0,
+    };
+
+    assert_eq!(parse_positive_amount("1.5", &token).unwrap(), 150);
+    assert!(parse_positive_amount("0", &token).is_err());
+    assert!(parse_positive_amount("0.001", &token).is_err());
+    assert!(parse_positive_amount("-1", &token).is_err());
+  }
+}
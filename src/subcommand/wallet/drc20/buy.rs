@@ -0,0 +1,70 @@
+use {super::*, crate::subcommand::wallet::offer::accept::Accept};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Buy {
+  #[arg(long, help = "Assert listing is for <AMOUNT> of <TICK>.")]
+  amount: String,
+  #[arg(long, help = "Assert listing is for <TICK>.")]
+  tick: String,
+  #[arg(long, help = "Assert listing is for <PRICE>.")]
+  price: Amount,
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`."
+  )]
+  fee_rate: FeeRate,
+  #[arg(long, help = "Don't sign or broadcast transaction.")]
+  dry_run: bool,
+  #[arg(help = "Buy from <PSBT> listing.")]
+  psbt: String,
+}
+
+impl Buy {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let token = deployed_token(&wallet, &self.tick)?;
+
+    let amount = parse_positive_amount(&self.amount, &token)?;
+
+    let psbt = base64_decode(&self.psbt).context("failed to base64 decode PSBT")?;
+
+    let psbt = Psbt::deserialize(&psbt).context("failed to deserialize PSBT")?;
+
+    ensure!(
+      psbt.unsigned_tx.input.len() == 2
+        && psbt.unsigned_tx.input[0].previous_output == OutPoint::null(),
+      "PSBT is not a DRC-20 listing: DRC-20 listings sell exactly one transfer inscription",
+    );
+
+    let outpoint = psbt.unsigned_tx.input[1].previous_output;
+
+    let transfer = wallet
+      .get_drc20_transfer(outpoint)?
+      .with_context(|| format!("listing input `{outpoint}` is not an unspent DRC-20 transfer"))?;
+
+    ensure!(
+      transfer.tick == token.tick.to_lowercase() && transfer.amount == amount,
+      "listing input `{outpoint}` is a transfer of {} `{}`, not {} `{}`",
+      format_amount(transfer.amount, token.decimals),
+      transfer.tick,
+      format_amount(amount, token.decimals),
+      token.tick,
+    );
+
+    let Some(inscriptions) = wallet
+      .get_output(outpoint)?
+      .and_then(|output| output.inscriptions)
+    else {
+      bail!("index must have inscription index to buy DRC-20 listings");
+    };
+
+    Accept {
+      amount: self.price,
+      dune: None,
+      dry_run: self.dry_run,
+      fee_rate: Some(self.fee_rate),
+      inscription: inscriptions,
+      psbt: self.psbt,
+    }
+    .run(wallet)
+  }
+}
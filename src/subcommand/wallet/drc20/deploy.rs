@@ -0,0 +1,71 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Deploy {
+  #[command(flatten)]
+  inscribe: InscribeArgs,
+  #[arg(long, help = "Allow <DECIMALS> decimal places. [default: 8]")]
+  decimals: Option<u8>,
+  #[arg(long, help = "Send deploy inscription to <DESTINATION>.")]
+  destination: Option<Address<NetworkUnchecked>>,
+  #[arg(long, help = "Limit each mint to <LIMIT>. [default: <MAX>]")]
+  limit: Option<String>,
+  #[arg(long, help = "Cap total supply at <MAX>.")]
+  max: String,
+  #[arg(long, help = "Deploy <TICK>.")]
+  tick: String,
+}
+
+impl Deploy {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    check_tick(&self.tick)?;
+
+    ensure!(
+      wallet.get_drc20_token(&self.tick)?.is_none(),
+      "DRC-20 token `{}` has already been deployed",
+      self.tick,
+    );
+
+    let decimals = self.decimals.unwrap_or(8);
+
+    ensure!(
+      decimals <= 18,
+      "DRC-20 decimals must be at most 18: {decimals}"
+    );
+
+    let max = parse_amount(&self.max, decimals)
+      .filter(|max| *max > 0)
+      .ok_or_else(|| anyhow!("invalid max supply `{}`", self.max))?;
+
+    let mut fields = vec![("max", self.max.clone().into())];
+
+    if let Some(limit) = &self.limit {
+      let parsed = parse_amount(limit, decimals)
+        .filter(|limit| *limit > 0)
+        .ok_or_else(|| anyhow!("invalid mint limit `{limit}`"))?;
+
+      ensure!(
+        parsed <= max,
+        "mint limit {limit} exceeds max supply {}",
+        self.max
+      );
+
+      fields.push(("lim", limit.clone().into()));
+    }
+
+    if let Some(decimals) = self.decimals {
+      fields.push(("dec", decimals.into()));
+    }
+
+    let destination = match self.destination {
+      Some(destination) => destination.require_network(wallet.chain().network())?,
+      None => wallet.get_change_address()?,
+    };
+
+    Ok(Some(Box::new(self.inscribe.inscribe(
+      &wallet,
+      destination,
+      operation("deploy", &self.tick, &fields),
+    )?)))
+  }
+}
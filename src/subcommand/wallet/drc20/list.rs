@@ -0,0 +1,109 @@
+use {
+  super::*,
+  crate::subcommand::wallet::offer::{
+    list::{sign_listing, unsigned_listing},
+    submit,
+  },
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct List {
+  #[command(flatten)]
+  inscribe: InscribeArgs,
+  #[arg(long, help = "List <AMOUNT> of <TICK>.")]
+  amount: String,
+  #[arg(long, help = "List <TICK>.")]
+  tick: String,
+  #[arg(long, help = "Sell for <PRICE>.")]
+  price: Amount,
+  #[arg(long, help = "Submit listing to <URL>.", value_name = "URL")]
+  submit: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Output {
+  pub transfer: InscriptionId,
+  pub inscription: Option<batch::Output>,
+  pub psbt: String,
+  pub price: u64,
+}
+
+impl List {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let token = deployed_token(&wallet, &self.tick)?;
+
+    let amount = parse_positive_amount(&self.amount, &token)?;
+
+    let (wallet, transfer, satpoint, inscription) = if let Some((transfer, satpoint)) =
+      find_transfer(&wallet, &token, amount)?
+    {
+      (wallet, transfer, satpoint, None)
+    } else {
+      ensure!(
+        !self.inscribe.dry_run,
+        "no `{}` transfer inscription of {} in wallet, create one with `dog wallet drc20 transfer` before using `--dry-run`",
+        token.tick,
+        self.amount,
+      );
+
+      let source = source_address(&wallet, &token, amount)?;
+
+      let inscription = self.inscribe.inscribe(
+        &wallet,
+        source,
+        operation(
+          "transfer",
+          &self.tick,
+          &[("amt", format_amount(amount, token.decimals).into())],
+        ),
+      )?;
+
+      let info = &inscription.inscriptions[0];
+
+      wait_for_transfer(&wallet, info.id, info.location.outpoint, "list")?;
+
+      (wallet.reload()?, info.id, info.location, Some(inscription))
+    };
+
+    // Buyers are credited through the first output of the purchase, which
+    // must receive the transfer inscription and nothing else of value.
+    let Some(inscriptions) = wallet.get_inscriptions_in_output(&satpoint.outpoint)? else {
+      bail!("index must have inscription index to list DRC-20 transfers");
+    };
+
+    ensure!(
+      inscriptions == [transfer],
+      "transfer inscription {transfer} shares output {} with other inscriptions",
+      satpoint.outpoint,
+    );
+
+    if let Some(dunes) = wallet.get_dunes_balances_in_output(&satpoint.outpoint)? {
+      ensure!(
+        dunes.is_empty(),
+        "transfer inscription {transfer} shares output {} with dunes",
+        satpoint.outpoint,
+      );
+    }
+
+    let psbt = unsigned_listing(&wallet, &[(satpoint.outpoint, 1)], self.price)?;
+
+    let psbt = if self.inscribe.dry_run {
+      base64_encode(&psbt.serialize())
+    } else {
+      sign_listing(&wallet, &psbt)?
+    };
+
+    if let Some(url) = &self.submit
+      && !self.inscribe.dry_run
+    {
+      submit(url, &psbt)?;
+    }
+
+    Ok(Some(Box::new(Output {
+      transfer,
+      inscription,
+      psbt,
+      price: self.price.to_sat(),
+    })))
+  }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Mint {
+  #[command(flatten)]
+  inscribe: InscribeArgs,
+  #[arg(long, help = "Mint <AMOUNT> of <TICK>.")]
+  amount: String,
+  #[arg(long, help = "Send mint inscription to <DESTINATION>.")]
+  destination: Option<Address<NetworkUnchecked>>,
+  #[arg(long, help = "Mint <TICK>.")]
+  tick: String,
+}
+
+impl Mint {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let token = deployed_token(&wallet, &self.tick)?;
+
+    let amount = parse_positive_amount(&self.amount, &token)?;
+
+    ensure!(
+      amount <= token.mint_limit,
+      "mint amount {} exceeds `{}` mint limit of {}",
+      self.amount,
+      token.tick,
+      token.limit_display(),
+    );
+
+    ensure!(
+      token.minted < token.max_supply,
+      "DRC-20 token `{}` is fully minted",
+      token.tick,
+    );
+
+    let destination = match self.destination {
+      Some(destination) => destination.require_network(wallet.chain().network())?,
+      None => wallet.get_change_address()?,
+    };
+
+    Ok(Some(Box::new(self.inscribe.inscribe(
+      &wallet,
+      destination,
+      operation(
+        "mint",
+        &self.tick,
+        &[("amt", format_amount(amount, token.decimals).into())],
+      ),
+    )?)))
+  }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Send {
+  #[command(flatten)]
+  inscribe: InscribeArgs,
+  #[arg(long, help = "Send <AMOUNT> of <TICK>.")]
+  amount: String,
+  #[arg(long, help = "Send <TICK>.")]
+  tick: String,
+  #[arg(help = "Recipient address")]
+  address: Address<NetworkUnchecked>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Output {
+  pub transfer: InscriptionId,
+  pub inscription: Option<batch::Output>,
+  pub txid: Txid,
+  pub psbt: String,
+  pub fee: u64,
+}
+
+impl Send {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let destination = self
+      .address
+      .clone()
+      .require_network(wallet.chain().network())?;
+
+    let token = deployed_token(&wallet, &self.tick)?;
+
+    let amount = parse_positive_amount(&self.amount, &token)?;
+
+    let (wallet, transfer, satpoint, inscription) = if let Some((transfer, satpoint)) =
+      find_transfer(&wallet, &token, amount)?
+    {
+      (wallet, transfer, satpoint, None)
+    } else {
+      ensure!(
+        !self.inscribe.dry_run,
+        "no `{}` transfer inscription of {} in wallet, create one with `dog wallet drc20 transfer` before using `--dry-run`",
+        token.tick,
+        self.amount,
+      );
+
+      let source = source_address(&wallet, &token, amount)?;
+
+      let inscription = self.inscribe.inscribe(
+        &wallet,
+        source,
+        operation(
+          "transfer",
+          &self.tick,
+          &[("amt", format_amount(amount, token.decimals).into())],
+        ),
+      )?;
+
+      let info = &inscription.inscriptions[0];
+
+      wait_for_transfer(&wallet, info.id, info.location.outpoint, "send")?;
+
+      (wallet.reload()?, info.id, info.location, Some(inscription))
+    };
+
+    let unsigned_transaction = wallet.create_unsigned_send_satpoint_transaction(
+      destination.clone(),
+      satpoint,
+      None,
+      self.inscribe.fee_rate,
+      true,
+    )?;
+
+    // DRC-20 indexers credit a transfer to the first output of the spending
+    // transaction, so anything else would send the tokens to the wrong place.
+    ensure!(
+      unsigned_transaction
+        .input
+        .first()
+        .map(|input| input.previous_output)
+        == Some(satpoint.outpoint)
+        && unsigned_transaction.output.first().is_some_and(|output| {
+          output.script_pubkey == destination.script_pubkey()
+            && satpoint.offset < output.value.to_sat()
+        }),
+      "refusing to send transfer inscription {transfer}: it would not be spent to the first output paying {destination}",
+    );
+
+    let (txid, psbt, fee) =
+      wallet.sign_and_broadcast_transaction(unsigned_transaction, self.inscribe.dry_run, None)?;
+
+    Ok(Some(Box::new(Output {
+      transfer,
+      inscription,
+      txid,
+      psbt,
+      fee,
+    })))
+  }
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Transfer {
+  #[command(flatten)]
+  inscribe: InscribeArgs,
+  #[arg(long, help = "Make <AMOUNT> of <TICK> transferable.")]
+  amount: String,
+  #[arg(long, help = "Transfer <TICK>.")]
+  tick: String,
+}
+
+impl Transfer {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let token = deployed_token(&wallet, &self.tick)?;
+
+    let amount = parse_positive_amount(&self.amount, &token)?;
+
+    let source = source_address(&wallet, &token, amount)?;
+
+    Ok(Some(Box::new(self.inscribe.inscribe(
+      &wallet,
+      source,
+      operation(
+        "transfer",
+        &self.tick,
+        &[("amt", format_amount(amount, token.decimals).into())],
+      ),
+    )?)))
+  }
+}
@@ -43,7 +43,7 @@ pub(crate) struct Inscribe {
   pub(crate) parent: Option<InscriptionId>,
   #[arg(
     long,
-    help = "Include <AMOUNT> postage with inscription. [default: 10000sat]",
+    help = "Include <AMOUNT> postage with inscription. [default: 0.01 DOGE]",
     value_name = "AMOUNT"
   )]
   pub(crate) postage: Option<Amount>,
@@ -8,7 +8,7 @@ pub(crate) struct Mint {
   dune: SpacedDune,
   #[clap(
     long,
-    help = "Include <AMOUNT> postage with mint output. [default: 10000sat]"
+    help = "Include <AMOUNT> postage with mint output. [default: 0.01 DOGE]"
   )]
   postage: Option<Amount>,
   #[clap(long, help = "Send minted dunes to <DESTINATION>.")]
@@ -53,9 +53,9 @@ impl Mint {
     };
 
     ensure!(
-      destination.script_pubkey().minimal_non_dust() <= postage,
+      policy::dust_limit(&destination.script_pubkey()) <= postage,
       "postage below dust limit of {}sat",
-      destination.script_pubkey().minimal_non_dust().to_sat()
+      policy::dust_limit(&destination.script_pubkey()).to_sat()
     );
 
     let dunestone = Dunestone {
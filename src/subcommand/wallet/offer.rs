@@ -2,13 +2,16 @@ use super::*;
 
 pub mod accept;
 pub mod create;
+pub mod list;
 
 #[derive(Debug, Parser)]
 pub(crate) enum Offer {
-  #[command(about = "Accept offer to buy inscription")]
+  #[command(about = "Accept offer to buy inscription or listing to sell inscriptions or dunes")]
   Accept(accept::Accept),
   #[command(about = "Create offer to buy inscription")]
   Create(create::Create),
+  #[command(about = "Create listing to sell inscriptions or dunes")]
+  List(list::List),
 }
 
 impl Offer {
@@ -16,6 +19,28 @@ impl Offer {
     match self {
       Self::Accept(accept) => accept.run(wallet),
       Self::Create(create) => create.run(wallet),
+      Self::List(list) => list.run(wallet),
     }
   }
 }
+
+pub(crate) fn submit(url: &str, psbt: &str) -> Result {
+  let response = reqwest::blocking::Client::new()
+    .post(url)
+    .body(psbt.as_bytes().to_vec())
+    .send()
+    .context("failed to submit PSBT")?;
+
+  let status = response.status();
+
+  let text = response
+    .text()
+    .context("failed to receive submission response body")?;
+
+  ensure! {
+    status == StatusCode::OK,
+    "submission failed with status code {status}: {text}",
+  }
+
+  Ok(())
+}
@@ -1,4 +1,7 @@
-use super::*;
+use {
+  super::*,
+  bitcoin::sighash::{EcdsaSighashType, TapSighashType},
+};
 
 #[derive(PartialEq)]
 enum Signature<'a> {
@@ -13,14 +16,29 @@ pub struct Output {
 
 #[derive(Debug, Parser)]
 pub(crate) struct Accept {
-  #[arg(long, help = "Assert offer is for <AMOUNT>")]
-  amount: Amount,
+  #[arg(long, help = "Assert offer or listing is for <AMOUNT>")]
+  pub(crate) amount: Amount,
+  #[arg(
+    long,
+    help = "Buy <DECIMAL:DUNE> from listing",
+    value_name = "DECIMAL:DUNE",
+    conflicts_with = "inscription"
+  )]
+  pub(crate) dune: Option<Outgoing>,
   #[arg(long, help = "Don't sign or broadcast transaction")]
-  dry_run: bool,
-  #[arg(long, help = "Assert offer is for <INSCRIPTION>")]
-  inscription: InscriptionId,
-  #[arg(long, help = "Accept <PSBT> offer")]
-  psbt: String,
+  pub(crate) dry_run: bool,
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`, when buying from listing"
+  )]
+  pub(crate) fee_rate: Option<FeeRate>,
+  #[arg(
+    long,
+    help = "Assert offer or listing is for <INSCRIPTION>. May be given more than once for bundle listings"
+  )]
+  pub(crate) inscription: Vec<InscriptionId>,
+  #[arg(long, help = "Accept <PSBT> offer or listing")]
+  pub(crate) psbt: String,
 }
 
 impl Accept {
@@ -29,6 +47,24 @@ impl Accept {
 
     let psbt = Psbt::deserialize(&psbt).context("failed to deserialize PSBT")?;
 
+    if psbt
+      .unsigned_tx
+      .input
+      .first()
+      .is_some_and(|input| input.previous_output == OutPoint::null())
+    {
+      return self.accept_listing(wallet, psbt);
+    }
+
+    let [expected] = self.inscription[..] else {
+      bail!("offer must be accepted with exactly one `--inscription`");
+    };
+
+    ensure! {
+      self.dune.is_none(),
+      "`--dune` may only be used to accept listings",
+    }
+
     let mut outgoing = BTreeMap::new();
 
     for (index, input) in psbt.unsigned_tx.input.iter().enumerate() {
@@ -69,7 +105,7 @@ impl Accept {
     };
 
     ensure! {
-      inscription == self.inscription,
+      inscription == expected,
       "unexpected outgoing inscription {inscription}",
     }
 
@@ -150,6 +186,350 @@ impl Accept {
     Ok(Some(Box::new(Output { txid })))
   }
 
+  fn accept_listing(&self, wallet: Wallet, listing: Psbt) -> SubcommandResult {
+    let Some(fee_rate) = self.fee_rate else {
+      bail!("`--fee-rate` is required to accept listings");
+    };
+
+    ensure! {
+      listing.unsigned_tx.input.len() == listing.unsigned_tx.output.len(),
+      "listing has {} inputs but {} outputs",
+      listing.unsigned_tx.input.len(),
+      listing.unsigned_tx.output.len(),
+    }
+
+    let dune = match &self.dune {
+      Some(Outgoing::Dune { decimal, dune }) => {
+        let (id, entry, _parent) = wallet
+          .get_dune(dune.dune)?
+          .with_context(|| format!("dune `{}` has not been etched", dune.dune))?;
+
+        Some((id, *dune, *decimal, decimal.to_integer(entry.divisibility)?))
+      }
+      Some(outgoing) => bail!("`--dune` must be of the form DECIMAL:DUNE, not `{outgoing}`"),
+      None => None,
+    };
+
+    ensure! {
+      dune.is_some() || !self.inscription.is_empty(),
+      "listing must be accepted with `--dune` or `--inscription`",
+    }
+
+    let signatures = Self::psbt_signatures(&listing)?;
+
+    let mut expected = self
+      .inscription
+      .iter()
+      .collect::<BTreeSet<&InscriptionId>>();
+    let mut filled = 0;
+    let mut lots = Vec::new();
+
+    for (i, txin) in listing.unsigned_tx.input.iter().enumerate().skip(1) {
+      if let Some((.., amount)) = dune
+        && filled == amount
+      {
+        break;
+      }
+
+      let outpoint = txin.previous_output;
+
+      let Some(signature) = &signatures[i] else {
+        bail!("listing input `{outpoint}` is unsigned: listing inputs must be signed");
+      };
+
+      ensure! {
+        Self::signed_single_anyone_can_pay(signature),
+        "listing input `{outpoint}` is not signed with SIGHASH_SINGLE|ANYONECANPAY",
+      }
+
+      let output = wallet
+        .get_output(outpoint)?
+        .with_context(|| format!("listing input `{outpoint}` not found in index"))?;
+
+      ensure! {
+        !output.spent,
+        "listing input `{outpoint}` has already been spent",
+      }
+
+      let Some(inscriptions) = output.inscriptions else {
+        bail!("index must have inscription index to accept listing");
+      };
+
+      let Some(dunes) = output.dunes else {
+        bail!("index must have dune index to accept listing");
+      };
+
+      if let Some((_, spaced_dune, decimal, amount)) = dune {
+        ensure! {
+          inscriptions.is_empty(),
+          "listing input `{outpoint}` contains inscriptions",
+        }
+
+        let [(dune, pile)] = dunes.iter().collect::<Vec<_>>()[..] else {
+          bail!("listing input `{outpoint}` does not contain only {spaced_dune}");
+        };
+
+        ensure! {
+          dune.dune == spaced_dune.dune,
+          "listing input `{outpoint}` contains {dune}, not {spaced_dune}",
+        }
+
+        filled += pile.amount;
+
+        ensure! {
+          filled <= amount,
+          "listing outputs cannot be combined into exactly {decimal} {spaced_dune}",
+        }
+      } else {
+        ensure! {
+          dunes.is_empty(),
+          "listing input `{outpoint}` contains dunes",
+        }
+
+        for inscription in inscriptions {
+          ensure! {
+            expected.remove(&inscription),
+            "listing input `{outpoint}` contains unexpected inscription {inscription}",
+          }
+        }
+      }
+
+      lots.push((
+        i,
+        TxOut {
+          value: Amount::from_sat(output.value),
+          script_pubkey: output.script_pubkey,
+        },
+      ));
+    }
+
+    if let Some((_, spaced_dune, decimal, amount)) = dune {
+      ensure! {
+        filled == amount,
+        "listing does not contain {decimal} {spaced_dune}",
+      }
+    } else {
+      if let Some(inscription) = expected.first() {
+        bail!("listing does not contain inscription {inscription}");
+      }
+
+      ensure! {
+        lots.len() == listing.unsigned_tx.input.len() - 1,
+        "inscription listings must be accepted in full",
+      }
+    }
+
+    let lot_value = lots.iter().map(|(_, txout)| txout.value).sum::<Amount>();
+
+    let payment = lots
+      .iter()
+      .map(|(i, _)| listing.unsigned_tx.output[*i].value)
+      .sum::<Amount>();
+
+    let price = payment
+      .checked_sub(lot_value)
+      .context("listing pays less than the value of its inputs")?;
+
+    ensure! {
+      price == self.amount,
+      "unexpected listing price of {} DOGE",
+      price.to_float_in(bitcoin::Denomination::Bitcoin),
+    }
+
+    let runic_outputs = wallet.get_runic_outputs()?.unwrap_or_default();
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    // The buyer's smallest cardinal output is spent first, so that the sats
+    // and dunes of every lot flow into output 0 which pays the buyer.
+    let (padding, padding_output) = wallet
+      .utxos()
+      .iter()
+      .filter(|(outpoint, _)| {
+        !runic_outputs.contains(outpoint)
+          && !inscribed_outputs.contains(outpoint)
+          && !wallet.locked_utxos().contains_key(outpoint)
+      })
+      .min_by_key(|(_, txout)| txout.value)
+      .ok_or_else(|| anyhow!("wallet contains no cardinal utxos"))?;
+
+    let mut input = vec![TxIn {
+      previous_output: *padding,
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+      witness: Witness::new(),
+    }];
+
+    let mut output = vec![TxOut {
+      value: padding_output.value + lot_value,
+      script_pubkey: wallet.get_receive_address()?.into(),
+    }];
+
+    for (i, _) in &lots {
+      input.push(listing.unsigned_tx.input[*i].clone());
+      output.push(listing.unsigned_tx.output[*i].clone());
+    }
+
+    if let Some((id, ..)) = dune {
+      output.push(TxOut {
+        value: Amount::ZERO,
+        script_pubkey: Dunestone {
+          edicts: vec![Edict {
+            id,
+            amount: 0,
+            output: 0,
+          }],
+          ..default()
+        }
+        .encipher(),
+      });
+    }
+
+    let unfunded = Transaction {
+      version: listing.unsigned_tx.version,
+      lock_time: LockTime::ZERO,
+      input,
+      output,
+    };
+
+    let input_weights = lots
+      .iter()
+      .map(|(i, _)| {
+        let input = &listing.inputs[*i];
+
+        fund_raw_transaction::InputWeight {
+          txid: listing.unsigned_tx.input[*i].previous_output.txid,
+          vout: listing.unsigned_tx.input[*i].previous_output.vout,
+          weight: TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: input.final_script_sig.clone().unwrap_or_default(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: input.final_script_witness.clone().unwrap_or_default(),
+          }
+          .segwit_weight()
+          .to_wu()
+          .try_into()
+          .unwrap(),
+        }
+      })
+      .collect();
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let tx = fund_raw_transaction(
+      wallet.dogecoin_client(),
+      fee_rate,
+      &unfunded,
+      Some(input_weights),
+    )?;
+
+    let tx = consensus::encode::deserialize::<Transaction>(&tx)?;
+
+    ensure! {
+      tx.input[..unfunded.input.len()] == unfunded.input[..]
+        && tx.output[..unfunded.output.len()] == unfunded.output[..],
+      "funded transaction does not preserve listing inputs and outputs",
+    }
+
+    if let Some((id, ..)) = dune {
+      let Some(Artifact::Dunestone(dunestone)) = Dunestone::decipher(&tx) else {
+        bail!("funded transaction does not contain valid dunestone");
+      };
+
+      ensure! {
+        dunestone.edicts == [Edict { id, amount: 0, output: 0 }],
+        "funded transaction contains unexpected dunestone",
+      }
+    }
+
+    if self.dry_run {
+      return Ok(Some(Box::new(Output {
+        txid: tx.compute_txid(),
+      })));
+    }
+
+    let mut psbt = Psbt::from_unsigned_tx(tx)?;
+
+    for (lot, (i, _)) in lots.iter().enumerate() {
+      psbt.inputs[lot + 1] = listing.inputs[*i].clone();
+    }
+
+    let signatures = Self::psbt_signatures(&psbt)?;
+
+    let signed_psbt = wallet
+      .dogecoin_client()
+      .wallet_process_psbt(&base64_encode(&psbt.serialize()), Some(true), None, None)?
+      .psbt;
+
+    let signed_tx = wallet
+      .dogecoin_client()
+      .finalize_psbt(&signed_psbt, None)?
+      .hex
+      .ok_or_else(|| anyhow!("unable to sign transaction"))?;
+
+    {
+      let signed_tx = Transaction::consensus_decode(&mut signed_tx.as_slice())
+        .context("unable to decode finalized transaction")?;
+
+      ensure! {
+        signed_tx.input.len() == psbt.unsigned_tx.input.len(),
+        "signed transaction input length mismatch",
+      }
+
+      for (i, (old, new)) in signatures
+        .into_iter()
+        .zip(Self::tx_signatures(&signed_tx)?)
+        .enumerate()
+      {
+        let outpoint = signed_tx.input[i].previous_output;
+
+        if (1..=lots.len()).contains(&i) {
+          ensure! {
+            old == new,
+            "listing input `{outpoint}` signature changed after signing",
+          }
+        } else {
+          ensure! {
+            new.is_some(),
+            "buyer input `{outpoint}` was not signed by wallet",
+          }
+        }
+      }
+    }
+
+    let txid = wallet.send_raw_transaction(&signed_tx, None)?;
+
+    Ok(Some(Box::new(Output { txid })))
+  }
+
+  /// Whether the first signature in `signature`, the only one for the single
+  /// key inputs that listings are made of, commits to
+  /// `SIGHASH_SINGLE|ANYONECANPAY`, so that the buyer may add inputs and
+  /// outputs without invalidating it.
+  fn signed_single_anyone_can_pay(signature: &Signature) -> bool {
+    let bytes = match signature {
+      Signature::Script(script) => match script.instructions().next() {
+        Some(Ok(bitcoin::script::Instruction::PushBytes(bytes))) => bytes.as_bytes(),
+        _ => return false,
+      },
+      Signature::Witness(witness) => match witness.nth(0) {
+        Some(bytes) => bytes,
+        None => return false,
+      },
+    };
+
+    if let Ok(signature) = bitcoin::ecdsa::Signature::from_slice(bytes) {
+      return signature.sighash_type == EcdsaSighashType::SinglePlusAnyoneCanPay;
+    }
+
+    bitcoin::taproot::Signature::from_slice(bytes)
+      .is_ok_and(|signature| signature.sighash_type == TapSighashType::SinglePlusAnyoneCanPay)
+  }
+
   fn psbt_signatures(psbt: &Psbt) -> Result<Vec<Option<Signature>>> {
     psbt
       .inputs
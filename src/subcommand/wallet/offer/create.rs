@@ -92,22 +92,7 @@ impl Create {
     }
 
     if let Some(url) = &self.submit {
-      let response = reqwest::blocking::Client::new()
-        .post(url)
-        .body(result.psbt.as_bytes().to_vec())
-        .send()
-        .context("failed to submit PSBT")?;
-
-      let status = response.status();
-
-      let text = response
-        .text()
-        .context("failed to receive submission response body")?;
-
-      ensure! {
-        status == StatusCode::OK,
-        "submission failed with status code {status}: {text}",
-      }
+      submit(url, &result.psbt)?;
     }
 
     Ok(Some(Box::new(Output {
@@ -0,0 +1,329 @@
+use {super::*, bitcoin::sighash::EcdsaSighashType};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Output {
+  pub psbt: String,
+  pub lots: Vec<OutPoint>,
+  pub price: u64,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct List {
+  #[arg(
+    long,
+    help = "List <INSCRIPTION> for sale. May be given more than once to list a bundle."
+  )]
+  inscription: Vec<InscriptionId>,
+  #[arg(
+    long,
+    help = "List <DECIMAL:DUNE> for sale.",
+    value_name = "DECIMAL:DUNE",
+    conflicts_with = "inscription",
+    required_unless_present = "inscription"
+  )]
+  dune: Option<Outgoing>,
+  #[arg(long, help = "Sell for <PRICE>.")]
+  price: Amount,
+  #[arg(long, help = "Submit listing to <URL>.", value_name = "URL")]
+  submit: Option<String>,
+}
+
+impl List {
+  pub(crate) fn run(&self, wallet: Wallet) -> SubcommandResult {
+    let lots = match &self.dune {
+      Some(Outgoing::Dune { decimal, dune }) => Self::dune_lots(&wallet, *decimal, *dune)?,
+      Some(outgoing) => bail!("`--dune` must be of the form DECIMAL:DUNE, not `{outgoing}`"),
+      None => self.inscription_lots(&wallet)?,
+    };
+
+    ensure!(!lots.is_empty(), "listing contains no outputs");
+
+    let psbt = sign_listing(&wallet, &unsigned_listing(&wallet, &lots, self.price)?)?;
+
+    if let Some(url) = &self.submit {
+      submit(url, &psbt)?;
+    }
+
+    Ok(Some(Box::new(Output {
+      psbt,
+      lots: lots.into_iter().map(|(outpoint, _)| outpoint).collect(),
+      price: self.price.to_sat(),
+    })))
+  }
+
+  fn inscription_lots(&self, wallet: &Wallet) -> Result<Vec<(OutPoint, u128)>> {
+    let inscription_info = wallet.inscription_info();
+
+    let mut outpoints = BTreeSet::new();
+
+    for inscription in &self.inscription {
+      let info = inscription_info
+        .get(inscription)
+        .ok_or_else(|| anyhow!("inscription {inscription} not in wallet"))?;
+
+      outpoints.insert(info.satpoint.outpoint);
+    }
+
+    let mut lots = Vec::new();
+
+    for outpoint in outpoints {
+      let Some(inscriptions) = wallet.get_inscriptions_in_output(&outpoint)? else {
+        bail!("index must have inscription index to list inscriptions");
+      };
+
+      for inscription in inscriptions {
+        ensure! {
+          self.inscription.contains(&inscription),
+          "output {outpoint} contains unlisted inscription {inscription}",
+        }
+      }
+
+      if let Some(dunes) = wallet.get_dunes_balances_in_output(&outpoint)? {
+        ensure! {
+          dunes.is_empty(),
+          "output {outpoint} contains dunes",
+        }
+      }
+
+      lots.push((outpoint, 1));
+    }
+
+    Ok(lots)
+  }
+
+  fn dune_lots(
+    wallet: &Wallet,
+    decimal: Decimal,
+    spaced_dune: SpacedDune,
+  ) -> Result<Vec<(OutPoint, u128)>> {
+    ensure!(
+      wallet.has_dune_index(),
+      "listing dunes requires index created with `--index-dunes` flag",
+    );
+
+    let (_id, entry, _parent) = wallet
+      .get_dune(spaced_dune.dune)?
+      .with_context(|| format!("dune `{}` has not been etched", spaced_dune.dune))?;
+
+    let amount = decimal.to_integer(entry.divisibility)?;
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    let mut lots = Vec::new();
+    let mut total = 0;
+
+    for outpoint in wallet.get_runic_outputs()?.unwrap_or_default() {
+      if total == amount {
+        break;
+      }
+
+      if inscribed_outputs.contains(&outpoint) {
+        continue;
+      }
+
+      let dunes = wallet
+        .get_dunes_balances_in_output(&outpoint)?
+        .unwrap_or_default();
+
+      let [(dune, pile)] = dunes.iter().collect::<Vec<_>>()[..] else {
+        continue;
+      };
+
+      if dune.dune != spaced_dune.dune || total + pile.amount > amount {
+        continue;
+      }
+
+      total += pile.amount;
+      lots.push((outpoint, pile.amount));
+    }
+
+    ensure! {
+      total == amount,
+      "wallet outputs holding only {spaced_dune} cannot be combined into exactly {decimal} {spaced_dune}: use `dog wallet split` to create outputs of the amounts to list",
+    }
+
+    Ok(lots)
+  }
+}
+
+/// Build an unsigned listing of `lots`, splitting `price` between them in
+/// proportion to their weights. Input and output 0 are placeholders for the
+/// buyer, so that each lot input is signed together with the payment output
+/// at the same index.
+pub(crate) fn unsigned_listing(
+  wallet: &Wallet,
+  lots: &[(OutPoint, u128)],
+  price: Amount,
+) -> Result<Psbt> {
+  let seller_address = wallet.get_receive_address()?;
+
+  let prices = allocate(
+    price,
+    &lots
+      .iter()
+      .map(|(_, weight)| *weight)
+      .collect::<Vec<u128>>(),
+    policy::dust_limit(&seller_address.script_pubkey()),
+  )?;
+
+  let mut input = vec![TxIn {
+    previous_output: OutPoint::null(),
+    script_sig: ScriptBuf::new(),
+    sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+    witness: Witness::new(),
+  }];
+
+  let mut output = vec![TxOut {
+    value: Amount::ZERO,
+    script_pubkey: ScriptBuf::new(),
+  }];
+
+  for ((outpoint, _), price) in lots.iter().zip(prices) {
+    let value = wallet
+      .utxos()
+      .get(outpoint)
+      .ok_or_else(|| anyhow!("output {outpoint} not in wallet"))?
+      .value;
+
+    input.push(TxIn {
+      previous_output: *outpoint,
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+      witness: Witness::new(),
+    });
+
+    output.push(TxOut {
+      value: value + price,
+      script_pubkey: seller_address.script_pubkey(),
+    });
+  }
+
+  Ok(Psbt::from_unsigned_tx(Transaction {
+    version: Version(2),
+    lock_time: LockTime::ZERO,
+    input,
+    output,
+  })?)
+}
+
+/// Sign every lot of a listing with `SIGHASH_SINGLE|ANYONECANPAY`, returning
+/// the base64 encoded PSBT.
+pub(crate) fn sign_listing(wallet: &Wallet, psbt: &Psbt) -> Result<String> {
+  let result = wallet.dogecoin_client().wallet_process_psbt(
+    &base64_encode(&psbt.serialize()),
+    Some(true),
+    Some(EcdsaSighashType::SinglePlusAnyoneCanPay.into()),
+    None,
+  )?;
+
+  let signed = Psbt::deserialize(&base64_decode(&result.psbt)?)?;
+
+  for (input, txin) in signed.inputs.iter().zip(&psbt.unsigned_tx.input).skip(1) {
+    ensure! {
+      input.final_script_sig.is_some() || input.final_script_witness.is_some(),
+      "failed to sign listing input `{}`", txin.previous_output,
+    }
+  }
+
+  Ok(result.psbt)
+}
+
+/// Split `price` between lots in proportion to `weights`. Lots priced below
+/// `dust` are an error, since rounding could otherwise give a lot away.
+fn allocate(price: Amount, weights: &[u128], dust: Amount) -> Result<Vec<Amount>> {
+  // Scale weights down to 64 bits so that multiplying by price can't overflow
+  let shift = 64u32.saturating_sub(weights.iter().sum::<u128>().leading_zeros());
+
+  let weights = weights
+    .iter()
+    .map(|weight| weight >> shift)
+    .collect::<Vec<u128>>();
+
+  let total = weights.iter().sum::<u128>();
+
+  let mut remaining = price.to_sat();
+
+  let mut prices = weights
+    .iter()
+    .map(|weight| {
+      let share = u64::try_from(u128::from(price.to_sat()) * weight / total).unwrap();
+      remaining -= share;
+      Amount::from_sat(share)
+    })
+    .collect::<Vec<Amount>>();
+
+  if let Some(last) = prices.last_mut() {
+    *last += Amount::from_sat(remaining);
+  }
+
+  for (lot, lot_price) in prices.iter().enumerate() {
+    ensure! {
+      *lot_price > Amount::ZERO && *lot_price >= dust,
+      "price of {} DOGE only allocates {} DOGE to lot {lot}, below the dust limit of {} DOGE: raise the price or list fewer lots",
+      price.to_float_in(bitcoin::Denomination::Bitcoin),
+      lot_price.to_float_in(bitcoin::Denomination::Bitcoin),
+      dust.to_float_in(bitcoin::Denomination::Bitcoin),
+    }
+  }
+
+  Ok(prices)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn allocate_splits_price_by_weight() {
+    assert_eq!(
+      allocate(Amount::from_sat(100), &[1, 1, 1], Amount::ZERO).unwrap(),
+      [33, 33, 34].map(Amount::from_sat),
+    );
+
+    assert_eq!(
+      allocate(Amount::from_sat(1000), &[500, 250, 250], Amount::ZERO).unwrap(),
+      [500, 250, 250].map(Amount::from_sat),
+    );
+
+    assert_eq!(
+      allocate(Amount::from_sat(7), &[1], Amount::ZERO).unwrap(),
+      [Amount::from_sat(7)],
+    );
+
+    assert_eq!(
+      allocate(
+        Amount::from_sat(u64::MAX),
+        &[u128::MAX / 2, u128::MAX / 2],
+        Amount::ZERO,
+      )
+      .unwrap(),
+      [u64::MAX / 2, u64::MAX / 2 + 1].map(Amount::from_sat),
+    );
+  }
+
+  #[test]
+  fn allocate_rejects_lots_priced_below_dust() {
+    assert_eq!(
+      allocate(Amount::from_sat(100), &[1, 1000], Amount::ZERO)
+        .unwrap_err()
+        .to_string(),
+      "price of 0.000001 DOGE only allocates 0 DOGE to lot 0, below the dust limit of 0 DOGE: raise the price or list fewer lots",
+    );
+
+    assert_eq!(
+      allocate(Amount::from_sat(1500), &[1, 1, 1], Amount::from_sat(546))
+        .unwrap_err()
+        .to_string(),
+      "price of 0.000015 DOGE only allocates 0.000005 DOGE to lot 0, below the dust limit of 0.00000546 DOGE: raise the price or list fewer lots",
+    );
+
+    assert_eq!(
+      allocate(Amount::from_sat(1638), &[1, 1, 1], Amount::from_sat(546)).unwrap(),
+      [546, 546, 546].map(Amount::from_sat),
+    );
+  }
+}
@@ -4,11 +4,14 @@ use super::*;
 pub(crate) struct Send {
   #[arg(long, help = "Don't sign or broadcast transaction")]
   pub(crate) dry_run: bool,
-  #[arg(long, help = "Use fee rate of <FEE_RATE> koinu/vB")]
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`"
+  )]
   fee_rate: FeeRate,
   #[arg(
     long,
-    help = "Target <AMOUNT> postage with sent inscriptions. [default: 10000 sat]",
+    help = "Target <AMOUNT> postage with sent inscriptions. [default: 0.01 DOGE]",
     value_name = "AMOUNT"
   )]
   pub(crate) postage: Option<Amount>,
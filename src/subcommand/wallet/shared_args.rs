@@ -12,7 +12,10 @@ pub(super) struct SharedArgs {
     help = "Compress inscription content and properties with brotli."
   )]
   pub(crate) compress: bool,
-  #[arg(long, help = "Use fee rate of <FEE_RATE> koinu/vB.")]
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`."
+  )]
   pub(crate) fee_rate: FeeRate,
   #[arg(long, help = "Don't sign or broadcast transactions.")]
   pub(crate) dry_run: bool,
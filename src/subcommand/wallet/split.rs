@@ -63,11 +63,14 @@ impl std::error::Error for Error {}
 pub(crate) struct Split {
   #[arg(long, help = "Don't sign or broadcast transaction")]
   pub(crate) dry_run: bool,
-  #[arg(long, help = "Use fee rate of <FEE_RATE> koinu/vB")]
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`"
+  )]
   fee_rate: FeeRate,
   #[arg(
     long,
-    help = "Include <AMOUNT> postage with change output. [default: 10000 sat]",
+    help = "Include <AMOUNT> postage with change output. [default: 0.01 DOGE]",
     value_name = "AMOUNT"
   )]
   pub(crate) postage: Option<Amount>,
@@ -168,9 +171,9 @@ impl Split {
 
     let change_script_pubkey = change_address.script_pubkey();
 
-    let change_dust_threshold = change_script_pubkey.minimal_non_dust();
+    let change_dust_threshold = policy::dust_limit(&change_script_pubkey);
 
-    if postage < change_script_pubkey.minimal_non_dust() {
+    if postage < change_dust_threshold {
       return Err(Error::DustPostage {
         value: postage,
         threshold: change_dust_threshold,
@@ -285,7 +288,7 @@ impl Split {
 
     for (i, split_output) in splits.outputs.iter().enumerate() {
       let script_pubkey = split_output.address.script_pubkey();
-      let threshold = script_pubkey.minimal_non_dust();
+      let threshold = policy::dust_limit(&script_pubkey);
       let value = split_output.value.unwrap_or(threshold);
       if value < threshold {
         return Err(Error::DustOutput {
@@ -316,7 +319,7 @@ impl Split {
     };
 
     for output in &tx.output {
-      assert!(output.value >= output.script_pubkey.minimal_non_dust());
+      assert!(output.value >= policy::dust_limit(&output.script_pubkey));
     }
 
     assert_eq!(
@@ -370,7 +373,7 @@ mod tests {
       .unwrap_err(),
       Error::DustPostage {
         value: Amount::from_sat(100),
-        threshold: Amount::from_sat(294),
+        threshold: policy::DUST_LIMIT,
       },
     );
   }
@@ -586,7 +589,7 @@ mod tests {
       .unwrap_err(),
       Error::DustOutput {
         value: Amount::from_sat(1),
-        threshold: Amount::from_sat(294),
+        threshold: policy::DUST_LIMIT,
         output: 0,
       }
     );
@@ -602,7 +605,7 @@ mod tests {
             splitfile::Output {
               address: address(0),
               dunes: [(Dune(0), 1000)].into(),
-              value: Some(Amount::from_sat(1000)),
+              value: Some(Amount::from_sat(1_000_000)),
             },
             splitfile::Output {
               address: address(0),
@@ -628,7 +631,7 @@ mod tests {
       .unwrap_err(),
       Error::DustOutput {
         value: Amount::from_sat(10),
-        threshold: Amount::from_sat(294),
+        threshold: policy::DUST_LIMIT,
         output: 1,
       }
     );
@@ -694,7 +697,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -766,7 +769,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -808,7 +811,7 @@ mod tests {
       false,
       balances,
       &change,
-      Some(Amount::from_sat(500)),
+      Some(Amount::from_sat(1_500_000)),
       &splits,
     )
     .unwrap();
@@ -841,11 +844,11 @@ mod tests {
           },
           TxOut {
             script_pubkey: change.into(),
-            value: Amount::from_sat(500),
+            value: Amount::from_sat(1_500_000),
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -915,7 +918,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -985,7 +988,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(330),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -1056,7 +1059,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -1134,7 +1137,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -1214,11 +1217,11 @@ mod tests {
           },
           TxOut {
             script_pubkey: address(0).into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           },
           TxOut {
             script_pubkey: address(1).into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -1315,7 +1318,7 @@ mod tests {
           },
           TxOut {
             script_pubkey: address.into(),
-            value: Amount::from_sat(294),
+            value: policy::DUST_LIMIT,
           }
         ],
       },
@@ -1414,7 +1417,7 @@ mod tests {
           } else {
             TxOut {
               script_pubkey: address(i - 1).into(),
-              value: Amount::from_sat(294),
+              value: policy::DUST_LIMIT,
             }
           })
           .collect()
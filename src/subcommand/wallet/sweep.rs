@@ -24,7 +24,10 @@ pub(crate) struct Sweep {
   address_type: AddressType,
   #[arg(long, help = "Don't sign or broadcast transaction")]
   dry_run: bool,
-  #[arg(long, help = "Use fee rate of <FEE_RATE> koinu/vB")]
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> koinu/vB, or DOGE/kB if suffixed with `doge/kB`"
+  )]
   fee_rate: FeeRate,
 }
 
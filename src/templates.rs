@@ -8,6 +8,12 @@ pub(crate) use {
   children::ChildrenHtml,
   clock::ClockSvg,
   collections::CollectionsHtml,
+  dns::DnsHtml,
+  dns_name::DnsNameHtml,
+  dogemap::DogemapHtml,
+  dogemaps::DogemapsHtml,
+  drc20::Drc20Html,
+  drc20_tokens::Drc20TokensHtml,
   dune_not_found::DuneNotFoundHtml,
   galleries::GalleriesHtml,
   gallery::GalleryHtml,
@@ -28,6 +34,7 @@ pub(crate) use {
     PreviewModelHtml, PreviewPdfHtml, PreviewTextHtml, PreviewUnknownHtml, PreviewVideoHtml,
   },
   rare::RareTxt,
+  text_search::TextSearchHtml,
 };
 
 pub use {
@@ -42,6 +49,12 @@ pub mod blocks;
 mod children;
 mod clock;
 pub mod collections;
+mod dns;
+mod dns_name;
+mod dogemap;
+mod dogemaps;
+mod drc20;
+mod drc20_tokens;
 pub mod dune;
 pub mod dune_not_found;
 pub mod dunes;
@@ -64,6 +77,7 @@ mod parents;
 mod preview;
 mod rare;
 pub mod status;
+mod text_search;
 pub mod transaction;
 
 #[derive(Boilerplate)]
@@ -7,8 +7,11 @@ pub(crate) struct AddressHtml {
   pub(crate) inscriptions: Option<Vec<InscriptionId>>,
   pub(crate) outputs: Vec<OutPoint>,
   pub(crate) dunes_balances: Option<Vec<(SpacedDune, Decimal, Option<char>)>>,
+  pub(crate) drc20_balances: BTreeMap<String, api::Drc20Balance>,
+  pub(crate) names: Vec<String>,
+  pub(crate) dogemaps: Vec<u32>,
   pub(crate) sat_balance: u64,
-  pub(crate) lazy_lookup: bool,
+  pub(crate) lazy_lookup: Option<bool>,
 }
 
 impl PageContent for AddressHtml {
@@ -28,7 +31,17 @@ mod tests {
       outputs: vec![outpoint(1), outpoint(2)],
       inscriptions: Some(vec![inscription_id(1)]),
       sat_balance: 99,
-      lazy_lookup: false,
+      lazy_lookup: None,
+      drc20_balances: [(
+        "doge".to_string(),
+        api::Drc20Balance {
+          available: 1000,
+          transferable: 50,
+        },
+      )]
+      .into(),
+      names: vec!["satoshi.doge".to_string()],
+      dogemaps: vec![5056597],
       dunes_balances: Some(vec![
         (
           SpacedDune {
@@ -84,6 +97,27 @@ mod tests {
     assert_regex_match!(address_html, expected_pattern);
   }
 
+  #[test]
+  fn test_drc20_balances_rendering() {
+    let address_html = setup();
+    let expected_pattern = r#".*<dt>drc-20 balances</dt>\n\s*<dd><a class=monospace href=/drc20/doge>doge</a>: 1000 available, 50 transferable</dd>.*"#;
+    assert_regex_match!(address_html, expected_pattern);
+  }
+
+  #[test]
+  fn test_names_rendering() {
+    let address_html = setup();
+    let expected_pattern = r#".*<dt>names</dt>\n\s*<dd><a class=monospace href=/dns/satoshi.doge>satoshi.doge</a></dd>.*"#;
+    assert_regex_match!(address_html, expected_pattern);
+  }
+
+  #[test]
+  fn test_dogemaps_rendering() {
+    let address_html = setup();
+    let expected_pattern = r#".*<dt>dogemaps</dt>\n\s*<dd><a class=monospace href=/dogemap/5056597>5056597.dogemap</a></dd>.*"#;
+    assert_regex_match!(address_html, expected_pattern);
+  }
+
   #[test]
   fn test_outputs_rendering() {
     let address_html = setup();
@@ -0,0 +1,48 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct DnsHtml {
+  pub(crate) total: u64,
+  pub(crate) namespaces: Vec<(String, Vec<String>)>,
+  pub(crate) prev: Option<usize>,
+  pub(crate) next: Option<usize>,
+}
+
+impl PageContent for DnsHtml {
+  fn title(&self) -> String {
+    "Dogecoin Name System".to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      DnsHtml {
+        total: 2,
+        namespaces: vec![("doge".into(), vec!["jon.doge".into(), "wow.doge".into()])],
+        prev: Some(0),
+        next: Some(2),
+      }
+      .to_string(),
+      "<h1>Dogecoin Name System</h1>
+<dl>
+  <dt>names</dt>
+  <dd>2</dd>
+</dl>
+<h2>.doge</h2>
+<ul>
+  <li><a href=/dns/jon.doge>jon.doge</a></li>
+  <li><a href=/dns/wow.doge>wow.doge</a></li>
+</ul>
+<div class=center>
+    <a class=prev href=/dns/page/0>prev</a>
+      <a class=next href=/dns/page/2>next</a>
+  </div>
+"
+    );
+  }
+}
@@ -0,0 +1,53 @@
+use {super::*, crate::index::DnsEntry};
+
+#[derive(Boilerplate)]
+pub(crate) struct DnsNameHtml {
+  pub(crate) entry: DnsEntry,
+}
+
+impl PageContent for DnsNameHtml {
+  fn title(&self) -> String {
+    format!("Name {}", self.entry.name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_regex_match!(
+      DnsNameHtml {
+        entry: DnsEntry {
+          name: "jon.doge".into(),
+          owner_inscription_id: inscription_id(1),
+          owner_inscription_number: 5,
+          height: 10,
+          timestamp: 0,
+          fee: 0,
+          address: Some("DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into()),
+          avatar: None,
+          reverse: None,
+        },
+      },
+      "<h1>jon.doge</h1>
+<div class=thumbnails>
+  <a href=/inscription/1{64}i1>.*</a>
+</div>
+<dl>
+  <dt>inscription</dt>
+  <dd><a class=collapse href=/inscription/1{64}i1>1{64}i1</a></dd>
+  <dt>inscription number</dt>
+  <dd><a href=/inscription/5>5</a></dd>
+  <dt>owner</dt>
+  <dd><a class=collapse href=/address/DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc>DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc</a></dd>
+  <dt>registered</dt>
+  <dd><a href=/block/10>10</a></dd>
+  <dt>timestamp</dt>
+  <dd><time>1970-01-01 00:00:00 UTC</time></dd>
+</dl>
+"
+    );
+  }
+}
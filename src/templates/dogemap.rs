@@ -0,0 +1,132 @@
+use {
+  super::*,
+  crate::{
+    index::DogemapEntry,
+    subcommand::dogemap::{DogemapParcel, DogemapTransfer},
+  },
+};
+
+#[derive(Boilerplate)]
+pub(crate) struct DogemapHtml {
+  pub(crate) block_number: u32,
+  pub(crate) rarity: &'static str,
+  pub(crate) block_hash: Option<BlockHash>,
+  pub(crate) tx_count: usize,
+  pub(crate) claim: Option<DogemapEntry>,
+  pub(crate) owner: Option<String>,
+  pub(crate) transfers: Vec<DogemapTransfer>,
+  pub(crate) parcels: Vec<DogemapParcel>,
+}
+
+impl PageContent for DogemapHtml {
+  fn title(&self) -> String {
+    format!("Dogemap {}", self.block_number)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unclaimed() {
+    assert_regex_match!(
+      DogemapHtml {
+        block_number: 5,
+        rarity: "common",
+        block_hash: Some(BlockHash::all_zeros()),
+        tx_count: 1,
+        claim: None,
+        owner: None,
+        transfers: Vec::new(),
+        parcels: Vec::new(),
+      },
+      "<h1>Dogemap 5</h1>
+<img class=dogemap src=/dogemap/5/svg alt=.*>
+<dl>
+  <dt>block</dt>
+  <dd><a href=/block/5>5</a></dd>
+  <dt>hash</dt>
+  <dd><a class=collapse href=/block/0{64}>0{64}</a></dd>
+  <dt>transactions</dt>
+  <dd>1</dd>
+  <dt>rarity</dt>
+  <dd>common</dd>
+  <dt>claimed</dt>
+  <dd>false</dd>
+</dl>
+"
+    );
+  }
+
+  #[test]
+  fn claimed() {
+    assert_regex_match!(
+      DogemapHtml {
+        block_number: 5,
+        rarity: "common",
+        block_hash: None,
+        tx_count: 3,
+        claim: Some(DogemapEntry {
+          block_number: 5,
+          owner_inscription_id: inscription_id(1),
+          claim_height: 6,
+          claim_timestamp: 0,
+        }),
+        owner: Some("DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into()),
+        transfers: vec![DogemapTransfer {
+          height: 6,
+          timestamp: 0,
+          location: satpoint(1, 0),
+          from: None,
+          to: Some("DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into()),
+        }],
+        parcels: vec![DogemapParcel {
+          block_number: 5,
+          parcel: 0,
+          inscription_id: inscription_id(2),
+        }],
+      },
+      "<h1>Dogemap 5</h1>
+<img class=dogemap src=/dogemap/5/svg alt=.*>
+<dl>
+  <dt>block</dt>
+  <dd><a href=/block/5>5</a></dd>
+  <dt>transactions</dt>
+  <dd>3</dd>
+  <dt>rarity</dt>
+  <dd>common</dd>
+  <dt>claimed</dt>
+  <dd>true</dd>
+  <dt>claim</dt>
+  <dd><a class=collapse href=/inscription/1{64}i1>1{64}i1</a></dd>
+  <dt>claim height</dt>
+  <dd><a href=/block/6>6</a></dd>
+  <dt>owner</dt>
+  <dd><a class=collapse href=/address/DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc>DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc</a></dd>
+  <dt>parcels</dt>
+  <dd>1 of 3</dd>
+</dl>
+<h2>Parcels</h2>
+<ul>
+  <li><a href=/inscription/2{64}i2>0\\.5\\.dogemap</a></li>
+</ul>
+<h2>History</h2>
+<table>
+  <tr>
+    <th>height</th>
+    <th>from</th>
+    <th>to</th>
+    <th>location</th>
+  </tr>
+  <tr>
+    <td><a href=/block/6>6</a></td>
+    <td></td>
+    <td><a class=collapse href=/address/DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc>DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc</a></td>
+    <td><a class=collapse href=/koinupoint/1{64}:1:0>1{64}:1:0</a></td>
+  </tr>
+</table>
+"
+    );
+  }
+}
@@ -0,0 +1,51 @@
+use {super::*, crate::index::DogemapEntry};
+
+#[derive(Boilerplate)]
+pub(crate) struct DogemapsHtml {
+  pub(crate) entries: Vec<(DogemapEntry, Option<String>)>,
+  pub(crate) total: u64,
+  pub(crate) prev: Option<usize>,
+  pub(crate) next: Option<usize>,
+}
+
+impl PageContent for DogemapsHtml {
+  fn title(&self) -> String {
+    "Dogemaps".to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      DogemapsHtml {
+        entries: vec![(
+          DogemapEntry {
+            block_number: 5,
+            owner_inscription_id: inscription_id(1),
+            claim_height: 6,
+            claim_timestamp: 0,
+          },
+          None,
+        )],
+        total: 1,
+        prev: None,
+        next: Some(1),
+      }
+      .to_string(),
+      "<h1>Dogemaps</h1>
+<p>1 claimed</p>
+<div class=dogemaps>
+  <a href=/dogemap/5><img loading=lazy src=/dogemap/5/svg alt=\"Dogemap 5\" title=\"5.dogemap\"></a>
+</div>
+<div class=center>
+    prev
+      <a class=next href=/dogemaps/1>next</a>
+  </div>
+"
+    );
+  }
+}
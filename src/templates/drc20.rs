@@ -0,0 +1,124 @@
+use {
+  super::*,
+  crate::subcommand::drc20::{Drc20Activity, Drc20Token, format_amount},
+};
+
+#[derive(Boilerplate)]
+pub(crate) struct Drc20Html {
+  pub(crate) token: Drc20Token,
+  pub(crate) holder_count: usize,
+  pub(crate) holders: Vec<(String, u128, u128)>,
+  pub(crate) activity: Vec<Drc20Activity>,
+}
+
+impl Drc20Html {
+  fn amount(&self, amount: u128) -> String {
+    format_amount(amount, self.token.decimals)
+  }
+
+  fn mint_progress(&self) -> Decimal {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Decimal {
+      value: (self.token.minted as f64 / self.token.max_supply as f64 * 10000.0) as u128,
+      scale: 2,
+    }
+  }
+}
+
+impl PageContent for Drc20Html {
+  fn title(&self) -> String {
+    format!("DRC-20 {}", self.token.tick)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_regex_match!(
+      Drc20Html {
+        token: Drc20Token {
+          tick: "DOGI".into(),
+          max_supply: 2100,
+          mint_limit: 100,
+          decimals: 2,
+          minted: 525,
+          deploy_inscription: inscription_id(1).to_string(),
+          deploy_height: 10,
+          deploy_timestamp: 0,
+          deployer: "DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into(),
+          mint_count: 6,
+        },
+        holder_count: 101,
+        holders: vec![("DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into(), 500, 25)],
+        activity: vec![Drc20Activity {
+          op: "mint".into(),
+          amount: 100,
+          from: None,
+          to: Some("DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into()),
+          txid: txid(1),
+          height: 11,
+          timestamp: 0,
+        }],
+      },
+      "<h1>DRC-20 DOGI</h1>
+<dl>
+  <dt>supply</dt>
+  <dd>21</dd>
+  <dt>minted</dt>
+  <dd>5.25</dd>
+  <dt>progress</dt>
+  <dd>25%</dd>
+  <dt>limit per mint</dt>
+  <dd>1</dd>
+  <dt>mints</dt>
+  <dd>6</dd>
+  <dt>decimals</dt>
+  <dd>2</dd>
+  <dt>deploy</dt>
+  <dd><a class=collapse href=/inscription/1{64}i1>1{64}i1</a></dd>
+  <dt>deploy block</dt>
+  <dd><a href=/block/10>10</a></dd>
+  <dt>deployer</dt>
+  <dd><a class=collapse href=/address/DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc>DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc</a></dd>
+  <dt>timestamp</dt>
+  <dd><time>1970-01-01 00:00:00 UTC</time></dd>
+  <dt>holders</dt>
+  <dd>101</dd>
+</dl>
+<h2>Holders</h2>
+<table>
+  <tr>
+    <th>address</th>
+    <th>available</th>
+    <th>transferable</th>
+  </tr>
+  <tr>
+    <td><a class=collapse href=/address/DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc>DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc</a></td>
+    <td>5</td>
+    <td>0.25</td>
+  </tr>
+</table>
+<h2>Recent Activity</h2>
+<table>
+  <tr>
+    <th>operation</th>
+    <th>amount</th>
+    <th>from</th>
+    <th>to</th>
+    <th>transaction</th>
+  </tr>
+  <tr>
+    <td>mint</td>
+    <td>1</td>
+    <td></td>
+    <td><a class=collapse href=/address/DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc>DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc</a></td>
+    <td><a class=collapse href=/tx/1{64}>1{64}</a></td>
+  </tr>
+</table>
+"
+    );
+  }
+}
@@ -0,0 +1,54 @@
+use {super::*, crate::subcommand::drc20::Drc20Token};
+
+#[derive(Boilerplate)]
+pub(crate) struct Drc20TokensHtml {
+  pub(crate) tokens: Vec<Drc20Token>,
+}
+
+impl PageContent for Drc20TokensHtml {
+  fn title(&self) -> String {
+    "DRC-20 Tokens".to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      Drc20TokensHtml {
+        tokens: vec![Drc20Token {
+          tick: "DOGI".into(),
+          max_supply: 2100,
+          mint_limit: 100,
+          decimals: 2,
+          minted: 525,
+          deploy_inscription: inscription_id(1).to_string(),
+          deploy_height: 10,
+          deploy_timestamp: 0,
+          deployer: "DHrqn6H6ocgbRB1Szu7Q1sn1tVTfkpinnc".into(),
+          mint_count: 6,
+        }],
+      }
+      .to_string(),
+      "<h1>DRC-20 Tokens</h1>
+<table>
+  <tr>
+    <th>tick</th>
+    <th>minted</th>
+    <th>supply</th>
+    <th>mints</th>
+  </tr>
+  <tr>
+    <td><a href=/drc20/DOGI>DOGI</a></td>
+    <td>5.25</td>
+    <td>21</td>
+    <td>6</td>
+  </tr>
+</table>
+"
+    );
+  }
+}
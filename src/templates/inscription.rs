@@ -3,7 +3,7 @@ use super::*;
 #[derive(Boilerplate, Default)]
 pub struct InscriptionHtml {
   pub chain: Chain,
-  pub charms: u16,
+  pub charms: u32,
   pub child_count: u64,
   pub children: Vec<InscriptionId>,
   pub fee: u64,
@@ -4,6 +4,7 @@ use super::*;
 pub(crate) struct KoinuHtml {
   pub(crate) address: Option<Address>,
   pub(crate) blocktime: Blocktime,
+  pub(crate) charms: u32,
   pub(crate) inscriptions: Vec<InscriptionId>,
   pub(crate) sat: Koinu,
   pub(crate) satpoint: Option<KoinuPoint>,
@@ -27,6 +28,7 @@ mod tests {
         sat: Koinu(0),
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(0).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: Vec::new(),
       },
       "
@@ -48,6 +50,7 @@ mod tests {
             <span title=coin>🪙</span>
             <span title=mythic>🎃</span>
             <span title=palindrome>🦋</span>
+            <span title=wonky>🎲</span>
           </dd>
         </dl>
         .*
@@ -67,6 +70,7 @@ mod tests {
         sat: Koinu(2099999997689999),
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(2099999997689999).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: Vec::new(),
       },
       "
@@ -97,6 +101,30 @@ mod tests {
     );
   }
 
+  #[test]
+  fn dogecoin_charms() {
+    assert_regex_match!(
+      KoinuHtml {
+        address: None,
+        sat: Height(13_329).starting_sat() + 1,
+        satpoint: None,
+        blocktime: Blocktime::confirmed(0),
+        charms: (Height(13_329).starting_sat() + 1).charms(Chain::Dogecoin.charm_heights()),
+        inscriptions: Vec::new(),
+      },
+      "
+        .*
+          <dt>charms</dt>
+          <dd>
+            <span title=wonky>🎲</span>
+            <span title=jackpot>💰</span>
+          </dd>
+        .*
+      "
+      .unindent()
+    );
+  }
+
   #[test]
   fn sat_with_next_and_prev() {
     assert_regex_match!(
@@ -105,6 +133,7 @@ mod tests {
         sat: Koinu(1),
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(1).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: Vec::new(),
       },
       r"<h1>Koinu 1</h1>.*<a class=prev href=/koinu/0>prev</a>\n<a class=next href=/koinu/2>next</a>.*",
@@ -119,6 +148,7 @@ mod tests {
         sat: Koinu(0),
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(0).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: vec![inscription_id(1)],
       },
       "
@@ -141,6 +171,7 @@ mod tests {
         sat: Koinu(0),
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(0).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: vec![inscription_id(1), inscription_id(2)],
       },
       "
@@ -164,6 +195,7 @@ mod tests {
         sat: Koinu::LAST,
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu::LAST.charms(Chain::Dogecoin.charm_heights()),
         inscriptions: Vec::new(),
       },
       r"<h1>Koinu 2099999997689999</h1>.*<a class=prev href=/koinu/2099999997689998>prev</a>\nnext.*",
@@ -178,6 +210,7 @@ mod tests {
         sat: Koinu(0),
         satpoint: Some(satpoint(1, 0)),
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(0).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: Vec::new(),
       },
       "<h1>Koinu 0</h1>.*<dt>location</dt><dd><a class=collapse href=/satpoint/1{64}:1:0>1{64}:1:0</a></dd>.*",
@@ -192,6 +225,7 @@ mod tests {
         sat: Koinu(0),
         satpoint: Some(satpoint(1, 0)),
         blocktime: Blocktime::confirmed(0),
+        charms: Koinu(0).charms(Chain::Dogecoin.charm_heights()),
         inscriptions: Vec::new(),
       },
       "<h1>Koinu 0</h1>.*<dt>address</dt><dd class=monospace><a href=/address/bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4>bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4</a></dd>.*",
@@ -76,8 +76,11 @@ mod tests {
             inscriptions: Some(Vec::new()),
             outputs: Vec::new(),
             dunes_balances: None,
+            drc20_balances: BTreeMap::new(),
+            names: Vec::new(),
+            dogemaps: Vec::new(),
             sat_balance: 0,
-            lazy_lookup: false,
+            lazy_lookup: None,
           })
         )),
       }
@@ -132,8 +135,11 @@ mod tests {
             inscriptions: Some(Vec::new()),
             outputs: Vec::new(),
             dunes_balances: None,
+            drc20_balances: BTreeMap::new(),
+            names: Vec::new(),
+            dogemaps: Vec::new(),
             sat_balance: 0,
-            lazy_lookup: false,
+            lazy_lookup: None,
           })
         )),
       }
@@ -161,8 +167,11 @@ mod tests {
             inscriptions: Some(Vec::new()),
             outputs: Vec::new(),
             dunes_balances: None,
+            drc20_balances: BTreeMap::new(),
+            names: Vec::new(),
+            dogemaps: Vec::new(),
             sat_balance: 0,
-            lazy_lookup: false,
+            lazy_lookup: None,
           })
         )),
       }
@@ -0,0 +1,65 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct TextSearchHtml {
+  pub(crate) query: String,
+  pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) prev: Option<usize>,
+  pub(crate) next: Option<usize>,
+}
+
+impl PageContent for TextSearchHtml {
+  fn title(&self) -> String {
+    format!("Search: {}", self.query)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn without_results() {
+    assert_regex_match!(
+      TextSearchHtml {
+        query: "such wow".into(),
+        inscriptions: Vec::new(),
+        prev: None,
+        next: None,
+      },
+      "
+        <h1>Inscriptions matching “such wow”</h1>
+        <p>No matching inscriptions.</p>
+        <div class=center>
+        prev
+        next
+        </div>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_prev_and_next() {
+    assert_regex_match!(
+      TextSearchHtml {
+        query: "such wow".into(),
+        inscriptions: vec![inscription_id(1), inscription_id(2)],
+        prev: Some(1),
+        next: Some(3),
+      },
+      "
+        <h1>Inscriptions matching “such wow”</h1>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+          <a href=/inscription/2{64}i2><iframe .* src=/preview/2{64}i2></iframe></a>
+        </div>
+        <div class=center>
+        <a class=prev href=\"/search\\?q=such%20wow&amp;type=text&amp;page=1\">prev</a>
+        <a class=next href=\"/search\\?q=such%20wow&amp;type=text&amp;page=3\">next</a>
+        </div>
+      "
+      .unindent()
+    );
+  }
+}
@@ -84,6 +84,7 @@ pub(crate) struct Wallet {
   output_info: BTreeMap<OutPoint, api::Output>,
   inscriptions: BTreeMap<KoinuPoint, Vec<InscriptionId>>,
   locked_utxos: BTreeMap<OutPoint, TxOut>,
+  name: String,
   settings: Settings,
 }
 
@@ -307,6 +308,19 @@ impl Wallet {
     )
   }
 
+  pub(crate) fn get_output(&self, outpoint: OutPoint) -> Result<Option<api::Output>> {
+    let response = self
+      .ord_client
+      .get(self.rpc_url.join(&format!("/output/{outpoint}")).unwrap())
+      .send()?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+      return Ok(None);
+    }
+
+    Ok(Some(response.error_for_status()?.json()?))
+  }
+
   pub(crate) fn get_dune(
     &self,
     dune: Dune,
@@ -332,6 +346,64 @@ impl Wallet {
     Ok(Some((dune_json.id, dune_json.entry, dune_json.parent)))
   }
 
+  pub(crate) fn get_drc20_token(
+    &self,
+    tick: &str,
+  ) -> Result<Option<crate::subcommand::drc20::Drc20Token>> {
+    let response = self
+      .ord_client
+      .get(
+        self
+          .rpc_url
+          .join(&format!("/r/drc20/token/{tick}"))
+          .unwrap(),
+      )
+      .send()?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+      return Ok(None);
+    }
+
+    Ok(Some(response.error_for_status()?.json()?))
+  }
+
+  pub(crate) fn get_drc20_balance(&self, address: &str, tick: &str) -> Result<api::Drc20Balance> {
+    Ok(
+      self
+        .ord_client
+        .get(
+          self
+            .rpc_url
+            .join(&format!("/r/drc20/balance/{address}/{tick}"))
+            .unwrap(),
+        )
+        .send()?
+        .error_for_status()?
+        .json()?,
+    )
+  }
+
+  pub(crate) fn get_drc20_transfer(
+    &self,
+    outpoint: OutPoint,
+  ) -> Result<Option<crate::subcommand::drc20::Drc20Transfer>> {
+    let response = self
+      .ord_client
+      .get(
+        self
+          .rpc_url
+          .join(&format!("/r/drc20/transfer/{outpoint}"))
+          .unwrap(),
+      )
+      .send()?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+      return Ok(None);
+    }
+
+    Ok(Some(response.error_for_status()?.json()?))
+  }
+
   pub(crate) fn get_change_address(&self) -> Result<Address> {
     Ok(
       self
@@ -368,6 +440,19 @@ impl Wallet {
     self.settings.integration_test()
   }
 
+  /// Rebuild the wallet, picking up outputs and inscriptions created since it
+  /// was constructed.
+  pub(crate) fn reload(self) -> Result<Self> {
+    let name = self.name.clone();
+    let settings = self.settings.clone();
+    let rpc_url = self.rpc_url.clone();
+
+    // the wallet database can only be opened once
+    drop(self);
+
+    wallet_constructor::WalletConstructor::construct(name, false, settings, rpc_url)
+  }
+
   fn is_above_minimum_at_height(&self, dune: Dune) -> Result<bool> {
     Ok(
       dune
@@ -875,6 +960,13 @@ impl Wallet {
     amount: Amount,
     fee_rate: FeeRate,
   ) -> Result<Transaction> {
+    let dust_limit = policy::dust_limit(&destination.script_pubkey());
+
+    ensure!(
+      amount >= dust_limit,
+      "amount {amount} below dust limit {dust_limit}",
+    );
+
     self.lock_non_cardinal_outputs()?;
 
     let unfunded_transaction = Transaction {
@@ -5,7 +5,7 @@ use {
     key::PrivateKey,
     key::{TapTweak, TweakedKeypair, TweakedPublicKey, UntweakedKeypair},
     secp256k1::{self, Secp256k1, XOnlyPublicKey, constants::SCHNORR_SIGNATURE_SIZE, rand},
-    sighash::{Prevouts, SighashCache, TapSighashType},
+    sighash::{EcdsaSighashType, Prevouts, SighashCache, TapSighashType},
     taproot::Signature,
     taproot::{ControlBlock, LeafVersion, TapLeafHash, TaprootBuilder},
   },
@@ -73,7 +73,7 @@ mod tests {
 
   #[test]
   fn reveal_transaction_pays_fee() {
-    let utxos = vec![(outpoint(1), tx_out(20000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(20_000_000, address(0)))];
     let inscription = inscription("text/plain", "ord");
     let commit_address = change(0);
     let reveal_address = recipient_address();
@@ -109,17 +109,18 @@ mod tests {
 
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
-    let fee = Amount::from_sat((1.0 * (reveal_tx.vsize() as f64)).ceil() as u64);
+    let fee = Amount::from_sat((1.0 * (reveal_tx.vsize() as f64)).ceil() as u64)
+      + policy::soft_dust_surcharge(FeeRate::try_from(1.0).unwrap());
 
     assert_eq!(
       reveal_tx.output[0].value.to_sat(),
-      20000 - fee.to_sat() - (20000 - commit_tx.output[0].value.to_sat()),
+      20_000_000 - fee.to_sat() - (20_000_000 - commit_tx.output[0].value.to_sat()),
     );
   }
 
   #[test]
   fn inscribe_transactions_opt_in_to_rbf() {
-    let utxos = vec![(outpoint(1), tx_out(20000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(20_000_000, address(0)))];
     let inscription = inscription("text/plain", "ord");
     let commit_address = change(0);
     let reveal_address = recipient_address();
@@ -157,9 +158,69 @@ mod tests {
     assert!(reveal_tx.is_explicitly_rbf());
   }
 
+  #[test]
+  fn etching_reveal_spends_p2sh_dune_commitment() {
+    let utxos = vec![(outpoint(1), tx_out(100_000_000, address(0)))];
+    let dune = Dune(99_246_114_928_149_462);
+
+    let batch::Transactions {
+      commit_tx,
+      reveal_tx,
+      ..
+    } = batch::Plan {
+      satpoint: Some(satpoint(1, 0)),
+      inscriptions: vec![inscription("text/plain", "ord")],
+      destinations: vec![recipient_address()],
+      postages: vec![TARGET_POSTAGE],
+      etching: Some(batch::Etching {
+        dune: SpacedDune { dune, spacers: 0 },
+        symbol: '¢',
+        divisibility: 0,
+        supply: "1000".parse().unwrap(),
+        premine: "1000".parse().unwrap(),
+        terms: None,
+        turbo: false,
+      }),
+      ..default()
+    }
+    .create_batch_transactions(
+      BTreeMap::new(),
+      Chain::Dogecoin,
+      BTreeSet::new(),
+      BTreeSet::new(),
+      utxos.into_iter().collect(),
+      [change(0), change(1)],
+      change(2),
+    )
+    .unwrap();
+
+    let input = reveal_tx.input.last().unwrap();
+
+    assert_eq!(input.previous_output.txid, commit_tx.compute_txid());
+
+    let redeem_script = redeem_script_from_script_sig(&input.script_sig).unwrap();
+
+    assert_eq!(
+      commit_tx.output[input.previous_output.vout.into_usize()].script_pubkey,
+      ScriptBuf::new_p2sh(&redeem_script.script_hash()),
+    );
+
+    assert_eq!(
+      redeem_script
+        .instructions()
+        .next()
+        .unwrap()
+        .unwrap()
+        .push_bytes()
+        .unwrap()
+        .as_bytes(),
+      dune.commitment(),
+    );
+  }
+
   #[test]
   fn inscribe_with_no_satpoint_and_no_cardinal_utxos() {
-    let utxos = vec![(outpoint(1), tx_out(1000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(1_000_000, address(0)))];
     let mut inscriptions = BTreeMap::new();
     inscriptions.insert(
       KoinuPoint {
@@ -209,8 +270,8 @@ mod tests {
   #[test]
   fn inscribe_with_no_satpoint_and_enough_cardinal_utxos() {
     let utxos = vec![
-      (outpoint(1), tx_out(20_000, address(0))),
-      (outpoint(2), tx_out(20_000, address(0))),
+      (outpoint(1), tx_out(20_000_000, address(0))),
+      (outpoint(2), tx_out(20_000_000, address(0))),
     ];
     let mut inscriptions = BTreeMap::new();
     inscriptions.insert(
@@ -256,8 +317,8 @@ mod tests {
   #[test]
   fn inscribe_with_custom_fee_rate() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(20_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(20_000_000, address(0))),
     ];
     let mut inscriptions = BTreeMap::new();
     inscriptions.insert(
@@ -303,10 +364,12 @@ mod tests {
     .unwrap();
 
     let sig_vbytes = 17;
-    let fee = FeeRate::try_from(fee_rate)
-      .unwrap()
-      .fee(commit_tx.vsize() + sig_vbytes)
-      .to_sat();
+    let fee = policy::fee(
+      FeeRate::try_from(fee_rate).unwrap(),
+      commit_tx.vsize() + sig_vbytes,
+      &commit_tx.output,
+    )
+    .to_sat();
 
     let reveal_value = commit_tx
       .output
@@ -315,24 +378,26 @@ mod tests {
       .reduce(|acc, i| acc + i)
       .unwrap();
 
-    assert_eq!(reveal_value.to_sat(), 20_000 - fee);
+    assert_eq!(reveal_value.to_sat(), 20_000_000 - fee);
 
-    let fee = FeeRate::try_from(fee_rate)
-      .unwrap()
-      .fee(reveal_tx.vsize())
-      .to_sat();
+    let fee = policy::fee(
+      FeeRate::try_from(fee_rate).unwrap(),
+      reveal_tx.vsize(),
+      &reveal_tx.output,
+    )
+    .to_sat();
 
     assert_eq!(
       reveal_tx.output[0].value.to_sat(),
-      20_000 - fee - (20_000 - commit_tx.output[0].value.to_sat()),
+      20_000_000 - fee - (20_000_000 - commit_tx.output[0].value.to_sat()),
     );
   }
 
   #[test]
   fn inscribe_with_parent() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(20_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(20_000_000, address(0))),
     ];
 
     let mut inscriptions = BTreeMap::new();
@@ -346,7 +411,7 @@ mod tests {
       },
       tx_out: TxOut {
         script_pubkey: change(0).script_pubkey(),
-        value: Amount::from_sat(10000),
+        value: Amount::from_sat(10_000_000),
       },
     };
 
@@ -391,10 +456,12 @@ mod tests {
     .unwrap();
 
     let sig_vbytes = 17;
-    let fee = FeeRate::try_from(fee_rate)
-      .unwrap()
-      .fee(commit_tx.vsize() + sig_vbytes)
-      .to_sat();
+    let fee = policy::fee(
+      FeeRate::try_from(fee_rate).unwrap(),
+      commit_tx.vsize() + sig_vbytes,
+      &commit_tx.output,
+    )
+    .to_sat();
 
     let reveal_value = commit_tx
       .output
@@ -403,12 +470,14 @@ mod tests {
       .reduce(|acc, i| acc + i)
       .unwrap();
 
-    assert_eq!(reveal_value.to_sat(), 20_000 - fee);
+    assert_eq!(reveal_value.to_sat(), 20_000_000 - fee);
 
     let sig_vbytes = 16;
-    let fee = FeeRate::try_from(fee_rate)
-      .unwrap()
-      .fee(reveal_tx.vsize() + sig_vbytes);
+    let fee = policy::fee(
+      FeeRate::try_from(fee_rate).unwrap(),
+      reveal_tx.vsize() + sig_vbytes,
+      &reveal_tx.output,
+    );
 
     assert_eq!(fee, commit_tx.output[0].value - reveal_tx.output[1].value);
     assert_eq!(
@@ -429,8 +498,8 @@ mod tests {
   #[test]
   fn inscribe_with_commit_fee_rate() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(20_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(20_000_000, address(0))),
     ];
     let mut inscriptions = BTreeMap::new();
     inscriptions.insert(
@@ -477,10 +546,12 @@ mod tests {
     .unwrap();
 
     let sig_vbytes = 17;
-    let fee = FeeRate::try_from(commit_fee_rate)
-      .unwrap()
-      .fee(commit_tx.vsize() + sig_vbytes)
-      .to_sat();
+    let fee = policy::fee(
+      FeeRate::try_from(commit_fee_rate).unwrap(),
+      commit_tx.vsize() + sig_vbytes,
+      &commit_tx.output,
+    )
+    .to_sat();
 
     let reveal_value = commit_tx
       .output
@@ -489,16 +560,18 @@ mod tests {
       .reduce(|acc, i| acc + i)
       .unwrap();
 
-    assert_eq!(reveal_value.to_sat(), 20_000 - fee);
+    assert_eq!(reveal_value.to_sat(), 20_000_000 - fee);
 
-    let fee = FeeRate::try_from(fee_rate)
-      .unwrap()
-      .fee(reveal_tx.vsize())
-      .to_sat();
+    let fee = policy::fee(
+      FeeRate::try_from(fee_rate).unwrap(),
+      reveal_tx.vsize(),
+      &reveal_tx.output,
+    )
+    .to_sat();
 
     assert_eq!(
       reveal_tx.output[0].value.to_sat(),
-      20_000 - fee - (20_000 - commit_tx.output[0].value.to_sat()),
+      20_000_000 - fee - (20_000_000 - commit_tx.output[0].value.to_sat()),
     );
   }
 
@@ -582,8 +655,8 @@ mod tests {
   #[test]
   fn batch_inscribe_with_parent() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(50_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(50_000_000, address(0))),
     ];
 
     let parent = inscription_id(1);
@@ -597,7 +670,7 @@ mod tests {
       },
       tx_out: TxOut {
         script_pubkey: change(0).script_pubkey(),
-        value: Amount::from_sat(10000),
+        value: Amount::from_sat(10_000_000),
       },
     };
 
@@ -642,7 +715,7 @@ mod tests {
       reveal_fee_rate: fee_rate,
       no_limit: false,
       reinscribe: false,
-      postages: vec![Amount::from_sat(10_000); 3],
+      postages: vec![Amount::from_sat(10_000_000); 3],
       mode,
       ..default()
     }
@@ -658,7 +731,7 @@ mod tests {
     .unwrap();
 
     let sig_vbytes = 17;
-    let fee = fee_rate.fee(commit_tx.vsize() + sig_vbytes).to_sat();
+    let fee = policy::fee(fee_rate, commit_tx.vsize() + sig_vbytes, &commit_tx.output).to_sat();
 
     let reveal_value = commit_tx
       .output
@@ -667,10 +740,10 @@ mod tests {
       .reduce(|acc, i| acc + i)
       .unwrap();
 
-    assert_eq!(reveal_value.to_sat(), 50_000 - fee);
+    assert_eq!(reveal_value.to_sat(), 50_000_000 - fee);
 
     let sig_vbytes = 16;
-    let fee = fee_rate.fee(reveal_tx.vsize() + sig_vbytes);
+    let fee = policy::fee(fee_rate, reveal_tx.vsize() + sig_vbytes, &reveal_tx.output);
 
     assert_eq!(fee, commit_tx.output[0].value - reveal_tx.output[1].value);
     assert_eq!(
@@ -691,12 +764,12 @@ mod tests {
   #[test]
   fn batch_inscribe_satpoints_with_parent() {
     let utxos = vec![
-      (outpoint(1), tx_out(1_111, address(0))),
-      (outpoint(2), tx_out(2_222, address(0))),
-      (outpoint(3), tx_out(3_333, address(0))),
-      (outpoint(4), tx_out(10_000, address(0))),
-      (outpoint(5), tx_out(50_000, address(0))),
-      (outpoint(6), tx_out(60_000, address(0))),
+      (outpoint(1), tx_out(1_111_000, address(0))),
+      (outpoint(2), tx_out(2_222_000, address(0))),
+      (outpoint(3), tx_out(3_333_000, address(0))),
+      (outpoint(4), tx_out(10_000_000, address(0))),
+      (outpoint(5), tx_out(50_000_000, address(0))),
+      (outpoint(6), tx_out(60_000_000, address(0))),
     ];
 
     let parent = inscription_id(1);
@@ -710,7 +783,7 @@ mod tests {
       },
       tx_out: TxOut {
         script_pubkey: change(0).script_pubkey(),
-        value: Amount::from_sat(10_000),
+        value: Amount::from_sat(10_000_000),
       },
     };
 
@@ -772,9 +845,9 @@ mod tests {
       commit_fee_rate: fee_rate,
       reveal_fee_rate: fee_rate,
       postages: vec![
-        Amount::from_sat(1_111),
-        Amount::from_sat(2_222),
-        Amount::from_sat(3_333),
+        Amount::from_sat(1_111_000),
+        Amount::from_sat(2_222_000),
+        Amount::from_sat(3_333_000),
       ],
       mode,
       ..default()
@@ -794,7 +867,7 @@ mod tests {
     .unwrap();
 
     let sig_vbytes = 17;
-    let fee = fee_rate.fee(commit_tx.vsize() + sig_vbytes).to_sat();
+    let fee = policy::fee(fee_rate, commit_tx.vsize() + sig_vbytes, &commit_tx.output).to_sat();
 
     let reveal_value = commit_tx
       .output
@@ -803,7 +876,7 @@ mod tests {
       .reduce(|acc, i| acc + i)
       .unwrap();
 
-    assert_eq!(reveal_value.to_sat(), 50_000 - fee);
+    assert_eq!(reveal_value.to_sat(), 50_000_000 - fee);
 
     assert_eq!(
       reveal_tx.output[0].script_pubkey,
@@ -823,8 +896,8 @@ mod tests {
   #[test]
   fn batch_inscribe_with_parent_not_enough_cardinals_utxos_fails() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(20_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(20_000_000, address(0))),
     ];
 
     let parent = inscription_id(1);
@@ -838,7 +911,7 @@ mod tests {
       },
       tx_out: TxOut {
         script_pubkey: change(0).script_pubkey(),
-        value: Amount::from_sat(10000),
+        value: Amount::from_sat(10_000_000),
       },
     };
 
@@ -875,7 +948,7 @@ mod tests {
       reveal_fee_rate: 4.0.try_into().unwrap(),
       no_limit: false,
       reinscribe: false,
-      postages: vec![Amount::from_sat(10_000); 3],
+      postages: vec![Amount::from_sat(10_000_000); 3],
       mode: batch::Mode::SharedOutput,
       ..default()
     }
@@ -900,8 +973,8 @@ mod tests {
   #[should_panic(expected = "invariant: shared-output has only one destination")]
   fn batch_inscribe_with_inconsistent_reveal_addresses_panics() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(80_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(80_000_000, address(0))),
     ];
 
     let parent = inscription_id(1);
@@ -915,7 +988,7 @@ mod tests {
       },
       tx_out: TxOut {
         script_pubkey: change(0).script_pubkey(),
-        value: Amount::from_sat(10000),
+        value: Amount::from_sat(10_000_000),
       },
     };
 
@@ -952,7 +1025,7 @@ mod tests {
       reveal_fee_rate: 4.0.try_into().unwrap(),
       no_limit: false,
       reinscribe: false,
-      postages: vec![Amount::from_sat(10_000)],
+      postages: vec![Amount::from_sat(10_000_000)],
       mode: batch::Mode::SharedOutput,
       ..default()
     }
@@ -991,7 +1064,7 @@ mod tests {
       reveal_fee_rate: 1.0.try_into().unwrap(),
       no_limit: false,
       reinscribe: false,
-      postages: vec![Amount::from_sat(30_000); 3],
+      postages: vec![Amount::from_sat(30_000_000); 3],
       mode: batch::Mode::SharedOutput,
       ..default()
     }
@@ -1017,8 +1090,8 @@ mod tests {
   #[test]
   fn batch_inscribe_into_separate_outputs() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(80_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(80_000_000, address(0))),
     ];
 
     let wallet_inscriptions = BTreeMap::new();
@@ -1049,7 +1122,7 @@ mod tests {
       reveal_fee_rate: fee_rate,
       no_limit: false,
       reinscribe: false,
-      postages: vec![Amount::from_sat(10_000); 3],
+      postages: vec![TARGET_POSTAGE; 3],
       mode,
       ..default()
     }
@@ -1076,8 +1149,8 @@ mod tests {
   #[test]
   fn batch_inscribe_into_separate_outputs_with_parent() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(50_000, address(0))),
+      (outpoint(1), tx_out(10_000_000, address(0))),
+      (outpoint(2), tx_out(50_000_000, address(0))),
     ];
 
     let parent = inscription_id(1);
@@ -1091,7 +1164,7 @@ mod tests {
       },
       tx_out: TxOut {
         script_pubkey: change(0).script_pubkey(),
-        value: Amount::from_sat(10000),
+        value: Amount::from_sat(10_000_000),
       },
     };
 
@@ -1140,7 +1213,7 @@ mod tests {
       reveal_fee_rate: fee_rate,
       no_limit: false,
       reinscribe: false,
-      postages: vec![Amount::from_sat(10_000); 3],
+      postages: vec![Amount::from_sat(10_000_000); 3],
       mode,
       ..default()
     }
@@ -1169,7 +1242,7 @@ mod tests {
     );
 
     let sig_vbytes = 17;
-    let fee = fee_rate.fee(commit_tx.vsize() + sig_vbytes).to_sat();
+    let fee = policy::fee(fee_rate, commit_tx.vsize() + sig_vbytes, &commit_tx.output).to_sat();
 
     let reveal_value = commit_tx
       .output
@@ -1178,7 +1251,7 @@ mod tests {
       .reduce(|acc, i| acc + i)
       .unwrap();
 
-    assert_eq!(reveal_value.to_sat(), 50_000 - fee);
+    assert_eq!(reveal_value.to_sat(), 50_000_000 - fee);
 
     assert_eq!(
       reveal_tx.output[0].script_pubkey,
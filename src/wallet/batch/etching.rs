@@ -12,3 +12,17 @@ pub struct Etching {
   pub terms: Option<batch::Terms>,
   pub turbo: bool,
 }
+
+impl Etching {
+  /// P2SH redeem script committing to the etched dune's name, spendable with
+  /// a signature from `public_key`. Spending it in the reveal transaction
+  /// satisfies the name commitment on chains without taproot.
+  pub(crate) fn commitment_script(&self, public_key: &bitcoin::PublicKey) -> ScriptBuf {
+    script::Builder::new()
+      .push_slice::<&script::PushBytes>(self.dune.dune.commitment().as_slice().try_into().unwrap())
+      .push_opcode(opcodes::all::OP_DROP)
+      .push_key(public_key)
+      .push_opcode(opcodes::all::OP_CHECKSIG)
+      .into_script()
+  }
+}
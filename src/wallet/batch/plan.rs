@@ -29,7 +29,7 @@ impl Default for Plan {
       no_backup: false,
       no_limit: false,
       parent_info: Vec::new(),
-      postages: vec![Amount::from_sat(10_000)],
+      postages: vec![TARGET_POSTAGE],
       reinscribe: false,
       reveal_fee_rate: 1.0.try_into().unwrap(),
       reveal_satpoints: Vec::new(),
@@ -46,6 +46,21 @@ impl Plan {
     utxos: &BTreeMap<OutPoint, TxOut>,
     wallet: &Wallet,
   ) -> SubcommandResult {
+    Ok(Some(Box::new(self.create_inscriptions(
+      locked_utxos,
+      runic_utxos,
+      utxos,
+      wallet,
+    )?)))
+  }
+
+  pub(crate) fn create_inscriptions(
+    &self,
+    locked_utxos: &BTreeSet<OutPoint>,
+    runic_utxos: BTreeSet<OutPoint>,
+    utxos: &BTreeMap<OutPoint, TxOut>,
+    wallet: &Wallet,
+  ) -> Result<Output> {
     let Transactions {
       commit_tx,
       commit_vout,
@@ -78,7 +93,7 @@ impl Plan {
 
       let reveal_psbt = Psbt::from_unsigned_tx(Self::remove_witnesses(reveal_tx.clone()))?;
 
-      return Ok(Some(Box::new(self.output(
+      return Ok(self.output(
         commit_tx.compute_txid(),
         Some(commit_psbt),
         reveal_tx.compute_txid(),
@@ -87,7 +102,7 @@ impl Plan {
         total_fees,
         self.inscriptions.clone(),
         dune,
-      ))));
+      ));
     }
 
     let signed_commit_tx = wallet
@@ -154,9 +169,7 @@ impl Plan {
         ),
       )?;
 
-      Ok(Some(Box::new(
-        wallet.wait_for_maturation(dune_info.dune.dune)?,
-      )))
+      wallet.wait_for_maturation(dune_info.dune.dune)
     } else {
       let reveal = match wallet
         .dogecoin_client()
@@ -170,7 +183,7 @@ impl Plan {
         }
       };
 
-      Ok(Some(Box::new(self.output(
+      Ok(self.output(
         commit_txid,
         None,
         reveal,
@@ -179,7 +192,7 @@ impl Plan {
         total_fees,
         self.inscriptions.clone(),
         dune,
-      ))))
+      ))
     }
   }
 
@@ -368,6 +381,10 @@ impl Plan {
     let key_pair = UntweakedKeypair::new(&secp256k1, &mut rand::thread_rng());
     let (public_key, _parity) = XOnlyPublicKey::from_keypair(&key_pair);
 
+    let commitment_script = self
+      .etching
+      .map(|etching| etching.commitment_script(&bitcoin::PublicKey::new(key_pair.public_key())));
+
     let reveal_script = Inscription::append_batch_reveal_script(
       &self.inscriptions,
       ScriptBuf::builder()
@@ -414,6 +431,10 @@ impl Plan {
 
     reveal_inputs.push(OutPoint::null());
 
+    if commitment_script.is_some() {
+      reveal_inputs.push(OutPoint::null());
+    }
+
     for (i, destination) in self.destinations.iter().enumerate() {
       reveal_outputs.push(TxOut {
         script_pubkey: destination.script_pubkey(),
@@ -511,7 +532,7 @@ impl Plan {
       reveal_outputs.clone(),
       reveal_inputs.clone(),
       &reveal_script,
-      dune.is_some(),
+      commitment_script.as_deref(),
     );
 
     let mut target_value = reveal_fee;
@@ -524,7 +545,36 @@ impl Plan {
       target_value += TARGET_POSTAGE;
     }
 
-    let unsigned_commit_tx = TransactionBuilder::new(
+    let commitment_output = commitment_script.as_ref().map(|commitment_script| {
+      let script_pubkey = ScriptBuf::new_p2sh(&commitment_script.script_hash());
+      TxOut {
+        value: policy::dust_limit(&script_pubkey),
+        script_pubkey,
+      }
+    });
+
+    let commitment_fee = commitment_output
+      .as_ref()
+      .map(|output| {
+        policy::fee(
+          self.commit_fee_rate,
+          output.size(),
+          std::slice::from_ref(output),
+        )
+      })
+      .unwrap_or_default();
+
+    // the commit output must relay even when it only pays the reveal fee, in
+    // which case the excess goes to the reveal fee
+    target_value = target_value.max(
+      policy::dust_limit(&commit_tx_address.script_pubkey())
+        + commitment_output
+          .as_ref()
+          .map(|output| output.value)
+          .unwrap_or_default(),
+    );
+
+    let mut unsigned_commit_tx = TransactionBuilder::new(
       satpoint,
       wallet_inscriptions,
       utxos.clone(),
@@ -533,7 +583,7 @@ impl Plan {
       commit_tx_address.script_pubkey(),
       commit_change,
       self.commit_fee_rate,
-      Target::Value(target_value),
+      Target::Value(target_value + commitment_fee),
       chain.network(),
     )
     .build_transaction()?;
@@ -545,11 +595,26 @@ impl Plan {
       .find(|(_vout, output)| output.script_pubkey == commit_tx_address.script_pubkey())
       .expect("should find sat commit/inscription output");
 
+    // the dune commitment output is split off of the inscription commit
+    // output, and both are spent by the reveal transaction
+    let commitment_vout = commitment_output.map(|output| {
+      unsigned_commit_tx.output[vout].value -= output.value + commitment_fee;
+      unsigned_commit_tx.output.push(output);
+      unsigned_commit_tx.output.len() - 1
+    });
+
     reveal_inputs[commit_input] = OutPoint {
       txid: unsigned_commit_tx.compute_txid(),
       vout: vout.try_into().unwrap(),
     };
 
+    if let Some(commitment_vout) = commitment_vout {
+      reveal_inputs[commit_input + 1] = OutPoint {
+        txid: unsigned_commit_tx.compute_txid(),
+        vout: commitment_vout.try_into().unwrap(),
+      };
+    }
+
     let (mut reveal_tx, _fee) = Self::build_reveal_transaction(
       commit_input,
       &control_block,
@@ -557,12 +622,12 @@ impl Plan {
       reveal_outputs.clone(),
       reveal_inputs,
       &reveal_script,
-      dune.is_some(),
+      commitment_script.as_deref(),
     );
 
     for output in reveal_tx.output.iter() {
       ensure!(
-        output.value >= output.script_pubkey.minimal_non_dust(),
+        output.value >= policy::dust_limit(&output.script_pubkey),
         "commit transaction output would be dust"
       );
     }
@@ -581,6 +646,10 @@ impl Plan {
 
     prevouts.push(unsigned_commit_tx.output[vout].clone());
 
+    if let Some(commitment_vout) = commitment_vout {
+      prevouts.push(unsigned_commit_tx.output[commitment_vout].clone());
+    }
+
     let mut sighash_cache = SighashCache::new(&mut reveal_tx);
 
     let sighash = sighash_cache
@@ -613,6 +682,29 @@ impl Plan {
     witness.push(reveal_script);
     witness.push(control_block.serialize());
 
+    if let Some(commitment_script) = &commitment_script {
+      let sighash = SighashCache::new(&reveal_tx)
+        .legacy_signature_hash(
+          commit_input + 1,
+          commitment_script,
+          EcdsaSighashType::All.to_u32(),
+        )
+        .expect("signature hash should compute");
+
+      let signature = bitcoin::ecdsa::Signature {
+        signature: secp256k1.sign_ecdsa(
+          &secp256k1::Message::from_digest(sighash.to_byte_array()),
+          &key_pair.secret_key(),
+        ),
+        sighash_type: EcdsaSighashType::All,
+      };
+
+      reveal_tx.input[commit_input + 1].script_sig = script::Builder::new()
+        .push_slice(signature.serialize())
+        .push_slice::<&script::PushBytes>(commitment_script.as_bytes().try_into().unwrap())
+        .into_script();
+    }
+
     let recovery_key_pair = key_pair.tap_tweak(&secp256k1, taproot_spend_info.merkle_root());
 
     let (x_only_pub_key, _parity) = recovery_key_pair.to_keypair().x_only_public_key();
@@ -638,6 +730,13 @@ impl Plan {
         .clone(),
     );
 
+    if let Some(commitment_vout) = commitment_vout {
+      utxos.insert(
+        reveal_tx.input[commit_input + 1].previous_output,
+        unsigned_commit_tx.output[commitment_vout].clone(),
+      );
+    }
+
     let total_fees =
       Self::calculate_fee(&unsigned_commit_tx, &utxos) + Self::calculate_fee(&reveal_tx, &utxos);
 
@@ -715,7 +814,7 @@ impl Plan {
     output: Vec<TxOut>,
     input: Vec<OutPoint>,
     script: &Script,
-    etching: bool,
+    commitment_script: Option<&Script>,
   ) -> (Transaction, Amount) {
     let reveal_tx = Transaction {
       input: input
@@ -724,7 +823,7 @@ impl Plan {
           previous_output,
           script_sig: script::Builder::new().into_script(),
           witness: Witness::new(),
-          sequence: if etching {
+          sequence: if commitment_script.is_some() {
             Sequence::from_height(Dunestone::COMMIT_CONFIRMATIONS - 1)
           } else {
             Sequence::ENABLE_RBF_NO_LOCKTIME
@@ -749,12 +848,20 @@ impl Plan {
           );
           txin.witness.push(script);
           txin.witness.push(control_block.serialize());
+        } else if current_index == commit_input_index + 1
+          && let Some(commitment_script) = commitment_script
+        {
+          // add dummy signature for dune commitment input
+          txin.script_sig = script::Builder::new()
+            .push_slice([0; 72])
+            .push_slice::<&script::PushBytes>(commitment_script.as_bytes().try_into().unwrap())
+            .into_script();
         } else {
           txin.witness = Witness::from_slice(&[&[0; SCHNORR_SIGNATURE_SIZE]]);
         }
       }
 
-      fee_rate.fee(reveal_tx.vsize())
+      policy::fee(fee_rate, reveal_tx.vsize(), &reveal_tx.output)
     };
 
     (reveal_tx, fee)
@@ -135,7 +135,7 @@ impl TransactionBuilder {
   const ADDITIONAL_INPUT_VBYTES: usize = 57;
   const ADDITIONAL_OUTPUT_VBYTES: usize = 43;
   const SCHNORR_SIGNATURE_SIZE: usize = 64;
-  pub(crate) const MAX_POSTAGE: Amount = Amount::from_sat(2 * 10_000);
+  pub(crate) const MAX_POSTAGE: Amount = Amount::from_sat(2 * 1_000_000);
 
   pub fn new(
     outgoing: KoinuPoint,
@@ -182,7 +182,7 @@ impl TransactionBuilder {
       }
 
       if let Target::Value(output_value) | Target::ExactPostage(output_value) = self.target {
-        let dust_value = self.recipient.minimal_non_dust();
+        let dust_value = policy::dust_limit(&self.recipient);
 
         if output_value < dust_value {
           return Err(Error::Dust {
@@ -204,13 +204,8 @@ impl TransactionBuilder {
   }
 
   fn select_outgoing(mut self) -> Result<Self> {
-    let dust_limit = self
-      .unused_change_addresses
-      .last()
-      .unwrap()
-      .script_pubkey()
-      .minimal_non_dust()
-      .to_sat();
+    let dust_limit =
+      policy::dust_limit(&self.unused_change_addresses.last().unwrap().script_pubkey()).to_sat();
 
     for (inscribed_satpoint, inscription_ids) in self.inscriptions.iter().rev() {
       if self.outgoing.outpoint == inscribed_satpoint.outpoint
@@ -287,12 +282,8 @@ impl TransactionBuilder {
     if self.outputs[0].script_pubkey == self.recipient {
       tprintln!("no alignment output");
     } else {
-      let dust_limit = self
-        .unused_change_addresses
-        .last()
-        .unwrap()
-        .script_pubkey()
-        .minimal_non_dust();
+      let dust_limit =
+        policy::dust_limit(&self.unused_change_addresses.last().unwrap().script_pubkey());
 
       if self.outputs[0].value >= dust_limit {
         tprintln!("no padding needed");
@@ -316,18 +307,19 @@ impl TransactionBuilder {
   }
 
   fn add_value(mut self) -> Result<Self> {
-    let estimated_fee = self.estimate_fee();
-
     let min_value = match self.target {
-      Target::Postage => self
-        .outputs
-        .last()
-        .unwrap()
-        .script_pubkey
-        .minimal_non_dust(),
+      Target::Postage => policy::dust_limit(&self.outputs.last().unwrap().script_pubkey),
       Target::Value(value) | Target::ExactPostage(value) => value,
     };
 
+    // estimate the fee with the last output at its minimum value, since it
+    // may end up with as little, and so be subject to a soft dust surcharge
+    let estimated_fee = {
+      let mut outputs = self.outputs.clone();
+      outputs.last_mut().unwrap().value = min_value;
+      policy::fee(self.fee_rate, self.estimate_vbytes(), &outputs)
+    };
+
     let total = min_value
       .checked_add(estimated_fee)
       .ok_or(Error::ValueOverflow)?;
@@ -380,33 +372,38 @@ impl TransactionBuilder {
 
     let value = total_output_amount - Amount::from_sat(sat_offset);
 
-    if let Some(excess) = value.checked_sub(self.fee_rate.fee(self.estimate_vbytes())) {
+    if let Some(excess) = value.checked_sub(self.estimate_fee()) {
       let (max, target) = match self.target {
         Target::ExactPostage(postage) => (postage, postage),
         Target::Postage => (Self::MAX_POSTAGE, TARGET_POSTAGE),
         Target::Value(value) => (value, value),
       };
 
+      let change = self.unused_change_addresses.last().unwrap().script_pubkey();
+
+      // fee after stripping, with the change output at its minimum value
+      let stripped_fee = {
+        let mut outputs = self.outputs.clone();
+        outputs.last_mut().unwrap().value = target;
+        outputs.push(TxOut {
+          value: policy::dust_limit(&change),
+          script_pubkey: change.clone(),
+        });
+        policy::fee(
+          self.fee_rate,
+          self.estimate_vbytes() + Self::ADDITIONAL_OUTPUT_VBYTES,
+          &outputs,
+        )
+      };
+
       if excess > max
-        && value.checked_sub(target).unwrap()
-          > self
-            .unused_change_addresses
-            .last()
-            .unwrap()
-            .script_pubkey()
-            .minimal_non_dust()
-            + self
-              .fee_rate
-              .fee(self.estimate_vbytes() + Self::ADDITIONAL_OUTPUT_VBYTES)
+        && value.checked_sub(target).unwrap() > policy::dust_limit(&change) + stripped_fee
       {
         tprintln!("stripped {} koinu", (value - target).to_sat());
         self.outputs.last_mut().expect("no outputs found").value = target;
+        self.unused_change_addresses.pop();
         self.outputs.push(TxOut {
-          script_pubkey: self
-            .unused_change_addresses
-            .pop()
-            .unwrap_or_else(|| panic!("not enough change addresses"))
-            .script_pubkey(),
+          script_pubkey: change,
           value: value - target,
         });
       }
@@ -418,7 +415,16 @@ impl TransactionBuilder {
   fn deduct_fee(mut self) -> Self {
     let sat_offset = self.calculate_sat_offset();
 
-    let fee = self.estimate_fee();
+    let mut fee = self.estimate_fee();
+
+    // deducting the fee may push the last output below the soft dust limit,
+    // which increases the fee
+    {
+      let mut outputs = self.outputs.clone();
+      let last = outputs.last_mut().expect("No output to deduct fee from");
+      last.value = last.value.checked_sub(fee).unwrap_or_default();
+      fee = fee.max(policy::fee(self.fee_rate, self.estimate_vbytes(), &outputs));
+    }
 
     let total_output_amount = self
       .outputs
@@ -474,7 +480,7 @@ impl TransactionBuilder {
   }
 
   fn estimate_fee(&self) -> Amount {
-    self.fee_rate.fee(self.estimate_vbytes())
+    policy::fee(self.fee_rate, self.estimate_vbytes(), &self.outputs)
   }
 
   fn build(self) -> Result<Transaction> {
@@ -569,7 +575,8 @@ impl TransactionBuilder {
     let mut offset = 0;
     for output in &transaction.output {
       if output.script_pubkey == self.recipient {
-        let slop = self.fee_rate.fee(Self::ADDITIONAL_OUTPUT_VBYTES);
+        let slop = self.fee_rate.fee(Self::ADDITIONAL_OUTPUT_VBYTES)
+          + policy::soft_dust_surcharge(self.fee_rate);
 
         match self.target {
           Target::Postage => {
@@ -590,7 +597,7 @@ impl TransactionBuilder {
                 <= self
                   .change_addresses
                   .iter()
-                  .map(|address| address.script_pubkey().minimal_non_dust())
+                  .map(|address| policy::dust_limit(&address.script_pubkey()))
                   .max()
                   .unwrap_or_default()
                   + slop,
@@ -627,7 +634,7 @@ impl TransactionBuilder {
     for input in &mut modified_tx.input {
       input.witness = Witness::from_slice(&[&[0; 64]]);
     }
-    let expected_fee = self.fee_rate.fee(modified_tx.vsize());
+    let expected_fee = policy::fee(self.fee_rate, modified_tx.vsize(), &transaction.output);
 
     assert_eq!(
       actual_fee, expected_fee,
@@ -636,7 +643,7 @@ impl TransactionBuilder {
 
     for tx_out in &transaction.output {
       assert!(
-        tx_out.value >= tx_out.script_pubkey.minimal_non_dust(),
+        tx_out.value >= policy::dust_limit(&tx_out.script_pubkey),
         "invariant: all outputs are above dust limit",
       );
     }
@@ -777,9 +784,9 @@ mod tests {
   #[test]
   fn tx_builder_to_transaction() {
     let mut amounts = BTreeMap::new();
-    amounts.insert(outpoint(1), tx_out(5_000, address(0)));
-    amounts.insert(outpoint(2), tx_out(5_000, address(0)));
-    amounts.insert(outpoint(3), tx_out(2_000, address(0)));
+    amounts.insert(outpoint(1), tx_out(1_000_000, address(0)));
+    amounts.insert(outpoint(2), tx_out(5_000_000, address(0)));
+    amounts.insert(outpoint(3), tx_out(2_000_000, address(0)));
 
     let tx_builder = TransactionBuilder {
       amounts,
@@ -796,15 +803,15 @@ mod tests {
       outputs: vec![
         TxOut {
           script_pubkey: recipient(),
-          value: Amount::from_sat(5_000),
+          value: Amount::from_sat(1_000_000),
         },
         TxOut {
           script_pubkey: change(0).script_pubkey(),
-          value: Amount::from_sat(5_000),
+          value: Amount::from_sat(5_000_000),
         },
         TxOut {
           script_pubkey: change(1).script_pubkey(),
-          value: Amount::from_sat(1_724),
+          value: Amount::from_sat(1_996_724),
         },
       ],
       target: Target::Postage,
@@ -818,9 +825,9 @@ mod tests {
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1)), tx_in(outpoint(2)), tx_in(outpoint(3))],
         output: vec![
-          tx_out(5_000, recipient_address()),
-          tx_out(5_000, change(0)),
-          tx_out(1_724, change(1))
+          tx_out(1_000_000, recipient_address()),
+          tx_out(5_000_000, change(0)),
+          tx_out(1_996_724, change(1))
         ],
       })
     )
@@ -828,7 +835,7 @@ mod tests {
 
   #[test]
   fn transactions_are_rbf() {
-    let utxos = vec![(outpoint(1), tx_out(5_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(2_000_000, address(0)))];
 
     assert!(
       TransactionBuilder::new(
@@ -851,7 +858,7 @@ mod tests {
 
   #[test]
   fn deduct_fee() {
-    let utxos = vec![(outpoint(1), tx_out(5_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(2_000_000, address(0)))];
 
     pretty_assert_eq!(
       TransactionBuilder::new(
@@ -871,7 +878,7 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(4901, recipient_address())],
+        output: vec![tx_out(1_998_901, recipient_address())],
       })
     )
   }
@@ -903,13 +910,13 @@ mod tests {
   #[test]
   fn additional_postage_added_when_required() {
     let utxos = vec![
-      (outpoint(1), tx_out(5_000, address(0))),
-      (outpoint(2), tx_out(5_000, address(0))),
+      (outpoint(1), tx_out(1_500_000, address(0))),
+      (outpoint(2), tx_out(1_500_000, address(0))),
     ];
 
     pretty_assert_eq!(
       TransactionBuilder::new(
-        satpoint(1, 4_950),
+        satpoint(1, 1_450_000),
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
@@ -925,7 +932,10 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1)), tx_in(outpoint(2))],
-        output: vec![tx_out(4_950, change(1)), tx_out(4_862, recipient_address())],
+        output: vec![
+          tx_out(1_450_000, change(1)),
+          tx_out(1_547_812, recipient_address())
+        ],
       })
     )
   }
@@ -980,13 +990,13 @@ mod tests {
   #[test]
   fn excess_additional_postage_is_stripped() {
     let utxos = vec![
-      (outpoint(1), tx_out(5_000, address(0))),
-      (outpoint(2), tx_out(25_000, address(0))),
+      (outpoint(1), tx_out(1_500_000, address(0))),
+      (outpoint(2), tx_out(2_500_000, address(0))),
     ];
 
     pretty_assert_eq!(
       TransactionBuilder::new(
-        satpoint(1, 4_950),
+        satpoint(1, 1_450_000),
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
@@ -1003,9 +1013,9 @@ mod tests {
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1)), tx_in(outpoint(2))],
         output: vec![
-          tx_out(4_950, change(1)),
+          tx_out(1_450_000, change(1)),
           tx_out(TARGET_POSTAGE.to_sat(), recipient_address()),
-          tx_out(14_831, change(0)),
+          tx_out(1_546_781, change(0)),
         ],
       })
     )
@@ -1130,7 +1140,7 @@ mod tests {
 
   #[test]
   fn excess_postage_is_stripped() {
-    let utxos = vec![(outpoint(1), tx_out(1_000_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(100_000_000, address(0)))];
 
     pretty_assert_eq!(
       TransactionBuilder::new(
@@ -1152,7 +1162,7 @@ mod tests {
         input: vec![tx_in(outpoint(1))],
         output: vec![
           tx_out(TARGET_POSTAGE.to_sat(), recipient_address()),
-          tx_out(989_870, change(1))
+          tx_out(98_997_870, change(1))
         ],
       })
     )
@@ -1161,7 +1171,7 @@ mod tests {
   #[test]
   #[should_panic(expected = "invariant: excess postage is stripped")]
   fn invariant_excess_postage_is_stripped() {
-    let utxos = vec![(outpoint(1), tx_out(1_000_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(100_000_000, address(0)))];
 
     TransactionBuilder::new(
       satpoint(1, 0),
@@ -1183,11 +1193,11 @@ mod tests {
 
   #[test]
   fn sat_is_aligned() {
-    let utxos = vec![(outpoint(1), tx_out(10_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(3_000_000, address(0)))];
 
     pretty_assert_eq!(
       TransactionBuilder::new(
-        satpoint(1, 3_333),
+        satpoint(1, 1_333_000),
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
@@ -1203,7 +1213,10 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(3_333, change(1)), tx_out(6_537, recipient_address())],
+        output: vec![
+          tx_out(1_333_000, change(1)),
+          tx_out(1_664_870, recipient_address())
+        ],
       })
     )
   }
@@ -1211,8 +1224,8 @@ mod tests {
   #[test]
   fn alignment_output_under_dust_limit_is_padded() {
     let utxos = vec![
-      (outpoint(1), tx_out(10_000, address(0))),
-      (outpoint(2), tx_out(10_000, address(0))),
+      (outpoint(1), tx_out(2_000_000, address(0))),
+      (outpoint(2), tx_out(2_000_000, address(0))),
     ];
 
     pretty_assert_eq!(
@@ -1234,8 +1247,8 @@ mod tests {
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(2)), tx_in(outpoint(1))],
         output: vec![
-          tx_out(10_001, change(1)),
-          tx_out(9_811, recipient_address())
+          tx_out(2_000_001, change(1)),
+          tx_out(1_997_811, recipient_address())
         ],
       })
     )
@@ -1244,10 +1257,10 @@ mod tests {
   #[test]
   #[should_panic(expected = "invariant: all outputs are either change or recipient")]
   fn invariant_all_output_are_recognized() {
-    let utxos = vec![(outpoint(1), tx_out(10_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(10_000_000, address(0)))];
 
     let mut builder = TransactionBuilder::new(
-      satpoint(1, 3_333),
+      satpoint(1, 3_333_000),
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
@@ -1274,10 +1287,10 @@ mod tests {
   #[test]
   #[should_panic(expected = "invariant: all outputs are above dust limit")]
   fn invariant_all_output_are_above_dust_limit() {
-    let utxos = vec![(outpoint(1), tx_out(10_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(10_000_000, address(0)))];
 
     TransactionBuilder::new(
-      satpoint(1, 1),
+      satpoint(1, 1_000),
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
@@ -1508,7 +1521,7 @@ mod tests {
 
   #[test]
   fn build_transaction_with_custom_fee_rate() {
-    let utxos = vec![(outpoint(1), tx_out(10_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(2_000_000, address(0)))];
 
     let fee_rate = FeeRate::try_from(17.3).unwrap();
 
@@ -1527,8 +1540,9 @@ mod tests {
     .build_transaction()
     .unwrap();
 
-    let fee =
-      fee_rate.fee(transaction.vsize() + TransactionBuilder::SCHNORR_SIGNATURE_SIZE / 4 + 1);
+    let fee = fee_rate
+      .fee(transaction.vsize() + TransactionBuilder::SCHNORR_SIGNATURE_SIZE / 4 + 1)
+      + policy::soft_dust_surcharge(fee_rate);
 
     pretty_assert_eq!(
       transaction,
@@ -1536,14 +1550,14 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(10_000 - fee.to_sat(), recipient_address())],
+        output: vec![tx_out(2_000_000 - fee.to_sat(), recipient_address())],
       }
     )
   }
 
   #[test]
   fn exact_transaction_has_correct_value() {
-    let utxos = vec![(outpoint(1), tx_out(5_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(5_000_000, address(0)))];
 
     pretty_assert_eq!(
       TransactionBuilder::new(
@@ -1555,7 +1569,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
-        Target::Value(Amount::from_sat(1000)),
+        Target::Value(Amount::from_sat(1_000_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1563,7 +1577,10 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(1000, recipient_address()), tx_out(3870, change(1))],
+        output: vec![
+          tx_out(1_000_000, recipient_address()),
+          tx_out(3_997_870, change(1))
+        ],
       })
     )
   }
@@ -1571,8 +1588,8 @@ mod tests {
   #[test]
   fn exact_transaction_adds_output_to_cover_value() {
     let utxos = vec![
-      (outpoint(1), tx_out(1_000, address(0))),
-      (outpoint(2), tx_out(1_000, address(0))),
+      (outpoint(1), tx_out(1_000_000, address(0))),
+      (outpoint(2), tx_out(2_000_000, address(0))),
     ];
 
     pretty_assert_eq!(
@@ -1585,7 +1602,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
-        Target::Value(Amount::from_sat(1500)),
+        Target::Value(Amount::from_sat(1_500_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1593,7 +1610,10 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1)), tx_in(outpoint(2))],
-        output: vec![tx_out(1500, recipient_address()), tx_out(312, change(1))],
+        output: vec![
+          tx_out(1_500_000, recipient_address()),
+          tx_out(1_497_812, change(1))
+        ],
       })
     )
   }
@@ -1618,7 +1638,7 @@ mod tests {
       .build_transaction(),
       Err(Error::Dust {
         output_value: Amount::from_sat(1),
-        dust_value: Amount::from_sat(294)
+        dust_value: policy::DUST_LIMIT,
       })
     )
   }
@@ -1626,7 +1646,7 @@ mod tests {
   #[test]
   fn do_not_select_outputs_which_do_not_pay_for_their_own_fee_at_default_fee_rate() {
     let utxos = vec![
-      (outpoint(1), tx_out(1_000, address(0))),
+      (outpoint(1), tx_out(1_000_000, address(0))),
       (outpoint(2), tx_out(100, address(0))),
     ];
 
@@ -1640,7 +1660,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
-        Target::Value(Amount::from_sat(1000)),
+        Target::Value(Amount::from_sat(1_000_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1651,7 +1671,7 @@ mod tests {
   #[test]
   fn do_not_select_outputs_which_do_not_pay_for_their_own_fee_at_higher_fee_rate() {
     let utxos = vec![
-      (outpoint(1), tx_out(1_000, address(0))),
+      (outpoint(1), tx_out(1_000_000, address(0))),
       (outpoint(2), tx_out(500, address(0))),
     ];
 
@@ -1665,7 +1685,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(4.0).unwrap(),
-        Target::Value(Amount::from_sat(1000)),
+        Target::Value(Amount::from_sat(1_000_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1703,7 +1723,7 @@ mod tests {
       TransactionBuilder::new(
         satpoint(1, 0),
         BTreeMap::new(),
-        vec![(outpoint(1), tx_out(1_000, address(0)))]
+        vec![(outpoint(1), tx_out(2_000_000, address(0)))]
           .into_iter()
           .collect(),
         BTreeSet::new(),
@@ -1711,7 +1731,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
-        Target::Value(Amount::from_sat(707)),
+        Target::Value(Amount::from_sat(1_000_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1719,7 +1739,7 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(901, recipient_address())],
+        output: vec![tx_out(1_998_901, recipient_address())],
       }),
     );
   }
@@ -1730,7 +1750,7 @@ mod tests {
       TransactionBuilder::new(
         satpoint(1, 0),
         BTreeMap::new(),
-        vec![(outpoint(1), tx_out(20_099, address(0)))]
+        vec![(outpoint(1), tx_out(2_001_099, address(0)))]
           .into_iter()
           .collect(),
         BTreeSet::new(),
@@ -1746,7 +1766,7 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(20_000, recipient_address())],
+        output: vec![tx_out(2_000_000, recipient_address())],
       }),
     );
   }
@@ -1757,7 +1777,7 @@ mod tests {
       TransactionBuilder::new(
         satpoint(1, 0),
         BTreeMap::new(),
-        vec![(outpoint(1), tx_out(1_500, address(0)))]
+        vec![(outpoint(1), tx_out(2_010_000, address(0)))]
           .into_iter()
           .collect(),
         BTreeSet::new(),
@@ -1765,7 +1785,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(5.0).unwrap(),
-        Target::Value(Amount::from_sat(1000)),
+        Target::Value(Amount::from_sat(1_000_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1773,7 +1793,7 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(1005, recipient_address())],
+        output: vec![tx_out(2_004_505, recipient_address())],
       }),
     );
   }
@@ -1784,7 +1804,7 @@ mod tests {
       TransactionBuilder::new(
         satpoint(1, 0),
         BTreeMap::new(),
-        vec![(outpoint(1), tx_out(1_500, address(0)))]
+        vec![(outpoint(1), tx_out(1_005_000, address(0)))]
           .into_iter()
           .collect(),
         BTreeSet::new(),
@@ -1792,7 +1812,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(6.0).unwrap(),
-        Target::Value(Amount::from_sat(1000)),
+        Target::Value(Amount::from_sat(1_000_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1850,7 +1870,7 @@ mod tests {
       TransactionBuilder::new(
         satpoint(1, 0),
         BTreeMap::new(),
-        vec![(outpoint(1), tx_out(2000, address(0)))]
+        vec![(outpoint(1), tx_out(2_502_000, address(0)))]
           .into_iter()
           .collect(),
         BTreeSet::new(),
@@ -1858,7 +1878,7 @@ mod tests {
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(2.0).unwrap(),
-        Target::Value(Amount::from_sat(1500)),
+        Target::Value(Amount::from_sat(1_500_000)),
         Network::Testnet,
       )
       .build_transaction(),
@@ -1866,7 +1886,7 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(1802, recipient_address())],
+        output: vec![tx_out(2_499_802, recipient_address())],
       }),
     );
   }
@@ -1877,7 +1897,7 @@ mod tests {
       TransactionBuilder::new(
         satpoint(1, 0),
         BTreeMap::new(),
-        vec![(outpoint(1), tx_out(45000, address(0)))]
+        vec![(outpoint(1), tx_out(2_500_000, address(0)))]
           .into_iter()
           .collect(),
         BTreeSet::new(),
@@ -1893,7 +1913,7 @@ mod tests {
         version: Version(2),
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
-        output: vec![tx_out(20250, recipient_address())],
+        output: vec![tx_out(2_225_250, recipient_address())],
       }),
     );
   }
@@ -1950,13 +1970,13 @@ mod tests {
   #[test]
   fn pad_alignment_output_can_select_multiple_utxos() {
     let mut utxos = vec![
-      (outpoint(4), tx_out(101, address(0))), // 4. smallest utxo >= 84 is selected 4th, filling deficit
-      (outpoint(1), tx_out(20_000, address(0))), // 1. satpoint is selected 1st leaving deficit 293
-      (outpoint(2), tx_out(105, address(0))), // 2. biggest utxo <= 293 is selected 2nd leaving deficit 188
-      (outpoint(5), tx_out(103, address(0))),
-      (outpoint(6), tx_out(10_000, address(0))),
-      (outpoint(3), tx_out(104, address(0))), // 3. biggest utxo <= 188 is selected 3rd leaving deficit 84
-      (outpoint(7), tx_out(102, address(0))),
+      (outpoint(4), tx_out(310_000, address(0))), // 4. smallest utxo >= 309_999 is selected 4th, filling deficit
+      (outpoint(1), tx_out(20_000_000, address(0))), // 1. satpoint is selected 1st leaving deficit 999_999
+      (outpoint(2), tx_out(350_000, address(0))), // 2. biggest utxo <= 999_999 is selected 2nd leaving deficit 649_999
+      (outpoint(5), tx_out(330_000, address(0))),
+      (outpoint(6), tx_out(10_000_000, address(0))),
+      (outpoint(3), tx_out(340_000, address(0))), // 3. biggest utxo <= 649_999 is selected 3rd leaving deficit 309_999
+      (outpoint(7), tx_out(320_000, address(0))),
     ];
 
     let tx_builder = TransactionBuilder::new(
@@ -1968,7 +1988,7 @@ mod tests {
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
-      Target::Value(Amount::from_sat(10_000)),
+      Target::Value(Amount::from_sat(10_000_000)),
       Network::Testnet,
     )
     .select_outgoing()
@@ -1994,11 +2014,11 @@ mod tests {
       [
         TxOut {
           script_pubkey: change(1).script_pubkey(),
-          value: Amount::from_sat(101 + 104 + 105 + 1)
+          value: Amount::from_sat(310_000 + 340_000 + 350_000 + 1)
         },
         TxOut {
           script_pubkey: recipient(),
-          value: Amount::from_sat(19_999)
+          value: Amount::from_sat(19_999_999)
         }
       ]
     )
@@ -2072,7 +2092,7 @@ mod tests {
 
   #[test]
   fn build_transaction_with_custom_postage() {
-    let utxos = vec![(outpoint(1), tx_out(1_000_000, address(0)))];
+    let utxos = vec![(outpoint(1), tx_out(10_000_000, address(0)))];
 
     let fee_rate = FeeRate::try_from(17.3).unwrap();
 
@@ -2085,14 +2105,15 @@ mod tests {
       recipient(),
       [change(0), change(1)],
       fee_rate,
-      Target::ExactPostage(Amount::from_sat(66_000)),
+      Target::ExactPostage(Amount::from_sat(1_500_000)),
       Network::Testnet,
     )
     .build_transaction()
     .unwrap();
 
-    let fee =
-      fee_rate.fee(transaction.vsize() + TransactionBuilder::SCHNORR_SIGNATURE_SIZE / 4 + 1);
+    let fee = fee_rate
+      .fee(transaction.vsize() + TransactionBuilder::SCHNORR_SIGNATURE_SIZE / 4 + 1)
+      + policy::soft_dust_surcharge(fee_rate) * 2;
 
     pretty_assert_eq!(
       transaction,
@@ -2101,8 +2122,8 @@ mod tests {
         lock_time: LockTime::ZERO,
         input: vec![tx_in(outpoint(1))],
         output: vec![
-          tx_out(66_000, recipient_address()),
-          tx_out(1_000_000 - 66_000 - fee.to_sat(), change(1))
+          tx_out(1_500_000, recipient_address()),
+          tx_out(10_000_000 - 1_500_000 - fee.to_sat(), change(1))
         ],
       }
     )
@@ -139,6 +139,7 @@ impl WalletConstructor {
       inscription_info,
       inscriptions,
       locked_utxos,
+      name: self.name,
       ord_client: self.ord_client,
       output_info,
       rpc_url: self.rpc_url,
@@ -1,6 +1,6 @@
 use {
   super::*,
-  dog::subcommand::find::{FindRangeOutput, Output},
+  dog::subcommand::find::{ContentOutput, FindRangeOutput, Output},
 };
 
 #[test]
@@ -89,3 +89,34 @@ fn no_koinu_index() {
     .expected_exit_code(1)
     .run_and_extract_stdout();
 }
+
+#[test]
+fn find_content_returns_content_hash() {
+  let core = mockcore::spawn();
+
+  pretty_assert_eq!(
+    CommandBuilder::new("--index-content-hashes find --content foo.txt")
+      .write("foo.txt", "foo")
+      .core(&core)
+      .run_and_deserialize_output::<ContentOutput>(),
+    ContentOutput {
+      content_hash: "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+        .parse()
+        .unwrap(),
+      inscriptions: Vec::new(),
+    }
+  );
+}
+
+#[test]
+fn no_content_hash_index() {
+  let core = mockcore::spawn();
+  CommandBuilder::new("find --content foo.txt")
+    .write("foo.txt", "foo")
+    .core(&core)
+    .expected_stderr(
+      "error: find --content requires index created with `--index-content-hashes` flag\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
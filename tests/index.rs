@@ -90,3 +90,110 @@ fn export_inscription_number_to_id_tsv() {
     &dog::Object::InscriptionId(inscription),
   );
 }
+
+/// Write the Dogecoin regtest genesis block and `blocks` more on top of it to
+/// `<data_dir>/regtest/blocks/blk00000.dat`, in the layout Core uses.
+fn write_blk_fixture(data_dir: &Path, blocks: u32) -> Vec<u8> {
+  let genesis: bitcoin::Block = bitcoin::consensus::deserialize(
+    &hex::decode(concat!(
+      "010000000000000000000000000000000000000000000000000000000000000000000000",
+      "696ad20e2dd4365c7459b4a4a5af743d5e92c6da3229e6532cd605f6533f2a5bdae5494d",
+      "ffff7f2002000000010100000001000000000000000000000000000000000000000000000",
+      "0000000000000000000ffffffff1004ffff001d0104084e696e746f6e646fffffffff0100",
+      "58850c020000004341040184710fa689ad5023690c80f3a49c8f13f8d45b8c857fbcbc8bc",
+      "4a8e4d3eb4b10f4d4604fa08dce601aaf0f470216fe1b51850b4acf21b179c45070ac7b03",
+      "a9ac00000000",
+    ))
+    .unwrap(),
+  )
+  .unwrap();
+
+  let mut chain = vec![genesis];
+
+  for height in 1..=blocks {
+    let coinbase = Transaction {
+      version: Version(1),
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: OutPoint::null(),
+        script_sig: script::Builder::new().push_int(height.into()).into_script(),
+        sequence: Sequence::MAX,
+        witness: Witness::new(),
+      }],
+      output: vec![TxOut {
+        value: Amount::from_sat(50 * COIN_VALUE),
+        script_pubkey: ScriptBuf::new(),
+      }],
+    };
+
+    let mut block = bitcoin::Block {
+      header: chain.last().unwrap().header,
+      txdata: vec![coinbase],
+    };
+    block.header.prev_blockhash = chain.last().unwrap().block_hash();
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    block.header.time += height;
+
+    chain.push(block);
+  }
+
+  let mut bytes = Vec::new();
+  for block in &chain {
+    let block = bitcoin::consensus::serialize(block);
+    bytes.extend_from_slice(&[0xfa, 0xbf, 0xb5, 0xda]);
+    bytes.extend_from_slice(&u32::try_from(block.len()).unwrap().to_le_bytes());
+    bytes.extend_from_slice(&block);
+  }
+
+  let blocks_dir = data_dir.join("regtest/blocks");
+  fs::create_dir_all(&blocks_dir).unwrap();
+  fs::write(blocks_dir.join("blk00000.dat"), &bytes).unwrap();
+
+  bytes
+}
+
+#[test]
+fn offline_update_reads_blk_files() {
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  write_blk_fixture(tempdir.path(), 2);
+
+  let args = format!(
+    "--regtest --offline --dogecoin-data-dir {}",
+    tempdir.path().display()
+  );
+
+  CommandBuilder::new(format!("{args} index update"))
+    .temp_dir(tempdir.clone())
+    .run_and_extract_stdout();
+
+  let info = CommandBuilder::new(format!("{args} index info"))
+    .temp_dir(tempdir.clone())
+    .run_and_deserialize_output::<serde_json::Value>();
+
+  assert_eq!(info["blocks_indexed"], 3);
+
+  assert!(tempdir.path().join("regtest/blk-index/scan.dat").is_file());
+}
+
+#[test]
+fn offline_update_fails_on_unreadable_block() {
+  let tempdir = TempDir::new().unwrap();
+
+  let bytes = write_blk_fixture(tempdir.path(), 2);
+
+  // Cut the last block off after its header.
+  fs::write(
+    tempdir.path().join("regtest/blocks/blk00000.dat"),
+    &bytes[..bytes.len() - 20],
+  )
+  .unwrap();
+
+  CommandBuilder::new(format!(
+    "--regtest --offline --dogecoin-data-dir {} index update",
+    tempdir.path().display()
+  ))
+  .expected_exit_code(1)
+  .stderr_regex("error: failed to read block 2 from .blk files\n\nbecause:.*")
+  .run_and_extract_stdout();
+}
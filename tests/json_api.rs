@@ -174,6 +174,7 @@ fn get_inscription() {
       content_type: Some("text/plain;charset=utf-8".to_string()),
       effective_content_type: Some("text/plain;charset=utf-8".to_string()),
       fee: 138,
+      first_of_content: None,
       height: 2,
       id: inscription_id,
       number: 0,
@@ -231,6 +232,7 @@ fn get_inscription_with_metaprotocol_and_properties() {
       content_type: Some("text/plain;charset=utf-8".to_string()),
       effective_content_type: Some("text/plain;charset=utf-8".to_string()),
       fee: 143,
+      first_of_content: None,
       height: 2,
       id: output.inscriptions[0].id,
       number: 0,
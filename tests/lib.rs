@@ -16,8 +16,8 @@ use {
     wallet::ListDescriptorsResult, wallet::batch,
   },
   doginals::{
-    Artifact, COIN_VALUE, Charm, Dune, DuneId, Dunestone, Edict, Koinu, KoinuPoint, Pile, Rarity,
-    SpacedDune,
+    Artifact, COIN_VALUE, Charm, Dune, DuneId, Dunestone, Edict, Etching, Koinu, KoinuPoint, Pile,
+    Rarity, SpacedDune,
   },
   mockcore::TransactionTemplate,
   pretty_assertions::assert_eq as pretty_assert_eq,
@@ -66,6 +66,7 @@ mod info;
 mod json_api;
 mod list;
 mod parse;
+mod remote;
 mod server;
 mod settings;
 mod subsidy;
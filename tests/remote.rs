@@ -0,0 +1,244 @@
+use {super::*, bitcoin::hashes::Hash};
+
+type Drc20Token = dog::subcommand::drc20::Drc20Token;
+
+// DRC-20 balances are only tracked for addresses with a Dogecoin encoding, so
+// the wallet must receive to P2PKH addresses.
+fn spawn(args: &[&str]) -> (mockcore::Handle, TestServer) {
+  let core = mockcore::builder().legacy_addresses(true).build();
+
+  let dog = TestServer::spawn_with_args(&core, args);
+
+  create_wallet(&core, &dog);
+
+  core.mine_blocks(1);
+
+  (core, dog)
+}
+
+// DNS names and dogemap claims are parsed from Dogecoin script_sig envelopes,
+// which the wallet does not create, so inscribe them directly.
+fn inscribe_text(core: &mockcore::Handle, text: &str) -> (InscriptionId, String) {
+  let recipient = Address::p2pkh(
+    bitcoin::PubkeyHash::from_byte_array([1; 20]),
+    Network::Bitcoin,
+  );
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Witness::new())],
+    script_sig: Inscription {
+      content_type: Some(b"text/plain;charset=utf-8".to_vec()),
+      body: Some(text.into()),
+      ..default()
+    }
+    .append_reveal_script_to_builder(script::Builder::new())
+    .into_script(),
+    recipient: Some(recipient.clone()),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  (
+    InscriptionId { txid, index: 0 },
+    Chain::Dogecoin
+      .address_string_from_script(&recipient.script_pubkey())
+      .unwrap(),
+  )
+}
+
+fn dogecoin_address(address: &Address<NetworkUnchecked>) -> String {
+  Chain::Dogecoin
+    .address_string_from_script(&address.clone().assume_checked().script_pubkey())
+    .unwrap()
+}
+
+// Remote subcommands are run without `--dogecoin-rpc-url`, so they fail if
+// they try to open the index instead of querying the server.
+fn remote<T: DeserializeOwned>(dog: &TestServer, args: &str) -> T {
+  dog.sync_server();
+
+  CommandBuilder::new(format!("--server-url {} {args}", dog.url()))
+    .run_and_deserialize_output::<T>()
+}
+
+#[test]
+fn drc20_commands_query_server() {
+  let (core, dog) = spawn(&[]);
+
+  let deploy = CommandBuilder::new(
+    "wallet drc20 deploy --fee-rate 1 --tick dogi --max 1000 --limit 100 --decimals 0",
+  )
+  .core(&core)
+  .dog(&dog)
+  .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  let mint = CommandBuilder::new("wallet drc20 mint --fee-rate 1 --tick dogi --amount 100")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  let address = dogecoin_address(&mint.inscriptions[0].destination);
+
+  let tokens = remote::<Vec<Drc20Token>>(&dog, "drc20 tokens --json");
+
+  assert_eq!(tokens.len(), 1);
+  assert_eq!(tokens[0].tick, "dogi");
+  assert_eq!(tokens[0].minted, 100);
+  assert_eq!(
+    tokens[0].deploy_inscription,
+    deploy.inscriptions[0].id.to_string()
+  );
+
+  let token = remote::<Drc20Token>(&dog, "drc20 token dogi --json");
+
+  assert_eq!(token.tick, "dogi");
+  assert_eq!(token.max_supply, 1000);
+  assert_eq!(token.mint_limit, 100);
+  assert_eq!(token.minted, 100);
+
+  pretty_assert_eq!(
+    remote::<serde_json::Value>(&dog, &format!("drc20 balance {address} --tick dogi --json")),
+    serde_json::json!({
+      "address": address,
+      "tick": "dogi",
+      "available": "100",
+      "transferable": "0",
+      "total": "100",
+    }),
+  );
+
+  let balances = remote::<serde_json::Value>(&dog, &format!("drc20 balance {address} --json"));
+
+  assert_eq!(balances["address"], address);
+  assert_eq!(balances["balances"].as_array().unwrap().len(), 1);
+  assert_eq!(balances["balances"][0]["tick"], "dogi");
+}
+
+#[test]
+fn dns_resolve_queries_server() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn(&core);
+
+  core.mine_blocks(1);
+
+  let (inscription_id, _) = inscribe_text(&core, "satoshi.doge");
+
+  let info = remote::<serde_json::Value>(&dog, "dns resolve satoshi.doge --json");
+
+  assert_eq!(info["name"], "satoshi.doge");
+  assert_eq!(info["owner_inscription_id"], inscription_id.to_string());
+
+  pretty_assert_eq!(
+    remote::<serde_json::Value>(&dog, "dns resolve nobody.doge --json"),
+    serde_json::json!({ "error": "name 'nobody.doge' not found" }),
+  );
+}
+
+#[test]
+fn dogemap_status_queries_server() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn(&core);
+
+  core.mine_blocks(1);
+
+  let (inscription_id, owner) = inscribe_text(&core, "1.dogemap");
+
+  let status = remote::<serde_json::Value>(&dog, "dogemap status 1");
+
+  assert_eq!(status["block_number"], 1);
+  assert_eq!(status["owner_inscription_id"], inscription_id.to_string());
+  assert_eq!(status["owner"], owner);
+  assert_eq!(status["transfers"].as_array().unwrap().len(), 1);
+
+  pretty_assert_eq!(
+    remote::<serde_json::Value>(&dog, "dogemap status 2"),
+    serde_json::json!({ "block_number": 2, "claimed": false }),
+  );
+}
+
+#[test]
+fn dune_commands_query_server() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_args(
+    &core,
+    &["--index-dunes", "--index-addresses", "--integration-test"],
+  );
+
+  core.mine_blocks(1);
+
+  let recipient = Address::p2pkh(
+    bitcoin::PubkeyHash::from_byte_array([1; 20]),
+    Network::Bitcoin,
+  );
+
+  core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Witness::new())],
+    op_return: Some(
+      Dunestone {
+        etching: Some(Etching {
+          premine: Some(1000),
+          symbol: Some('¢'),
+          ..default()
+        }),
+        ..default()
+      }
+      .encipher(),
+    ),
+    outputs: 1,
+    recipient: Some(recipient.clone()),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let address = Chain::Dogecoin
+    .address_string_from_script(&recipient.script_pubkey())
+    .unwrap();
+
+  let balance = remote::<serde_json::Value>(&dog, &format!("dune balance {address} --json"));
+
+  assert_eq!(balance["address"], address);
+
+  let balances = balance["balances"].as_array().unwrap();
+
+  assert_eq!(balances.len(), 1);
+  assert_eq!(balances[0]["amount"], 1000);
+  assert_eq!(balances[0]["symbol"], "¢");
+
+  let dune = balances[0]["dune"].as_str().unwrap();
+
+  let holders = remote::<serde_json::Value>(&dog, &format!("dune holders {dune} --json"));
+
+  assert_eq!(holders["dune"], dune);
+  assert_eq!(holders["holders"].as_array().unwrap().len(), 1);
+  assert_eq!(holders["holders"][0]["amount"], 1000);
+  assert_eq!(holders["holders"][0]["address"], address);
+}
+
+#[test]
+fn server_url_setting_does_not_enable_remote_mode() {
+  let (core, dog) = spawn(&[]);
+
+  CommandBuilder::new("wallet drc20 deploy --fee-rate 1 --tick dogi --max 1000 --limit 100")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  let tokens = CommandBuilder::new("drc20 tokens --json")
+    .env("DOG_SERVER_URL", "http://127.0.0.1:1")
+    .core(&core)
+    .run_and_deserialize_output::<Vec<Drc20Token>>();
+
+  assert_eq!(tokens.len(), 1);
+  assert_eq!(tokens[0].tick, "dogi");
+}
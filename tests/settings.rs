@@ -23,13 +23,16 @@ fn default() {
   "index": ".*index\.redb",
   "index_addresses": false,
   "index_cache_size": \d+,
+  "index_content_hashes": false,
   "index_dunes": false,
   "index_koinu": false,
+  "index_text": false,
   "index_transactions": false,
   "only_protocols": null,
   "integration_test": false,
   "max_savepoints": 2,
   "no_index_inscriptions": false,
+  "offline": false,
   "savepoint_interval": 10,
   "server_password": null,
   "server_url": null,
@@ -2,13 +2,42 @@ use super::*;
 
 #[test]
 fn genesis() {
+  assert_eq!(
+    CommandBuilder::new("supply --height 0").run_and_deserialize_output::<Supply>(),
+    Supply {
+      supply: 8800000000,
+      first: 0,
+      last: 8799999999,
+      last_mined_in_block: 0,
+      rarity: None,
+    }
+  );
+}
+
+#[test]
+fn last_koinu() {
   assert_eq!(
     CommandBuilder::new("supply").run_and_deserialize_output::<Supply>(),
     Supply {
-      supply: 2099999997690000,
+      supply: 18000000390884299946,
       first: 0,
-      last: 2099999997689999,
-      last_mined_in_block: 6929999
+      last: 18000000390884299945,
+      last_mined_in_block: 8762573,
+      rarity: None,
     }
   );
 }
+
+#[test]
+fn rarity() {
+  let supply =
+    CommandBuilder::new("supply --height 300000 --rarity").run_and_deserialize_output::<Supply>();
+
+  let rarity = supply.rarity.unwrap();
+
+  assert_eq!(rarity[&Rarity::Mythic], 1);
+  assert_eq!(rarity[&Rarity::Legendary], 1);
+  assert_eq!(rarity[&Rarity::Epic], 2);
+  assert_eq!(rarity[&Rarity::Rare], 1249);
+  assert_eq!(rarity.values().sum::<u64>(), supply.supply);
+}
@@ -15,6 +15,7 @@ fn traits_command_prints_sat_traits() {
       period: 0,
       offset: 0,
       rarity: Rarity::Mythic,
+      charms: vec![Charm::Coin, Charm::Mythic, Charm::Palindrome, Charm::Wonky],
     }
   );
 }
@@ -33,6 +34,16 @@ fn traits_command_for_last_sat() {
       period: 3437,
       offset: 0,
       rarity: Rarity::Uncommon,
+      charms: Vec::new(),
     }
   );
 }
+
+#[test]
+fn traits_command_prints_dogecoin_charms() {
+  let output = CommandBuilder::new("traits 13329.1").run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.height, 13329);
+  assert_eq!(output.rarity, Rarity::Common);
+  assert_eq!(output.charms, vec![Charm::Wonky, Charm::Jackpot]);
+}
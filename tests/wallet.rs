@@ -7,6 +7,7 @@ mod batch_command;
 mod burn;
 mod cardinals;
 mod create;
+mod drc20;
 mod dump;
 mod inscribe;
 mod inscriptions;
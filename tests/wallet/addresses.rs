@@ -46,7 +46,7 @@ fn addresses() {
       .unwrap(),
     &vec![Output {
       output: etched.output.dune.unwrap().location.unwrap(),
-      amount: 10000,
+      amount: 1_000_000,
       inscriptions: Some(Vec::new()),
       dunes: Some(
         vec![(
@@ -68,7 +68,7 @@ fn addresses() {
       .unwrap(),
     &vec![Output {
       output: etched.output.inscriptions[0].location.outpoint,
-      amount: 10000,
+      amount: 1_000_000,
       inscriptions: Some(vec![etched.output.inscriptions[0].id]),
       dunes: Some(BTreeMap::new()),
     }]
@@ -64,8 +64,8 @@ fn inscribed_utxos_are_deducted_from_cardinal() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 100 * COIN_VALUE - 10_000,
-      doginal: 10_000,
+      cardinal: 100 * COIN_VALUE - 1_000_000,
+      doginal: 1_000_000,
       runic: None,
       dunes: None,
       total: 100 * COIN_VALUE,
@@ -124,9 +124,9 @@ fn runic_utxos_are_deducted_from_cardinal() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 50 * COIN_VALUE * 7 - 20_000,
-      doginal: 10000,
-      runic: Some(10_000),
+      cardinal: 50 * COIN_VALUE * 7 - 2_000_000,
+      doginal: 1_000_000,
+      runic: Some(1_000_000),
       dunes: Some(
         vec![(
           SpacedDune { dune, spacers: 1 },
@@ -240,9 +240,9 @@ fn runic_utxos_are_displayed_with_decimal_amount() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 50 * COIN_VALUE * 7 - 20_000,
-      doginal: 10000,
-      runic: Some(10_000),
+      cardinal: 50 * COIN_VALUE * 7 - 2_000_000,
+      doginal: 1_000_000,
+      runic: Some(1_000_000),
       dunes: Some(
         vec![(
           SpacedDune { dune, spacers: 1 },
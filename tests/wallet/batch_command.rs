@@ -416,7 +416,7 @@ fn batch_in_same_output_but_different_satpoints() {
       inscription.location,
       KoinuPoint {
         outpoint,
-        offset: u64::try_from(i).unwrap() * 10_000,
+        offset: u64::try_from(i).unwrap() * 1_000_000,
       }
     );
   }
@@ -432,12 +432,12 @@ fn batch_in_same_output_but_different_satpoints() {
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[1].id),
-    format!(r".*<dt>location</dt>.*{outpoint}:10000.*",),
+    format!(r".*<dt>location</dt>.*{outpoint}:1000000.*",),
   );
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[2].id),
-    format!(r".*<dt>location</dt>.*{outpoint}:20000.*",),
+    format!(r".*<dt>location</dt>.*{outpoint}:2000000.*",),
   );
 
   dog.assert_response_regex(
@@ -462,7 +462,7 @@ fn batch_in_same_output_with_non_default_postage() {
     .write("meow.wav", [0; 2048])
     .write(
       "batch.yaml",
-      "mode: shared-output\npostage: 777\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n- file: meow.wav\n"
+      "mode: shared-output\npostage: 7777777\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n- file: meow.wav\n"
     )
     .core(&core)
     .dog(&dog)
@@ -475,7 +475,7 @@ fn batch_in_same_output_with_non_default_postage() {
       inscription.location,
       KoinuPoint {
         outpoint,
-        offset: u64::try_from(i).unwrap() * 777,
+        offset: u64::try_from(i).unwrap() * 7_777_777,
       }
     );
   }
@@ -491,12 +491,12 @@ fn batch_in_same_output_with_non_default_postage() {
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[1].id),
-    format!(r".*<dt>location</dt>.*{outpoint}:777.*",),
+    format!(r".*<dt>location</dt>.*{outpoint}:7777777.*",),
   );
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[2].id),
-    format!(r".*<dt>location</dt>.*{outpoint}:1554.*",),
+    format!(r".*<dt>location</dt>.*{outpoint}:15555554.*",),
   );
 
   dog.assert_response_regex(
@@ -561,21 +561,21 @@ fn batch_in_separate_outputs_with_parent() {
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[0].id),
     format!(
-      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>10000</dd>.*.*<dt>location</dt>.*{output_1}:0.*"
+      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>1000000</dd>.*.*<dt>location</dt>.*{output_1}:0.*"
     ),
   );
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[1].id),
     format!(
-      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>10000</dd>.*.*<dt>location</dt>.*{output_2}:0.*"
+      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>1000000</dd>.*.*<dt>location</dt>.*{output_2}:0.*"
     ),
   );
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[2].id),
     format!(
-      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>10000</dd>.*.*<dt>location</dt>.*{output_3}:0.*"
+      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>1000000</dd>.*.*<dt>location</dt>.*{output_3}:0.*"
     ),
   );
 }
@@ -608,7 +608,7 @@ fn batch_in_separate_outputs_with_parent_and_non_default_postage() {
     .write("meow.wav", [0; 2048])
     .write(
       "batch.yaml",
-      format!("parents:\n- {parent_id}\nmode: separate-outputs\npostage: 777\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n- file: meow.wav\n")
+      format!("parents:\n- {parent_id}\nmode: separate-outputs\npostage: 7777777\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n- file: meow.wav\n")
     )
     .core(&core)
     .dog(&dog)
@@ -636,21 +636,21 @@ fn batch_in_separate_outputs_with_parent_and_non_default_postage() {
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[0].id),
     format!(
-      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>777</dd>.*.*<dt>location</dt>.*{output_1}:0.*"
+      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>7777777</dd>.*.*<dt>location</dt>.*{output_1}:0.*"
     ),
   );
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[1].id),
     format!(
-      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>777</dd>.*.*<dt>location</dt>.*{output_2}:0.*"
+      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>7777777</dd>.*.*<dt>location</dt>.*{output_2}:0.*"
     ),
   );
 
   dog.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[2].id),
     format!(
-      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>777</dd>.*.*<dt>location</dt>.*{output_3}:0.*"
+      r".*<dt>parents</dt>\s*<dd>.*{parent_id}.*</dd>.*<dt>value</dt>.*<dd>7777777</dd>.*.*<dt>location</dt>.*{output_3}:0.*"
     ),
   );
 }
@@ -1630,9 +1630,9 @@ fn batch_can_etch_dune() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 39999980000,
-      doginal: 10000,
-      runic: Some(10000),
+      cardinal: 39_998_000_000,
+      doginal: 1_000_000,
+      runic: Some(1_000_000),
       dunes: Some(
         vec![(
           dune,
@@ -1771,8 +1771,8 @@ fn batch_can_etch_dune_without_premine() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 39999990000,
-      doginal: 10000,
+      cardinal: 39_999_000_000,
+      doginal: 1_000_000,
       runic: Some(0),
       dunes: Some(default()),
       total: 400 * COIN_VALUE,
@@ -53,7 +53,7 @@ fn runic_outputs_are_protected() {
 
   create_wallet(&core, &dog);
 
-  let (inscription, _) = inscribe_with_options(&core, &dog, Some(1000), 1);
+  let (inscription, _) = inscribe_with_options(&core, &dog, Some(1_000_000), 1);
   let height = core.height();
 
   let dune = Dune(DUNE);
@@ -69,7 +69,7 @@ fn runic_outputs_are_protected() {
     .unwrap();
 
   CommandBuilder::new(format!(
-    "--regtest --index-dunes wallet send --fee-rate 1 {} 1000:{} --postage 1000sat",
+    "--regtest --index-dunes wallet send --fee-rate 1 {} 1000:{} --postage 1000000sat",
     address.clone().require_network(Network::Regtest).unwrap(),
     Dune(DUNE)
   ))
@@ -88,7 +88,7 @@ fn runic_outputs_are_protected() {
       (core.height() as usize, 0, 0, Witness::new()),
     ],
     outputs: 2,
-    output_values: &[2000, 50 * COIN_VALUE],
+    output_values: &[2_000_000, 50 * COIN_VALUE],
     recipient: Some(address.require_network(Network::Regtest).unwrap()),
     ..default()
   });
@@ -139,7 +139,7 @@ fn burns_only_one_sat() {
     }
   );
 
-  let (inscription, _) = inscribe_with_options(&core, &dog, Some(100_000), 1);
+  let (inscription, _) = inscribe_with_options(&core, &dog, Some(2_000_000), 1);
 
   CommandBuilder::new(format!("wallet burn --fee-rate 1 {inscription}",))
     .core(&core)
@@ -183,11 +183,11 @@ fn cannot_burn_inscription_sharing_utxo_with_another_inscription() {
     .next()
     .unwrap();
 
-  let (inscription0, _) = inscribe_with_options(&core, &dog, Some(1000), 1);
+  let (inscription0, _) = inscribe_with_options(&core, &dog, Some(1_000_000), 1);
   let height0 = core.height();
-  let (inscription1, _) = inscribe_with_options(&core, &dog, Some(1000), 1);
+  let (inscription1, _) = inscribe_with_options(&core, &dog, Some(1_000_000), 1);
   let height1 = core.height();
-  let (inscription2, _) = inscribe_with_options(&core, &dog, Some(1000), 1);
+  let (inscription2, _) = inscribe_with_options(&core, &dog, Some(1_000_000), 1);
   let height2 = core.height();
 
   let txid = core.broadcast_tx(TransactionTemplate {
@@ -200,7 +200,7 @@ fn cannot_burn_inscription_sharing_utxo_with_another_inscription() {
       (core.height() as usize, 0, 0, Witness::new()),
     ],
     outputs: 2,
-    output_values: &[3000, 50 * COIN_VALUE],
+    output_values: &[3_000_000, 50 * COIN_VALUE],
     recipient: Some(address.require_network(Network::Regtest).unwrap()),
     ..default()
   });
@@ -216,7 +216,7 @@ fn cannot_burn_inscription_sharing_utxo_with_another_inscription() {
     .core(&core)
     .dog(&dog)
     .expected_stderr(format!(
-      "error: cannot send {txid}:0:0 without also sending inscription {inscription2} at {txid}:0:2000\n"
+      "error: cannot send {txid}:0:0 without also sending inscription {inscription2} at {txid}:0:2000000\n"
     ))
     .expected_exit_code(1)
     .run_and_extract_stdout();
@@ -435,9 +435,9 @@ fn burn_dune() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 450 * COIN_VALUE - 2 * 10000,
-      doginal: 10000,
-      runic: Some(10000),
+      cardinal: 450 * COIN_VALUE - 2 * 1_000_000,
+      doginal: 1_000_000,
+      runic: Some(1_000_000),
       dunes: Some(
         [(
           SpacedDune { dune, spacers: 0 },
@@ -466,8 +466,8 @@ fn burn_dune() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 500 * COIN_VALUE - 10000,
-      doginal: 10000,
+      cardinal: 500 * COIN_VALUE - 1_000_000,
+      doginal: 1_000_000,
       runic: Some(0),
       dunes: Some(BTreeMap::new()),
       total: 500 * COIN_VALUE,
@@ -565,9 +565,9 @@ fn burn_dune_with_many_assets_in_wallet() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 124999940000,
-      doginal: 40000,
-      runic: Some(20000),
+      cardinal: 124_994_000_000,
+      doginal: 4_000_000,
+      runic: Some(2_000_000),
       dunes: Some(
         [
           (
@@ -736,8 +736,8 @@ fn burning_dune_creates_change_output_for_non_burnt_dunes() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 84999970000,
-      doginal: 20000,
+      cardinal: 84_997_000_000,
+      doginal: 2_000_000,
       dunes: Some(
         [
           (SpacedDune::new(Dune(DUNE), 0), "500".parse().unwrap()),
@@ -745,7 +745,7 @@ fn burning_dune_creates_change_output_for_non_burnt_dunes() {
         ]
         .into()
       ),
-      runic: Some(10000),
+      runic: Some(1_000_000),
       total: 17 * 50 * COIN_VALUE,
     }
   );
@@ -0,0 +1,529 @@
+use super::*;
+
+type Drc20Buy = dog::subcommand::wallet::offer::accept::Output;
+type Drc20List = dog::subcommand::wallet::drc20::list::Output;
+type Drc20Send = dog::subcommand::wallet::drc20::send::Output;
+type Drc20Token = dog::subcommand::drc20::Drc20Token;
+type Drc20Transfer = dog::subcommand::drc20::Drc20Transfer;
+
+// DRC-20 balances are only tracked for addresses with a Dogecoin encoding, so
+// the wallet must receive to P2PKH addresses.
+fn spawn() -> (mockcore::Handle, TestServer) {
+  spawn_with_args(&[])
+}
+
+fn spawn_with_args(args: &[&str]) -> (mockcore::Handle, TestServer) {
+  let core = mockcore::builder().legacy_addresses(true).build();
+
+  let dog = TestServer::spawn_with_args(&core, args);
+
+  create_wallet(&core, &dog);
+
+  core.mine_blocks(1);
+
+  (core, dog)
+}
+
+fn dogecoin_address(address: &Address<NetworkUnchecked>) -> String {
+  Chain::Dogecoin
+    .address_string_from_script(&address.clone().assume_checked().script_pubkey())
+    .unwrap()
+}
+
+fn deploy(core: &mockcore::Handle, dog: &TestServer) -> Batch {
+  let output = CommandBuilder::new(
+    "wallet drc20 deploy --fee-rate 1 --tick dogi --max 1000 --limit 100 --decimals 0",
+  )
+  .core(core)
+  .dog(dog)
+  .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  output
+}
+
+fn mint(core: &mockcore::Handle, dog: &TestServer, amount: u128) -> String {
+  dogecoin_address(mint_destination(core, dog, amount).as_unchecked())
+}
+
+fn mint_destination(core: &mockcore::Handle, dog: &TestServer, amount: u128) -> Address {
+  let output = CommandBuilder::new(format!(
+    "wallet drc20 mint --fee-rate 1 --tick dogi --amount {amount}"
+  ))
+  .core(core)
+  .dog(dog)
+  .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  output.inscriptions[0].destination.clone().assume_checked()
+}
+
+fn token(dog: &TestServer) -> Drc20Token {
+  let response = dog.json_request("/r/drc20/token/dogi");
+  assert_eq!(response.status(), StatusCode::OK);
+  serde_json::from_str(&response.text().unwrap()).unwrap()
+}
+
+fn balance(dog: &TestServer, address: &str) -> api::Drc20Balance {
+  let response = dog.json_request(format!("/r/drc20/balance/{address}/dogi"));
+  assert_eq!(response.status(), StatusCode::OK);
+  serde_json::from_str(&response.text().unwrap()).unwrap()
+}
+
+fn transfer(dog: &TestServer, outpoint: OutPoint) -> Option<Drc20Transfer> {
+  let response = dog.json_request(format!("/r/drc20/transfer/{outpoint}"));
+
+  if response.status() == StatusCode::NOT_FOUND {
+    return None;
+  }
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  Some(serde_json::from_str(&response.text().unwrap()).unwrap())
+}
+
+#[test]
+fn deploy_creates_token() {
+  let (core, dog) = spawn();
+
+  let output = deploy(&core, &dog);
+
+  let token = token(&dog);
+
+  assert_eq!(token.tick, "dogi");
+  assert_eq!(token.max_supply, 1000);
+  assert_eq!(token.mint_limit, 100);
+  assert_eq!(token.decimals, 0);
+  assert_eq!(token.minted, 0);
+  assert_eq!(
+    token.deploy_inscription,
+    output.inscriptions[0].id.to_string()
+  );
+}
+
+#[test]
+fn deploy_rejects_existing_token() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  CommandBuilder::new("wallet drc20 deploy --fee-rate 1 --tick dogi --max 1000")
+    .core(&core)
+    .dog(&dog)
+    .expected_exit_code(1)
+    .expected_stderr("error: DRC-20 token `dogi` has already been deployed\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn mint_credits_balance() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  let address = mint(&core, &dog, 100);
+
+  assert_eq!(token(&dog).minted, 100);
+
+  assert_eq!(
+    balance(&dog, &address),
+    api::Drc20Balance {
+      available: 100,
+      transferable: 0,
+    }
+  );
+}
+
+#[test]
+fn mint_requires_deployed_token() {
+  let (core, dog) = spawn();
+
+  CommandBuilder::new("wallet drc20 mint --fee-rate 1 --tick dogi --amount 1")
+    .core(&core)
+    .dog(&dog)
+    .expected_exit_code(1)
+    .expected_stderr("error: DRC-20 token `dogi` has not been deployed\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn mint_may_not_exceed_limit() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  CommandBuilder::new("wallet drc20 mint --fee-rate 1 --tick dogi --amount 101")
+    .core(&core)
+    .dog(&dog)
+    .expected_exit_code(1)
+    .expected_stderr("error: mint amount 101 exceeds `dogi` mint limit of 100\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn transfer_makes_balance_transferable() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  let address = mint(&core, &dog, 100);
+
+  let output = CommandBuilder::new("wallet drc20 transfer --fee-rate 1 --tick dogi --amount 40")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    dogecoin_address(&output.inscriptions[0].destination),
+    address
+  );
+
+  assert_eq!(
+    balance(&dog, &address),
+    api::Drc20Balance {
+      available: 60,
+      transferable: 40,
+    }
+  );
+
+  let transfer = transfer(&dog, output.inscriptions[0].location.outpoint).unwrap();
+
+  assert_eq!(transfer.tick, "dogi");
+  assert_eq!(transfer.amount, 40);
+  assert_eq!(transfer.from_address, address);
+}
+
+#[test]
+fn transfer_requires_balance() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  mint(&core, &dog, 100);
+
+  CommandBuilder::new("wallet drc20 transfer --fee-rate 1 --tick dogi --amount 101")
+    .core(&core)
+    .dog(&dog)
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: insufficient `dogi` balance: 101 required, wallet has 100 available and 0 transferable\n",
+    )
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn send_dry_run_requires_existing_transfer() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  mint(&core, &dog, 100);
+
+  CommandBuilder::new(
+    "wallet drc20 send --fee-rate 1 --tick dogi --amount 40 --dry-run 1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+  )
+  .core(&core)
+  .dog(&dog)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: no `dogi` transfer inscription of 40 in wallet, create one with `dog wallet drc20 transfer` before using `--dry-run`\n",
+  )
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_inscribes_transfer_if_necessary() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  let address = mint(&core, &dog, 100);
+
+  let recipient = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"
+    .parse::<Address<NetworkUnchecked>>()
+    .unwrap();
+
+  let mut spawn = CommandBuilder::new(format!(
+    "wallet drc20 send --fee-rate 1 --tick dogi --amount 25 {}",
+    recipient.clone().assume_checked(),
+  ))
+  .core(&core)
+  .dog(&dog)
+  .spawn();
+
+  let mut buffer = String::new();
+
+  BufReader::new(spawn.child.stderr.as_mut().unwrap())
+    .read_line(&mut buffer)
+    .unwrap();
+
+  assert_regex_match!(
+    buffer,
+    "Waiting for transfer inscription [[:xdigit:]]{64}i0 to confirm…\n"
+  );
+
+  core.mine_blocks(1);
+
+  let output = spawn.run_and_deserialize_output::<Drc20Send>();
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    output.inscription.unwrap().inscriptions[0].id,
+    output.transfer
+  );
+
+  assert_eq!(
+    balance(&dog, &address),
+    api::Drc20Balance {
+      available: 75,
+      transferable: 0,
+    }
+  );
+
+  assert_eq!(
+    balance(&dog, &dogecoin_address(&recipient)),
+    api::Drc20Balance {
+      available: 25,
+      transferable: 0,
+    }
+  );
+}
+
+// Wallet inscriptions are revealed in witnesses, which the DRC-20 indexer
+// reads but Dogecoin inscription indexing does not, so listed transfers carry
+// their envelope in both the script_sig and the witness.
+fn inscribe_transfer(core: &mockcore::Handle, owner: &Address, amount: u128) -> InscriptionId {
+  let content_type = "text/plain;charset=utf-8";
+
+  let body = format!(r#"{{"p":"drc-20","op":"transfer","tick":"dogi","amt":"{amount}"}}"#);
+
+  core.mine_blocks(1);
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(
+      core.height().try_into().unwrap(),
+      0,
+      0,
+      envelope(&[b"ord", &[1], content_type.as_bytes(), &[], body.as_bytes()]),
+    )],
+    script_sig: Inscription {
+      content_type: Some(content_type.into()),
+      body: Some(body.into()),
+      ..default()
+    }
+    .append_reveal_script_to_builder(script::Builder::new())
+    .into_script(),
+    recipient: Some(owner.clone()),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  InscriptionId { txid, index: 0 }
+}
+
+fn list(core: &mockcore::Handle, dog: &TestServer) -> (Address, InscriptionId, Drc20List) {
+  deploy(core, dog);
+
+  let seller = mint_destination(core, dog, 100);
+
+  let id = inscribe_transfer(core, &seller, 40);
+
+  let list =
+    CommandBuilder::new("wallet drc20 list --fee-rate 1 --tick dogi --amount 40 --price 1btc")
+      .core(core)
+      .dog(dog)
+      .run_and_deserialize_output::<Drc20List>();
+
+  (seller, id, list)
+}
+
+#[test]
+fn list_signs_existing_transfer() {
+  let (core, dog) = spawn();
+
+  let (seller, id, list) = list(&core, &dog);
+
+  assert_eq!(list.transfer, id);
+  assert!(list.inscription.is_none());
+  assert_eq!(list.price, COIN_VALUE);
+
+  let psbt = Psbt::deserialize(&base64_decode(&list.psbt).unwrap()).unwrap();
+
+  assert_eq!(psbt.unsigned_tx.input.len(), 2);
+  assert_eq!(psbt.unsigned_tx.input[0].previous_output, OutPoint::null());
+  assert_eq!(
+    psbt.unsigned_tx.input[1].previous_output,
+    OutPoint {
+      txid: id.txid,
+      vout: 0,
+    },
+  );
+
+  // listings are signed with SIGHASH_SINGLE|ANYONECANPAY
+  assert_eq!(
+    psbt.inputs[1]
+      .final_script_witness
+      .as_ref()
+      .and_then(|witness| witness.nth(0))
+      .and_then(|signature| signature.last()),
+    Some(&0x83),
+  );
+
+  assert_eq!(
+    balance(&dog, &dogecoin_address(seller.as_unchecked())),
+    api::Drc20Balance {
+      available: 60,
+      transferable: 40,
+    }
+  );
+}
+
+#[test]
+fn list_dry_run_requires_existing_transfer() {
+  let (core, dog) = spawn();
+
+  deploy(&core, &dog);
+
+  mint(&core, &dog, 100);
+
+  CommandBuilder::new(
+    "wallet drc20 list --fee-rate 1 --tick dogi --amount 40 --price 1btc --dry-run",
+  )
+  .core(&core)
+  .dog(&dog)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: no `dogi` transfer inscription of 40 in wallet, create one with `dog wallet drc20 transfer` before using `--dry-run`\n",
+  )
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn buy_rejects_wrong_tick_or_amount() {
+  let (core, dog) = spawn();
+
+  let (_, id, list) = list(&core, &dog);
+
+  CommandBuilder::new(format!(
+    "wallet drc20 buy --tick dogi --amount 41 --price 1btc --fee-rate 1 {}",
+    list.psbt,
+  ))
+  .core(&core)
+  .dog(&dog)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: listing input `{}:0` is a transfer of 40 `dogi`, not 41 `dogi`\n",
+    id.txid,
+  ))
+  .run_and_extract_stdout();
+
+  CommandBuilder::new("wallet drc20 deploy --fee-rate 1 --tick wowo --max 1000 --decimals 0")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!(
+    "wallet drc20 buy --tick wowo --amount 40 --price 1btc --fee-rate 1 {}",
+    list.psbt,
+  ))
+  .core(&core)
+  .dog(&dog)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: listing input `{}:0` is a transfer of 40 `dogi`, not 40 `wowo`\n",
+    id.txid,
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn buy_rejects_spent_transfer() {
+  let (core, dog) = spawn();
+
+  let (_, id, list) = list(&core, &dog);
+
+  let (block, tx) = core.tx_index(id.txid);
+
+  core.broadcast_tx(TransactionTemplate {
+    inputs: &[(block, tx, 0, Witness::new())],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!(
+    "wallet drc20 buy --tick dogi --amount 40 --price 1btc --fee-rate 1 {}",
+    list.psbt,
+  ))
+  .core(&core)
+  .dog(&dog)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: listing input `{}:0` is not an unspent DRC-20 transfer\n",
+    id.txid,
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn buy_completes_valid_listing() {
+  let (core, dog) = spawn_with_args(&["--index-dunes"]);
+
+  let (seller, id, list) = list(&core, &dog);
+
+  core.state().clear_wallet_addresses();
+
+  // the buyer pays the value of the transfer output as well as its price
+  core.mine_blocks(3);
+
+  let buy = CommandBuilder::new(format!(
+    "wallet drc20 buy --tick dogi --amount 40 --price 1btc --fee-rate 1 {}",
+    list.psbt,
+  ))
+  .core(&core)
+  .dog(&dog)
+  .run_and_deserialize_output::<Drc20Buy>();
+
+  core.mine_blocks(1);
+
+  let tx = core.tx_by_id(buy.txid);
+
+  assert_eq!(
+    tx.input[1].previous_output,
+    OutPoint {
+      txid: id.txid,
+      vout: 0,
+    },
+  );
+
+  assert!(transfer(&dog, tx.input[1].previous_output).is_none());
+
+  assert_eq!(
+    balance(&dog, &dogecoin_address(seller.as_unchecked())),
+    api::Drc20Balance {
+      available: 60,
+      transferable: 0,
+    }
+  );
+
+  assert_eq!(
+    balance(
+      &dog,
+      &Chain::Dogecoin
+        .address_string_from_script(&tx.output[0].script_pubkey)
+        .unwrap(),
+    ),
+    api::Drc20Balance {
+      available: 40,
+      transferable: 0,
+    }
+  );
+}
@@ -105,7 +105,7 @@ fn inscriptions_with_postage() {
     .dog(&dog)
     .run_and_deserialize_output::<Vec<inscriptions::Output>>();
 
-  assert_eq!(output[0].postage, 10000);
+  assert_eq!(output[0].postage, 1_000_000);
 
   let addresses = CommandBuilder::new("wallet receive")
     .core(&core)
@@ -132,5 +132,5 @@ fn inscriptions_with_postage() {
     .dog(&dog)
     .run_and_deserialize_output::<Vec<inscriptions::Output>>();
 
-  assert_eq!(output[0].postage, 9889);
+  assert_eq!(output[0].postage, 998_889);
 }
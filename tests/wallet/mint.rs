@@ -218,7 +218,7 @@ fn minting_dune_and_then_sending_works() {
     }
   );
 
-  assert_eq!(balance.runic.unwrap(), 10000);
+  assert_eq!(balance.runic.unwrap(), 1_000_000);
 
   let output = CommandBuilder::new(format!(
     "--chain regtest --index-dunes wallet mint --fee-rate 1 --dune {}",
@@ -243,7 +243,7 @@ fn minting_dune_and_then_sending_works() {
     }
   );
 
-  assert_eq!(balance.runic.unwrap(), 20000);
+  assert_eq!(balance.runic.unwrap(), 2_000_000);
 
   pretty_assert_eq!(
     output.pile,
@@ -411,7 +411,7 @@ fn minting_dune_with_postage() {
   );
 
   let output = CommandBuilder::new(format!(
-    "--chain regtest --index-dunes wallet mint --fee-rate 1 --dune {} --postage 2222sat",
+    "--chain regtest --index-dunes wallet mint --fee-rate 1 --dune {} --postage 2222222sat",
     Dune(DUNE)
   ))
   .core(&core)
@@ -434,7 +434,7 @@ fn minting_dune_with_postage() {
     .dog(&dog)
     .run_and_deserialize_output::<dog::subcommand::wallet::balance::Output>();
 
-  assert_eq!(balance.runic.unwrap(), 2222);
+  assert_eq!(balance.runic.unwrap(), 2222222);
 }
 
 #[test]
@@ -480,13 +480,13 @@ fn minting_dune_with_postage_dust() {
   );
 
   CommandBuilder::new(format!(
-    "--chain regtest --index-dunes wallet mint --fee-rate 1 --dune {} --postage 300sat",
+    "--chain regtest --index-dunes wallet mint --fee-rate 1 --dune {} --postage 300000sat",
     Dune(DUNE)
   ))
   .core(&core)
   .dog(&dog)
   .expected_exit_code(1)
-  .expected_stderr("error: postage below dust limit of 330sat\n")
+  .expected_stderr("error: postage below dust limit of 1000000sat\n")
   .run_and_extract_stdout();
 }
 
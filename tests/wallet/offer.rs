@@ -2,3 +2,4 @@ use super::*;
 
 mod accept;
 mod create;
+mod list;
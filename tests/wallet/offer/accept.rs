@@ -2,6 +2,7 @@ use super::*;
 
 type Accept = dog::subcommand::wallet::offer::accept::Output;
 type Create = dog::subcommand::wallet::offer::create::Output;
+type List = dog::subcommand::wallet::offer::list::Output;
 
 #[test]
 fn accepted_offer_works() {
@@ -11,7 +12,7 @@ fn accepted_offer_works() {
 
   create_wallet(&core, &dog);
 
-  let postage = 9000;
+  let postage = 9_000_000;
 
   let (inscription, txid) = inscribe_with_options(&core, &dog, Some(postage), 0);
 
@@ -538,7 +539,7 @@ fn buyer_inputs_must_be_signed() {
 
   create_wallet(&core, &dog);
 
-  let postage = 9000;
+  let postage = 9_000_000;
 
   let (inscription, txid) = inscribe_with_options(&core, &dog, Some(postage), 0);
 
@@ -590,7 +591,7 @@ fn seller_input_must_not_be_signed() {
 
   create_wallet(&core, &dog);
 
-  let postage = 9000;
+  let postage = 9_000_000;
 
   let (inscription, txid) = inscribe_with_options(&core, &dog, Some(postage), 0);
 
@@ -633,3 +634,318 @@ fn seller_input_must_not_be_signed() {
   ))
   .run_and_extract_stdout();
 }
+
+#[track_caller]
+fn listing_error_case(
+  core: &mockcore::Handle,
+  dog: &TestServer,
+  signature: Option<Witness>,
+  args: &[&str],
+  message: &str,
+) {
+  let mut psbt = Psbt::from_unsigned_tx(Transaction {
+    version: Version(2),
+    lock_time: LockTime::ZERO,
+    input: vec![
+      TxIn {
+        previous_output: OutPoint::null(),
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness: Witness::new(),
+      },
+      TxIn {
+        previous_output: "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799:0"
+          .parse()
+          .unwrap(),
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness: Witness::new(),
+      },
+    ],
+    output: vec![
+      TxOut {
+        value: Amount::ZERO,
+        script_pubkey: ScriptBuf::new(),
+      },
+      TxOut {
+        value: Amount::from_sat(101_000_000),
+        script_pubkey: ScriptBuf::new(),
+      },
+    ],
+  })
+  .unwrap();
+
+  psbt.inputs[1].final_script_witness = signature;
+
+  let base64 = base64_encode(&psbt.serialize());
+
+  let mut command = vec![
+    "wallet", "offer", "accept", "--amount", "1btc", "--psbt", &base64,
+  ];
+
+  command.extend(args);
+
+  CommandBuilder::new(command.join(" "))
+    .core(core)
+    .dog(dog)
+    .expected_exit_code(1)
+    .expected_stderr(message)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn listing_requires_fee_rate() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &dog);
+
+  listing_error_case(
+    &core,
+    &dog,
+    None,
+    &[
+      "--inscription",
+      "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0",
+    ],
+    "error: `--fee-rate` is required to accept listings\n",
+  );
+}
+
+#[test]
+fn listing_requires_dune_or_inscription() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &dog);
+
+  listing_error_case(
+    &core,
+    &dog,
+    None,
+    &["--fee-rate", "1"],
+    "error: listing must be accepted with `--dune` or `--inscription`\n",
+  );
+}
+
+#[test]
+fn listing_inputs_must_be_signed() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &dog);
+
+  listing_error_case(
+    &core,
+    &dog,
+    None,
+    &[
+      "--fee-rate",
+      "1",
+      "--inscription",
+      "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0",
+    ],
+    "error: listing input `6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799:0` is unsigned: listing inputs must be signed\n",
+  );
+}
+
+#[test]
+fn listing_inputs_must_be_signed_single_anyone_can_pay() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &dog);
+
+  listing_error_case(
+    &core,
+    &dog,
+    Some(Witness::from_slice(&[&[0; 64]])),
+    &[
+      "--fee-rate",
+      "1",
+      "--inscription",
+      "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0",
+    ],
+    "error: listing input `6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799:0` is not signed with SIGHASH_SINGLE|ANYONECANPAY\n",
+  );
+}
+
+#[test]
+fn accepted_inscription_bundle_listing_works() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_args(&core, &["--index-dunes"]);
+
+  create_wallet(&core, &dog);
+
+  core.mine_blocks(2);
+
+  let seller = core.state().new_address(false);
+
+  let mut inscriptions = Vec::new();
+
+  for (block, body) in [(1, "foo"), (2, "bar")] {
+    inscriptions.push(InscriptionId {
+      txid: core.broadcast_tx(TransactionTemplate {
+        inputs: &[(block, 0, 0, Witness::new())],
+        script_sig: Inscription {
+          content_type: Some("text/plain;charset=utf-8".into()),
+          body: Some(body.into()),
+          ..default()
+        }
+        .append_reveal_script_to_builder(script::Builder::new())
+        .into_script(),
+        recipient: Some(seller.clone()),
+        ..default()
+      }),
+      index: 0,
+    });
+  }
+
+  core.mine_blocks(1);
+
+  let list = CommandBuilder::new(format!(
+    "wallet offer list --inscription {} --inscription {} --price 1btc",
+    inscriptions[0], inscriptions[1],
+  ))
+  .core(&core)
+  .dog(&dog)
+  .run_and_deserialize_output::<List>();
+
+  assert_eq!(list.lots.len(), 2);
+  assert_eq!(list.price, COIN_VALUE);
+
+  core.state().clear_wallet_addresses();
+
+  // the buyer pays the value of the lots as well as their price
+  core.mine_blocks(4);
+
+  let accept = CommandBuilder::new(format!(
+    "wallet offer accept --inscription {} --inscription {} --amount 1btc --fee-rate 1 --psbt {}",
+    inscriptions[0], inscriptions[1], list.psbt,
+  ))
+  .core(&core)
+  .dog(&dog)
+  .run_and_deserialize_output::<Accept>();
+
+  core.mine_blocks(1);
+
+  let tx = core.tx_by_id(accept.txid);
+
+  assert_eq!(
+    tx.output[1].value + tx.output[2].value,
+    Amount::from_sat(2 * 50 * COIN_VALUE + COIN_VALUE),
+  );
+
+  let owned = CommandBuilder::new("wallet inscriptions")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Inscriptions>();
+
+  assert_eq!(
+    owned
+      .iter()
+      .map(|output| output.inscription)
+      .collect::<BTreeSet<InscriptionId>>(),
+    inscriptions.into_iter().collect(),
+  );
+}
+
+#[test]
+fn dune_listing_may_be_partially_filled() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_args(&core, &["--index-dunes", "--integration-test"]);
+
+  create_wallet(&core, &dog);
+
+  core.mine_blocks(1);
+
+  let seller = core.state().new_address(false);
+
+  // the premine of an unnamed etching, which needs no commitment, is split
+  // evenly between its two outputs
+  core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Witness::new())],
+    op_return: Some(
+      Dunestone {
+        edicts: vec![Edict {
+          id: DuneId::default(),
+          amount: 0,
+          output: 3,
+        }],
+        etching: Some(Etching {
+          premine: Some(200),
+          ..default()
+        }),
+        ..default()
+      }
+      .encipher(),
+    ),
+    outputs: 2,
+    recipient: Some(seller),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let balance = CommandBuilder::new("wallet balance")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Balance>();
+
+  let dune = *balance.dunes.unwrap().keys().next().unwrap();
+
+  let list = CommandBuilder::new(format!("wallet offer list --dune 200:{dune} --price 2btc"))
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<List>();
+
+  assert_eq!(list.lots.len(), 2);
+
+  core.state().clear_wallet_addresses();
+
+  core.mine_blocks(2);
+
+  let accept = CommandBuilder::new(format!(
+    "wallet offer accept --dune 100:{dune} --amount 1btc --fee-rate 1 --psbt {}",
+    list.psbt,
+  ))
+  .core(&core)
+  .dog(&dog)
+  .run_and_deserialize_output::<Accept>();
+
+  core.mine_blocks(1);
+
+  let tx = core.tx_by_id(accept.txid);
+
+  assert_eq!(tx.input[1].previous_output, list.lots[0]);
+  assert!(
+    tx.input
+      .iter()
+      .all(|input| input.previous_output != list.lots[1])
+  );
+
+  let balance = CommandBuilder::new("wallet balance")
+    .core(&core)
+    .dog(&dog)
+    .run_and_deserialize_output::<Balance>();
+
+  pretty_assert_eq!(
+    balance.dunes,
+    Some(
+      [(
+        dune,
+        Decimal {
+          value: 100,
+          scale: 0,
+        }
+      )]
+      .into()
+    ),
+  );
+}
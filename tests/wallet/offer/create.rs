@@ -10,7 +10,7 @@ fn created_offer_is_correct() {
 
   create_wallet(&core, &dog);
 
-  let (inscription, _) = inscribe_with_options(&core, &dog, Some(9000), 0);
+  let (inscription, _) = inscribe_with_options(&core, &dog, Some(9_000_000), 0);
 
   let address = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
     .parse::<Address<NetworkUnchecked>>()
@@ -194,7 +194,7 @@ fn offers_can_be_submitted() {
 
   create_wallet(&core, &dog);
 
-  let (inscription, _) = inscribe_with_options(&core, &dog, Some(9000), 0);
+  let (inscription, _) = inscribe_with_options(&core, &dog, Some(9_000_000), 0);
 
   let address = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
     .parse::<Address<NetworkUnchecked>>()
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn listed_inscription_must_be_in_wallet() {
+  let core = mockcore::spawn();
+
+  let dog = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &dog);
+
+  CommandBuilder::new([
+    "wallet",
+    "offer",
+    "list",
+    "--inscription",
+    "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0",
+    "--price",
+    "1btc",
+  ])
+  .core(&core)
+  .dog(&dog)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: inscription 6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0 not in wallet\n",
+  )
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn listing_requires_dune_or_inscription() {
+  CommandBuilder::new(["wallet", "offer", "list", "--price", "1btc"])
+    .expected_exit_code(2)
+    .stderr_regex(
+      "error: the following required arguments were not provided:\n  --dune <DECIMAL:DUNE>\n.*",
+    )
+    .run_and_extract_stdout();
+}
@@ -139,7 +139,7 @@ fn outputs_includes_dunes_and_inscriptions() {
     output.contains(&Output {
       output: etched.output.dune.clone().unwrap().location.unwrap(),
       address: etched.output.dune.unwrap().destination,
-      amount: 10000,
+      amount: 1_000_000,
       inscriptions: Some(Vec::new()),
       dunes: Some(
         vec![(
@@ -159,7 +159,7 @@ fn outputs_includes_dunes_and_inscriptions() {
   assert!(output.contains(&Output {
     output: etched.output.inscriptions[0].location.outpoint,
     address: Some(etched.output.inscriptions[0].destination.clone()),
-    amount: 10000,
+    amount: 1_000_000,
     inscriptions: Some(vec![etched.output.inscriptions[0].id]),
     dunes: Some(BTreeMap::new()),
     koinu_ranges: None,
@@ -93,7 +93,7 @@ fn send_inscription_does_not_select_runic_utxos() {
         --index-dunes
         wallet
         send
-        --postage 10000sat
+        --postage 1000000sat
         --fee-rate 1
         bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw
         {id}
@@ -163,7 +163,7 @@ fn sending_dune_does_not_send_inscription() {
 
   create_wallet(&core, &dog);
 
-  core.mine_blocks_with_subsidy(1, 10000);
+  core.mine_blocks_with_subsidy(1, 1_000_000);
 
   let dune = Dune(DUNE);
 
@@ -173,7 +173,7 @@ fn sending_dune_does_not_send_inscription() {
     .dog(&dog)
     .run_and_deserialize_output::<Batch>();
 
-  core.mine_blocks_with_subsidy(1, 10000);
+  core.mine_blocks_with_subsidy(1, 1_000_000);
 
   pretty_assert_eq!(
     CommandBuilder::new("--regtest --index-dunes wallet balance")
@@ -181,11 +181,11 @@ fn sending_dune_does_not_send_inscription() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 10000,
-      doginal: 10000,
+      cardinal: 1_000_000,
+      doginal: 1_000_000,
       runic: Some(0),
       dunes: Some(BTreeMap::new()),
-      total: 20000,
+      total: 2_000_000,
     }
   );
 
@@ -198,7 +198,7 @@ fn sending_dune_does_not_send_inscription() {
        --chain regtest
        --index-dunes
        wallet send
-       --postage 11111sat
+       --postage 1111111sat
        --fee-rate 0
        bcrt1pyrmadgg78e38ewfv0an8c6eppk2fttv5vnuvz04yza60qau5va0saknu8k
        1000:{dune}
@@ -257,7 +257,7 @@ fn do_not_send_within_dust_limit_of_an_inscription() {
 
   create_wallet(&core, &dog);
 
-  let (inscription, reveal) = inscribe(&core, &dog);
+  let (inscription, reveal) = inscribe_with_options(&core, &dog, Some(2_000_000), 1);
 
   core.mine_blocks(1);
 
@@ -267,13 +267,13 @@ fn do_not_send_within_dust_limit_of_an_inscription() {
   };
 
   CommandBuilder::new(format!(
-    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {output}:329"
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {output}:999999"
   ))
   .core(&core)
   .dog(&dog)
   .expected_exit_code(1)
   .expected_stderr(format!(
-    "error: cannot send {output}:329 without also sending inscription {inscription} at {output}:0\n"
+    "error: cannot send {output}:999999 without also sending inscription {inscription} at {output}:0\n"
   ))
   .run_and_extract_stdout();
 }
@@ -286,7 +286,7 @@ fn can_send_after_dust_limit_from_an_inscription() {
 
   create_wallet(&core, &dog);
 
-  let (_, reveal) = inscribe(&core, &dog);
+  let (_, reveal) = inscribe_with_options(&core, &dog, Some(2_000_000), 1);
 
   core.mine_blocks(1);
 
@@ -296,7 +296,7 @@ fn can_send_after_dust_limit_from_an_inscription() {
   };
 
   CommandBuilder::new(format!(
-    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {output}:330"
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {output}:1000000"
   ))
   .core(&core)
   .dog(&dog)
@@ -366,11 +366,11 @@ inscriptions:
       ]),
       indexed: true,
       dunes: None,
-      koinu_ranges: Some(vec![(5_000_000_000, 5_000_030_000)]),
+      koinu_ranges: Some(vec![(5_000_000_000, 5_003_000_000)]),
       script_pubkey: destination.assume_checked_ref().script_pubkey(),
       spent: false,
       transaction: reveal_txid,
-      value: 30_000,
+      value: 3_000_000,
     }
   );
 
@@ -382,7 +382,7 @@ inscriptions:
     .dog(&dog)
   .expected_exit_code(1)
   .expected_stderr(format!(
-    "error: cannot send {reveal_txid}:0:0 without also sending inscription {reveal_txid}i2 at {reveal_txid}:0:20000\n",
+    "error: cannot send {reveal_txid}:0:0 without also sending inscription {reveal_txid}i2 at {reveal_txid}:0:2000000\n",
   ))
   .run_and_extract_stdout();
 
@@ -592,7 +592,7 @@ fn wallet_send_with_fee_rate_and_target_postage() {
   let (inscription, _) = inscribe(&core, &dog);
 
   CommandBuilder::new(format!(
-    "wallet send bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription} --fee-rate 2.0 --postage 77000sat"
+    "wallet send bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription} --fee-rate 2.0 --postage 150000000sat"
   ))
   .core(&core)
     .dog(&dog)
@@ -610,7 +610,7 @@ fn wallet_send_with_fee_rate_and_target_postage() {
   let fee_rate = fee.to_sat() as f64 / tx.vsize() as f64;
 
   pretty_assert_eq!(fee_rate, 2.0);
-  pretty_assert_eq!(tx.output[0].value.to_sat(), 77_000);
+  pretty_assert_eq!(tx.output[0].value.to_sat(), 150_000_000);
 }
 
 #[test]
@@ -791,7 +791,7 @@ fn sending_dune_with_change_works() {
   etch(&core, &dog, Dune(DUNE));
 
   let output = CommandBuilder::new(format!(
-    "--chain regtest --index-dunes wallet send --postage 1234sat --fee-rate 1 bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw 777:{}",
+    "--chain regtest --index-dunes wallet send --postage 1234567sat --fee-rate 1 bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw 777:{}",
     Dune(DUNE)
   ))
   .core(&core)
@@ -802,8 +802,8 @@ fn sending_dune_with_change_works() {
 
   let tx = core.tx_by_id(output.txid);
 
-  assert_eq!(tx.output[1].value.to_sat(), 1234);
-  assert_eq!(tx.output[2].value.to_sat(), 1234);
+  assert_eq!(tx.output[1].value.to_sat(), 1234567);
+  assert_eq!(tx.output[2].value.to_sat(), 1234567);
 
   let balances = CommandBuilder::new("--regtest --index-dunes balances")
     .core(&core)
@@ -983,11 +983,11 @@ fn sending_dune_creates_change_output_for_non_outgoing_dunes() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 84999960000,
-      doginal: 20000,
+      cardinal: 84_996_000_000,
+      doginal: 2_000_000,
       dunes: Some([(SpacedDune::new(Dune(DUNE + 1), 0), "1000".parse().unwrap())].into()),
-      runic: Some(10000),
-      total: 84999990000,
+      runic: Some(1_000_000),
+      total: 84_999_000_000,
     }
   );
 }
@@ -122,16 +122,16 @@ fn simple_split() {
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 7 * 50 * COIN_VALUE - 20000,
-      doginal: 10000,
-      runic: Some(10000),
+      cardinal: 7 * 50 * COIN_VALUE - 2_000_000,
+      doginal: 1_000_000,
+      runic: Some(1_000_000),
       dunes: Some([(spaced_dune, "100.0".parse().unwrap())].into()),
       total: 7 * 50 * COIN_VALUE,
     }
   );
 
   let output = CommandBuilder::new(
-    "--regtest wallet split --fee-rate 10 --postage 666sat --splits splits.yaml",
+    "--regtest wallet split --fee-rate 10 --postage 2000000sat --splits splits.yaml",
   )
   .core(&core)
   .dog(&dog)
@@ -158,11 +158,11 @@ outputs:
       .dog(&dog)
       .run_and_deserialize_output::<Balance>(),
     Balance {
-      cardinal: 7 * 50 * COIN_VALUE - 10960,
-      doginal: 10000,
-      runic: Some(666),
+      cardinal: 7 * 50 * COIN_VALUE - 4_000_000,
+      doginal: 1_000_000,
+      runic: Some(2_000_000),
       dunes: Some([(spaced_dune, "49.9".parse().unwrap())].into()),
-      total: 7 * 50 * COIN_VALUE - 294,
+      total: 7 * 50 * COIN_VALUE - 1_000_000,
     }
   );
 